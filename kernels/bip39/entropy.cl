@@ -83,13 +83,13 @@ inline bool mnemonic_to_entropy(const ushort words[24], uchar entropy[32]) {
     return checksum == expected_checksum;
 }
 
-// 熵递增 - 按步长递增熵值
+// 熵递增 - 只在末尾 len 字节内进位 (助记词模式 12-24 个单词的熵位于种子末尾)
 // 返回 false 表示溢出
-inline bool increment_entropy(uchar entropy[32], uint step) {
+inline bool increment_entropy_len(uchar entropy[32], uint len, uint step) {
     uint carry = step;
     
     // 从最后一个字节开始进位 (小端序处理)
-    for (int i = 31; i >= 0 && carry > 0; i--) {
+    for (int i = 31; i >= (int)(32 - len) && carry > 0; i--) {
         uint sum = (uint)entropy[i] + carry;
         entropy[i] = (uchar)(sum & 0xFF);
         carry = sum >> 8;
@@ -98,3 +98,9 @@ inline bool increment_entropy(uchar entropy[32], uint step) {
     // 如果还有进位，说明溢出
     return (carry == 0);
 }
+
+// 熵递增 - 按步长递增熵值
+// 返回 false 表示溢出
+inline bool increment_entropy(uchar entropy[32], uint step) {
+    return increment_entropy_len(entropy, 32, step);
+}
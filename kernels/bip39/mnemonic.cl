@@ -1,10 +1,14 @@
 // BIP39 助记词处理 (OpenCL)
 // 实现助记词到以太坊私钥的完整转换
-// 依赖: sha512.cl、pbkdf2.cl、wordlist.cl
+// 依赖: sha512.cl、pbkdf2.cl、wordlist.cl (任一语言的词表，由主机在加载时选择)
 
-// 助记词结构
+// 助记词字符串最大字节数: 24 个最长单词 + 23 个空格
+#define BIP39_MAX_PHRASE_LEN (24 * BIP39_MAX_WORD_LEN + 23)
+
+// 助记词结构 (12/15/18/21/24 个单词)
 typedef struct {
     ushort words[24];
+    uint word_count;
 } mnemonic_t;
 
 // 种子结构 (512位)
@@ -12,27 +16,38 @@ typedef struct {
     uchar bytes[64];
 } seed_t;
 
-// BIP32 派生路径: m/44'/60'/0'/0/0 (标准以太坊路径)
-// 44' = 0x8000002C (BIP44)
-// 60' = 0x8000003C (以太坊币种)
-// 0'  = 0x80000000 (账户)
-// 0   = 0x00000000 (外部链 - 非硬化派生)
-// 0   = 0x00000000 (地址索引 - 非硬化派生)
-__constant uint DERIVATION_PATH[5] = {
-    0x8000002C,  // 44'
-    0x8000003C,  // 60'
-    0x80000000,  // 0'
-    0x00000000,  // 0 (非硬化派生)
-    0x00000000   // 0 (非硬化派生)
-};
+// 获取单词长度 (UTF-8 字节数)
+uchar get_word_length(ushort idx) {
+    return WORD_LENGTHS[idx];
+}
 
-// 将助记词转换为标准BIP39字符串
-// 单词之间用空格分隔
+// 将单词复制到目标缓冲区
+// 返回复制的字节数
+uint copy_word(ushort idx, uchar* dest, uint max_len) {
+    uint offset = WORD_OFFSETS[idx];
+    uint len = min((uint)WORD_LENGTHS[idx], max_len);
+    
+    for (uint i = 0; i < len; i++) {
+        uint char_idx = offset + i;
+        // 防止数组越界
+        if (char_idx >= BIP39_TOTAL_CHARS) break;
+        uint ulong_idx = char_idx >> 3;  // / 8
+        // 防止 ulong_idx 越界
+        if (ulong_idx >= BIP39_ULONG_COUNT) break;
+        uint bit_offset = (7 - (char_idx & 7)) << 3;  // % 8, then * 8
+        dest[i] = (uchar)(WORD_DATA[ulong_idx] >> bit_offset);
+    }
+    return len;
+}
+
+// 将助记词转换为标准BIP39字符串 (NFKD 规范化形式)
+// 单词之间用空格分隔: 日文助记词显示时使用全角空格 U+3000，NFKD 规范化后同样是空格
+// 非英文单词为多字节 UTF-8，词表中已是 NFKD 规范化形式
 // 返回字符串长度
-inline uchar mnemonic_to_string(const mnemonic_t* mnemonic, uchar* output, uchar max_len) {
-    uchar pos = 0;
+inline uint mnemonic_to_string(const mnemonic_t* mnemonic, uchar* output, uint max_len) {
+    uint pos = 0;
     
-    for (int i = 0; i < 24; i++) {
+    for (uint i = 0; i < mnemonic->word_count; i++) {
         // 添加空格分隔符 (第一个单词前不加)
         if (i > 0) {
             if (pos >= max_len) return pos;
@@ -41,7 +56,7 @@ inline uchar mnemonic_to_string(const mnemonic_t* mnemonic, uchar* output, uchar
         
         // 复制单词
         ushort word_idx = mnemonic->words[i];
-        uchar word_len = copy_word(word_idx, output + pos, max_len - pos);
+        uint word_len = copy_word(word_idx, output + pos, max_len - pos);
         pos += word_len;
     }
     
@@ -51,19 +66,25 @@ inline uchar mnemonic_to_string(const mnemonic_t* mnemonic, uchar* output, uchar
 // 助记词到种子 (BIP39 标准)
 // 使用 PBKDF2-HMAC-SHA512
 // password: 助记词字符串 (单词之间用空格分隔)
-// salt: "mnemonic" + 可选密码
+// salt: "mnemonic" + 可选密码 (主机端已做 NFKD 规范化，最多 MAX_PASSPHRASE_LEN 字节)
 // 迭代次数: 2048
-inline void mnemonic_to_seed(const mnemonic_t* mnemonic, seed_t* seed) {
-    // 构建助记词字符串 (最大约 24 * 8 + 23 = 215 字节)
-    uchar password[256];
+inline void mnemonic_to_seed(const mnemonic_t* mnemonic, __constant const uchar* passphrase,
+                             uint passphrase_len, seed_t* seed) {
+    // 构建助记词字符串 (英文最大 24 * 8 + 23 = 215 字节，韩文最大 24 * 33 + 23 = 815 字节)
+    // 超过 128 字节时 HMAC 会先对密钥做一次 SHA-512
+    uchar password[BIP39_MAX_PHRASE_LEN];
     // 使用局部初始化，只清零需要的部分
-    uchar password_len = mnemonic_to_string(mnemonic, password, 255);
+    uint password_len = mnemonic_to_string(mnemonic, password, BIP39_MAX_PHRASE_LEN);
     
-    // salt = "mnemonic"
-    uchar salt[8] = {'m', 'n', 'e', 'm', 'o', 'n', 'i', 'c'};
+    // salt = "mnemonic" + passphrase
+    uchar salt[8 + MAX_PASSPHRASE_LEN] = {'m', 'n', 'e', 'm', 'o', 'n', 'i', 'c'};
+    passphrase_len = min(passphrase_len, (uint)MAX_PASSPHRASE_LEN);
+    for (uint i = 0; i < passphrase_len; i++) {
+        salt[8 + i] = passphrase[i];
+    }
     
     // PBKDF2-HMAC-SHA512, 2048 次迭代
-    pbkdf2_hmac_sha512(password, password_len, salt, 8, 2048, seed->bytes, 64);
+    pbkdf2_hmac_sha512(password, password_len, salt, 8 + passphrase_len, 2048, seed->bytes, 64);
 }
 
 // 从种子生成主密钥 (BIP32)
@@ -230,22 +251,56 @@ inline void derive_path(const seed_t* seed, __constant const uint* path, uint pa
     *((ulong*)(private_key + 24)) = *((ulong*)(current_key + 24));
 }
 
-// 获取以太坊私钥 (标准派生路径 m/44'/60'/0'/0/0)
-inline void get_ethereum_private_key(const mnemonic_t* mnemonic, uchar private_key[32]) {
+// 获取以太坊私钥 (派生路径由主机上传，默认 m/44'/60'/0'/0/0)
+// path: BIP32 索引数组，硬化索引带 0x80000000 标志
+inline void get_ethereum_private_key(const mnemonic_t* mnemonic, __constant const uchar* passphrase,
+                                     uint passphrase_len, __constant const uint* path, uint path_len,
+                                     uchar private_key[32]) {
     seed_t seed;
-    mnemonic_to_seed(mnemonic, &seed);
+    mnemonic_to_seed(mnemonic, passphrase, passphrase_len, &seed);
     
     // 直接使用 __constant 派生路径，避免局部拷贝
-    derive_path(&seed, DERIVATION_PATH, 5, private_key);
+    derive_path(&seed, path, min(path_len, (uint)MAX_DERIVATION_DEPTH), private_key);
+}
+
+// 获取账户级扩展密钥 (私钥 || 链码): 派生路径中除最后一层 (地址索引) 以外的各层
+// 同一助记词检查多个地址索引时，PBKDF2 和前几层派生只需做一次
+inline void get_ethereum_account_key(const mnemonic_t* mnemonic, __constant const uchar* passphrase,
+                                     uint passphrase_len, __constant const uint* path, uint path_len,
+                                     uchar account_key[64]) {
+    seed_t seed;
+    mnemonic_to_seed(mnemonic, passphrase, passphrase_len, &seed);
+    seed_to_master_key(&seed, account_key);
+    
+    path_len = min(path_len, (uint)MAX_DERIVATION_DEPTH);
+    for (uint i = 0; i + 1 < path_len; i++) {
+        derive_child_key(account_key, path[i], account_key);
+    }
+}
+
+// 由账户级扩展密钥派生地址索引 index 的私钥 (派生路径为空时账户密钥本身即为私钥)
+inline void get_ethereum_address_key(const uchar account_key[64], uint path_len, uint index, uchar private_key[32]) {
+    uchar child_key[64];
+    if (path_len > 0) {
+        derive_child_key(account_key, index, child_key);
+    } else {
+        for (int i = 0; i < 32; i++) {
+            child_key[i] = account_key[i];
+        }
+    }
+    for (int i = 0; i < 32; i++) {
+        private_key[i] = child_key[i];
+    }
 }
 
 // 兼容接口: local_mnemonic_t 类型在 search.cl 中定义
-inline void get_ethereum_private_key_local(const local_mnemonic_t* mnemonic, uchar private_key[32]) {
+inline void get_ethereum_account_key_local(const local_mnemonic_t* mnemonic, __constant const uchar* passphrase,
+                                           uint passphrase_len, __constant const uint* path, uint path_len,
+                                           uchar account_key[64]) {
     mnemonic_t mn;
-    for (int i = 0; i < 24; i++) {
+    mn.word_count = min(mnemonic->word_count, 24u);
+    for (uint i = 0; i < mn.word_count; i++) {
         mn.words[i] = mnemonic->words[i];
     }
-    get_ethereum_private_key(&mn, private_key);
+    get_ethereum_account_key(&mn, passphrase, passphrase_len, path, path_len, account_key);
 }
-
-
@@ -4,6 +4,7 @@
 #define BIP39_WORD_COUNT 2048
 #define BIP39_TOTAL_CHARS 11068
 #define BIP39_ULONG_COUNT 1384
+#define BIP39_MAX_WORD_LEN 8
 
 // 每个单词的长度 (2048 bytes)
 __constant uchar WORD_LENGTHS[BIP39_WORD_COUNT] = {
@@ -746,28 +747,3 @@ __constant ulong WORD_DATA[1384] = {
     0x6C65777269737477ULL, 0x7269746577726F6EULL, 0x6779617264796561ULL, 0x7279656C6C6F7779ULL,
     0x6F75796F756E6779ULL, 0x6F7574687A656272ULL, 0x617A65726F7A6F6EULL, 0x657A6F6F00000000ULL
 };
-
-// 获取单词长度
-uchar get_word_length(ushort idx) {
-    return WORD_LENGTHS[idx];
-}
-
-// 将单词复制到目标缓冲区
-// 返回复制的字节数
-uchar copy_word(ushort idx, uchar* dest, uchar max_len) {
-    ushort offset = WORD_OFFSETS[idx];
-    uchar len = WORD_LENGTHS[idx];
-    if (len > max_len) len = max_len;
-    
-    for (uchar i = 0; i < len; i++) {
-        ushort char_idx = offset + i;
-        // 防止数组越界
-        if (char_idx >= BIP39_TOTAL_CHARS) break;
-        ushort ulong_idx = char_idx >> 3;  // / 8
-        // 防止 ulong_idx 越界
-        if (ulong_idx >= BIP39_ULONG_COUNT) break;
-        uchar bit_offset = (7 - (char_idx & 7)) << 3;  // % 8, then * 8
-        dest[i] = (uchar)(WORD_DATA[ulong_idx] >> bit_offset);
-    }
-    return len;
-}
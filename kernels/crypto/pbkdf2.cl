@@ -21,7 +21,7 @@ void pbkdf2_hmac_sha512_block(
     
     // U_1 = HMAC-SHA512(Password, Salt || INT_32_BE(block_num))
     // 使用固定大小数组，避免大数组初始化开销
-    uchar salt_block[128];  // BIP39 salt "mnemonic" (8字节) + 4 = 12 < 128
+    uchar salt_block[128];  // BIP39 salt "mnemonic"(8字节) + 密码(最多 116 字节) + 4 <= 128
     
     // 直接复制 salt，不需要清零整个数组
     for (uint i = 0; i < salt_len; i++) {
@@ -0,0 +1,155 @@
+// RIPEMD-160 哈希实现 (OpenCL)
+// 比特币 HASH160 = RIPEMD-160(SHA-256(公钥))
+// 与 SHA-256 不同，消息字、长度和输出均为小端序
+
+#ifndef RIPEMD160_CL
+#define RIPEMD160_CL
+
+// 初始哈希值
+__constant uint RIPEMD160_H[5] = {
+    0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0
+};
+
+// 左、右两条线每 16 步一组的常数
+__constant uint RIPEMD160_KL[5] = {
+    0x00000000, 0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xA953FD4E
+};
+__constant uint RIPEMD160_KR[5] = {
+    0x50A28BE6, 0x5C4DD124, 0x6D703EF3, 0x7A6D76E9, 0x00000000
+};
+
+// 每一步读取的消息字下标
+__constant uchar RIPEMD160_RL[80] = {
+     0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15,
+     7,  4, 13,  1, 10,  6, 15,  3, 12,  0,  9,  5,  2, 14, 11,  8,
+     3, 10, 14,  4,  9, 15,  8,  1,  2,  7,  0,  6, 13, 11,  5, 12,
+     1,  9, 11, 10,  0,  8, 12,  4, 13,  3,  7, 15, 14,  5,  6,  2,
+     4,  0,  5,  9,  7, 12,  2, 10, 14,  1,  3,  8, 11,  6, 15, 13
+};
+__constant uchar RIPEMD160_RR[80] = {
+     5, 14,  7,  0,  9,  2, 11,  4, 13,  6, 15,  8,  1, 10,  3, 12,
+     6, 11,  3,  7,  0, 13,  5, 10, 14, 15,  8, 12,  4,  9,  1,  2,
+    15,  5,  1,  3,  7, 14,  6,  9, 11,  8, 12,  2, 10,  0,  4, 13,
+     8,  6,  4,  1,  3, 11, 15,  0,  5, 12,  2, 13,  9,  7, 10, 14,
+    12, 15, 10,  4,  1,  5,  8,  7,  6,  2, 13, 14,  0,  3,  9, 11
+};
+
+// 每一步的循环左移位数
+__constant uchar RIPEMD160_SL[80] = {
+    11, 14, 15, 12,  5,  8,  7,  9, 11, 13, 14, 15,  6,  7,  9,  8,
+     7,  6,  8, 13, 11,  9,  7, 15,  7, 12, 15,  9, 11,  7, 13, 12,
+    11, 13,  6,  7, 14,  9, 13, 15, 14,  8, 13,  6,  5, 12,  7,  5,
+    11, 12, 14, 15, 14, 15,  9,  8,  9, 14,  5,  6,  8,  6,  5, 12,
+     9, 15,  5, 11,  6,  8, 13, 12,  5, 12, 13, 14, 11,  8,  5,  6
+};
+__constant uchar RIPEMD160_SR[80] = {
+     8,  9,  9, 11, 13, 15, 15,  5,  7,  7,  8, 11, 14, 14, 12,  6,
+     9, 13, 15,  7, 12,  8,  9, 11,  7,  7, 12,  7,  6, 15, 13, 11,
+     9,  7, 15, 11,  8,  6,  6, 14, 12, 13,  5, 14, 13, 13,  7,  5,
+    15,  5,  8, 11, 14, 14,  6, 14,  6,  9, 12,  9, 12,  5, 15,  8,
+     8,  5, 12,  9, 12,  5, 14,  6,  8, 13,  6,  5, 15, 13, 11, 11
+};
+
+// 循环左移 - 手动实现，避免与 sha256.cl 的 rotr 等辅助函数重名
+inline uint ripemd160_rotl(uint x, uint n) {
+    return (x << n) | (x >> (32 - n));
+}
+
+// 第 round 组 (0-4) 的非线性函数
+inline uint ripemd160_f(uint round, uint x, uint y, uint z) {
+    switch (round) {
+        case 0: return x ^ y ^ z;
+        case 1: return (x & y) | (~x & z);
+        case 2: return (x | ~y) ^ z;
+        case 3: return (x & z) | (y & ~z);
+        default: return x ^ (y | ~z);
+    }
+}
+
+// RIPEMD-160 压缩函数: 左右两条线各 80 步，最后交叉合并到状态
+void ripemd160_compress(uint state[5], const uchar block[64]) {
+    uint x[16];
+    for (uint i = 0; i < 16; i++) {
+        x[i] = ((uint)block[i * 4]) |
+               ((uint)block[i * 4 + 1] << 8) |
+               ((uint)block[i * 4 + 2] << 16) |
+               ((uint)block[i * 4 + 3] << 24);
+    }
+
+    uint al = state[0], bl = state[1], cl = state[2], dl = state[3], el = state[4];
+    uint ar = al, br = bl, cr = cl, dr = dl, er = el;
+
+    for (uint j = 0; j < 80; j++) {
+        uint round = j >> 4;  // / 16
+
+        uint t = ripemd160_rotl(al + ripemd160_f(round, bl, cl, dl) + x[RIPEMD160_RL[j]] + RIPEMD160_KL[round],
+                                RIPEMD160_SL[j]) + el;
+        al = el;
+        el = dl;
+        dl = ripemd160_rotl(cl, 10);
+        cl = bl;
+        bl = t;
+
+        // 右线的非线性函数按相反顺序使用
+        t = ripemd160_rotl(ar + ripemd160_f(4 - round, br, cr, dr) + x[RIPEMD160_RR[j]] + RIPEMD160_KR[round],
+                           RIPEMD160_SR[j]) + er;
+        ar = er;
+        er = dr;
+        dr = ripemd160_rotl(cr, 10);
+        cr = br;
+        br = t;
+    }
+
+    uint t = state[1] + cl + dr;
+    state[1] = state[2] + dl + er;
+    state[2] = state[3] + el + ar;
+    state[3] = state[4] + al + br;
+    state[4] = state[0] + bl + cr;
+    state[0] = t;
+}
+
+// RIPEMD-160 哈希函数
+void ripemd160(const uchar* data, uint len, uchar hash[20]) {
+    uint state[5];
+    for (uint i = 0; i < 5; i++) {
+        state[i] = RIPEMD160_H[i];
+    }
+
+    // 处理完整块
+    uint i = 0;
+    while (i + 64 <= len) {
+        ripemd160_compress(state, &data[i]);
+        i += 64;
+    }
+
+    // 填充: 0x80，补零，末尾 8 字节为小端序的位长度
+    uchar block[64];
+    uint remaining = len - i;
+    for (uint j = 0; j < 64; j++) {
+        block[j] = (j < remaining) ? data[i + j] : 0;
+    }
+    block[remaining] = 0x80;
+
+    ulong bit_len_64 = (ulong)len * 8ULL;
+    if (remaining >= 56) {
+        // 长度放不下，需要额外一块
+        ripemd160_compress(state, block);
+        for (uint j = 0; j < 56; j++) {
+            block[j] = 0;
+        }
+    }
+    for (uint j = 0; j < 8; j++) {
+        block[56 + j] = (uchar)(bit_len_64 >> (8 * j));
+    }
+    ripemd160_compress(state, block);
+
+    // 输出哈希 - 小端序存储
+    for (uint j = 0; j < 5; j++) {
+        hash[j * 4] = (uchar)state[j];
+        hash[j * 4 + 1] = (uchar)(state[j] >> 8);
+        hash[j * 4 + 2] = (uchar)(state[j] >> 16);
+        hash[j * 4 + 3] = (uchar)(state[j] >> 24);
+    }
+}
+
+#endif // RIPEMD160_CL
@@ -828,6 +828,71 @@ void jacobian_to_affine(point* r, const jacobian_point* p) {
     mp_mod_mul(&r->y, &p->Y, &z_inv2);    // y = Y * Z^-3
 }
 
+// 批量转换的最大点数 (受私有内存大小限制)
+#define BATCH_INVERSE_MAX 16
+
+// 规约到 [0, p): mp_mod_mul 在真实结果很小 (如 Z=1 时的逆) 时可能返回 x+p，
+// 这种未规约的值再参与 mp_mod_mul 会得到错误结果
+void mp_mod_reduce(mp_number * const r) {
+    if (mp_gte(r, &mod)) {
+        mp_sub(r, r, &mod);
+    }
+}
+
+// 模乘并规约到 [0, p)，批量求逆中所有中间值和输出都经过此函数
+void mp_mod_mul_reduced(mp_number * const r, const mp_number * const X, const mp_number * const Y) {
+    mp_mod_mul(r, X, Y);
+    mp_mod_reduce(r);
+}
+
+// 批量 Jacobian 转仿射坐标 (Montgomery 批量求逆)
+// count 个点共享一次 mp_mod_inverse，额外代价为每点 3 次模乘
+// 无穷远点以 Z=1 参与累乘，不影响其他点，输出置零
+// 前缀积、逆元和输出 x/y 全部规约到 [0, p)，保证与 jacobian_to_affine 结果一致
+void jacobian_to_affine_batch(point* r, const jacobian_point* p, uint count) {
+    mp_number acc[BATCH_INVERSE_MAX];
+    mp_number one = {{1, 0, 0, 0, 0, 0, 0, 0}};
+
+    // 前缀积: acc[i] = Z_0 * Z_1 * ... * Z_i
+    acc[0] = jacobian_is_infinity(&p[0]) ? one : p[0].Z;
+    mp_mod_reduce(&acc[0]);
+    for (uint i = 1; i < count; i++) {
+        mp_mod_mul_reduced(&acc[i], &acc[i - 1], jacobian_is_infinity(&p[i]) ? &one : &p[i].Z);
+    }
+
+    // 唯一一次求逆: inv = (Z_0 * ... * Z_{count-1})^-1
+    mp_number inv = acc[count - 1];
+    mp_mod_inverse(&inv);
+    mp_mod_reduce(&inv);
+
+    // 逆序剥离: Z_i^-1 = inv * acc[i-1]，然后 inv = inv * Z_i
+    for (uint i = count; i-- > 0;) {
+        mp_number z_inv, z_inv2;
+        bool infinity = jacobian_is_infinity(&p[i]);
+        if (i > 0) {
+            mp_mod_mul_reduced(&z_inv, &inv, &acc[i - 1]);
+            if (!infinity) {
+                mp_mod_mul_reduced(&inv, &inv, &p[i].Z);
+            }
+        } else {
+            z_inv = inv;
+        }
+
+        if (infinity) {
+            for (int j = 0; j < 8; j++) {
+                r[i].x.d[j] = 0;
+                r[i].y.d[j] = 0;
+            }
+            continue;
+        }
+
+        mp_mod_mul_reduced(&z_inv2, &z_inv, &z_inv);       // Z^-2
+        mp_mod_mul_reduced(&r[i].x, &p[i].X, &z_inv2);     // x = X * Z^-2
+        mp_mod_mul_reduced(&z_inv2, &z_inv2, &z_inv);      // Z^-3
+        mp_mod_mul_reduced(&r[i].y, &p[i].Y, &z_inv2);     // y = Y * Z^-3
+    }
+}
+
 // 椭圆曲线点加法
 // 不处理共享 X 坐标的点
 void point_add(point * const r, point * const p, point * const o) {
@@ -949,15 +1014,16 @@ void scalar_mult_base(const uchar scalar[32], uchar result[65]) {
     mp_to_bytes(&ry, result + 33);
 }
 
-// 使用 Jacobian 射影坐标的标量乘法: result = scalar * G
-// 基础版本：双倍-加法算法，每次点加/倍乘都涉及模乘和模逆（仅在最后转换时调用一次模逆）
-void scalar_mult_base_jacobian(const uchar scalar[32], uchar result[65]) {
+// 使用 Jacobian 射影坐标的标量乘法: r = scalar * G (结果保持 Jacobian 坐标)
+// 私钥模式的增量遍历只在起点调用一次，之后通过点加法步进
+void scalar_mult_base_jacobian_point(const uchar scalar[32], jacobian_point* r) {
+    // 结果初始化为无穷远点（Jacobian 坐标）
+    jacobian_set_infinity(r);
+    
     // 如果私钥为零，返回无穷远点
     mp_number priv_key;
     mp_from_bytes(scalar, &priv_key);
     if (mp_is_zero(&priv_key)) {
-        result[0] = 0x04;
-        for (int i = 1; i < 65; i++) result[i] = 0;
         return;
     }
     
@@ -966,33 +1032,36 @@ void scalar_mult_base_jacobian(const uchar scalar[32], uchar result[65]) {
     g_affine.x = Gx;
     g_affine.y = Gy;
     
-    // 结果初始化为无穷远点（Jacobian 坐标）
-    jacobian_point r;
-    jacobian_set_infinity(&r);
-    
     // 从最高位到最低位处理
     for (int i = 0; i < 32; i++) {
         uchar byte = scalar[i];
         for (int j = 7; j >= 0; j--) {
             // 点加倍: r = 2*r (使用 Jacobian 坐标)
-            if (!jacobian_is_infinity(&r)) {
-                jacobian_double(&r, &r);
+            if (!jacobian_is_infinity(r)) {
+                jacobian_double(r, r);
             }
             
             // 如果当前位为 1，加上 G
             if ((byte >> j) & 1) {
-                if (jacobian_is_infinity(&r)) {
+                if (jacobian_is_infinity(r)) {
                     // r 是无穷远点，直接设为 G
-                    affine_to_jacobian(&r, &g_affine);
+                    affine_to_jacobian(r, &g_affine);
                 } else {
                     // r = r + G (混合加法)
-                    jacobian_add_affine(&r, &r, &g_affine);
+                    jacobian_add_affine(r, r, &g_affine);
                 }
             }
         }
     }
+}
+
+// 使用 Jacobian 射影坐标的标量乘法: result = scalar * G
+// 基础版本：双倍-加法算法，每次点加/倍乘都涉及模乘和模逆（仅在最后转换时调用一次模逆）
+void scalar_mult_base_jacobian(const uchar scalar[32], uchar result[65]) {
+    jacobian_point r;
+    scalar_mult_base_jacobian_point(scalar, &r);
     
-    // 转换回仿射坐标
+    // 转换回仿射坐标 (无穷远点转换为全零，与私钥为零时的旧行为一致)
     point result_affine;
     jacobian_to_affine(&result_affine, &r);
     
@@ -4,45 +4,90 @@
 // 注意: 所有依赖文件已由 main.rs 手动合并
 // 不要在此文件中添加 #include 语句
 
+// BIP39 密码最大字节数 (与 Rust 端 MAX_PASSPHRASE_BYTES 一致)
+// PBKDF2 的 salt 缓冲区为 128 字节: "mnemonic"(8) + 密码 + 块序号(4)
+#define MAX_PASSPHRASE_LEN 116
+
+// BIP32 派生路径最大层数 (与 Rust 端 MAX_DERIVATION_DEPTH 一致)
+#define MAX_DERIVATION_DEPTH 10
+
 // 搜索配置结构 (与Rust端对应)
 // Rust 布局: base_seed[32] @0, num_threads @32, source_mode @36, target_chain @40,
-//            condition @48, check_interval @56, pattern_config @64
-// 总大小: 104 bytes (包含填充)
+//            target_count @44, check_interval @48, batch_size @52, step_point @56,
+//            public_key @120, address_type @184, deployer @188, init_code_hash @208,
+//            max_results @240, score_mode @244, passphrase_len @248, passphrase @252,
+//            derivation_path_len @368, derivation_path @372, address_count @412, entropy_len @416
+// 总大小: 420 bytes (Rust 端按 8 字节对齐为 424 bytes)
 // 注意：使用基本类型数组而不是嵌套结构体，避免OpenCL兼容性问题
 typedef struct {
     uchar base_seed[32];         // 基础种子 (256位) - offset 0
     uint num_threads;            // offset 32
     uint source_mode;            // offset 36
-    uint target_chain;           // offset 40
-    uchar _padding1[4];          // offset 44
-    ulong condition;             // offset 48
-    uint check_interval;         // offset 56
-    uchar _padding2[4];          // offset 60
-    // pattern_config 展开 (offset 64)
-    uchar pattern_mask[20];      // 掩码数组 - 哪些位需要匹配
-    uchar pattern_value[20];     // 期望值数组 - 需要匹配的值
+    uint target_chain;           // 0=以太坊, 1/2=比特币 P2PKH/P2WPKH (匹配 HASH160), 3=比特币 P2TR, 4=波场 (同以太坊), 5=Nostr (x-only 公钥) - offset 40
+    uint target_count;           // 目标表中的条件数量 - offset 44
+    uint check_interval;         // offset 48
+    uint batch_size;             // 私钥模式每批共享一次求逆的点数 - offset 52
+    uchar step_point[64];        // 私钥模式步进点 num_threads*batch_size*G (x||y, 大端序) - offset 56
+    uchar public_key[64];        // 分离密钥模式的用户公钥 P (x||y, 大端序) - offset 120
+    uint address_type;           // 0=外部账户地址, 1=CREATE 合约地址 (nonce 0) - offset 184
+    uchar deployer[20];          // 固定部署者 / CREATE2 模式的部署者地址 - offset 188
+    uchar init_code_hash[32];    // CREATE2 模式的 keccak256(init_code) - offset 208
+    uint max_results;            // 收集到多少个结果后停止 (0 = 不限) - offset 240
+    uint score_mode;             // 评分模式 (SCORE_*)，非 0 时忽略 max_results，只输出更高分的地址 - offset 244
+    uint passphrase_len;         // BIP39 密码字节数 (0 = 无密码) - offset 248
+    uchar passphrase[MAX_PASSPHRASE_LEN]; // BIP39 密码 (NFKD 规范化后的 UTF-8) - offset 252
+    uint derivation_path_len;    // BIP32 派生路径层数 - offset 368
+    uint derivation_path[MAX_DERIVATION_DEPTH]; // BIP32 派生路径 (硬化索引带 0x80000000) - offset 372
+    uint address_count;          // 助记词模式每个助记词检查的地址索引个数 (路径最后一层依次加 0..n-1) - offset 412
+    uint entropy_len;            // 助记词模式的熵字节数 (16/20/24/28/32，位于 base_seed 末尾) - offset 416
 } search_config_t;
 
-// 搜索结果结构
+// 结果环形缓冲区槽位数 (与 Rust 端 RESULT_RING_CAPACITY 一致)
+#define RESULT_RING_CAPACITY 256
+
+// 搜索目标结构 (与Rust端 SearchTarget 对应)
+// 多个目标组成目标表，按后缀表达式求值，任一顶层条件满足即视为找到
+// Rust 布局: condition @0, pattern_config @8, case_mask @88, case_value @108, case_sensitive @128, root @132
+// 总大小: 136 bytes (包含填充)
+typedef struct {
+    ulong condition;             // 条件编码 - offset 0
+    uchar pattern_mask[20];      // 掩码数组 - 哪些位需要匹配 - offset 8
+    uchar pattern_value[20];     // 期望值数组 - 需要匹配的值 - offset 28
+    uchar pattern_class[40];     // 每个半字节的字符类约束 - offset 48
+    uchar case_mask[20];         // EIP-55 大小写掩码 (作用于校验哈希) - offset 88
+    uchar case_value[20];        // EIP-55 大小写期望值 - offset 108
+    uint case_sensitive;         // 是否启用大小写匹配 - offset 128
+    uint root;                   // 是否为顶层条件的最后一项 - offset 132
+} search_target_t;
+
+// 搜索结果结构 (结果环形缓冲区中的一个槽位)
 typedef struct {
-    int found;
+    int found;                 // 结果序号 + 1，最后写入，主机据此判断槽位已写完
     uchar result_seed[32];     // 找到的种子材料 (32字节)，可解释为熵或私钥
     uchar eth_address[20];
     uint found_by_thread;
     uint total_checked_low;    // 总共检查的地址数量 - 低32位
     uint total_checked_high;   // 总共检查的地址数量 - 高32位
+    uint target_index;         // 命中的目标在目标表中的下标
+    uint score;                // 评分模式下的地址分数
+    uint address_index;        // 助记词模式命中的地址索引 (派生路径最后一层)
 } search_result_t;
 
 // 本地助记词结构 (与 mnemonic.cl 中的定义保持一致)
 typedef struct {
     ushort words[24];
+    uint word_count;           // 有效单词数 (12/15/18/21/24)
 } local_mnemonic_t;
 
 // 函数前置声明
-inline void get_ethereum_private_key_local(const local_mnemonic_t* mnemonic, uchar private_key[32]);
+inline void get_ethereum_account_key_local(const local_mnemonic_t* mnemonic, __constant const uchar* passphrase,
+                                           uint passphrase_len, __constant const uint* path, uint path_len,
+                                           uchar account_key[64]);
+inline void get_ethereum_address_key(const uchar account_key[64], uint path_len, uint index, uchar private_key[32]);
 inline bool increment_entropy(uchar entropy[32], uint step);
+inline bool increment_entropy_len(uchar entropy[32], uint len, uint step);
 
-// 24 个 11-bit 单词索引的预计算偏移，减少循环内整数运算
+// 最多 24 个 11-bit 单词索引的预计算偏移，减少循环内整数运算 (较短的助记词只用前 word_count 项)
 constant uchar WORD_BYTE_IDX[24] = {
     0, 1, 2, 4, 5, 6, 8, 9, 11, 12, 13, 15,
     16, 17, 19, 20, 22, 23, 24, 26, 27, 28, 30, 31
@@ -52,28 +97,33 @@ constant uchar WORD_BIT_SHIFT[24] = {
     4, 7, 2, 5, 0, 3, 6, 1, 4, 7, 2, 5
 };
 
-// 从熵生成以太坊地址
-// 流程: 熵 -> 助记词 -> 种子 -> 私钥 -> 公钥 -> Keccak-256 -> 地址
+// 从熵派生账户级扩展密钥 (私钥 || 链码)
+// 流程: 熵 -> 助记词 -> 种子 (可选 BIP39 密码) -> 账户密钥 (BIP32 路径 path 除最后一层)
+// entropy_len: 16/20/24/28/32 字节，对应 12/15/18/21/24 个单词
 // 优化: entropy_to_mnemonic 逻辑已内联，减少函数调用开销
-inline void derive_address_from_entropy(const uchar entropy[32], uchar address[20]) {
+inline void derive_account_key_from_entropy(const uchar* entropy, uint entropy_len,
+                                            __constant const uchar* passphrase, uint passphrase_len,
+                                            __constant const uint* path, uint path_len,
+                                            uchar account_key[64]) {
     // ===== 内联 entropy_to_mnemonic 开始 =====
-    // 计算校验和: SHA256 的前 8 位 (256/32 = 8)
-    // 使用单个 hash 缓冲区，减少私有内存占用
+    // 计算校验和: SHA256 的前 ENT/32 位 (最多 8 位)，只会读到校验和字节的高位
     uchar hash[32];
-    sha256(entropy, 32, hash);
-    uchar checksum_bits = hash[0]; // 取前8位
+    sha256(entropy, entropy_len, hash);
+    uchar checksum_bits = hash[0];
     
     // 直接写入 local_mnemonic_t，避免 words[24] 临时数组
     local_mnemonic_t mn;
+    mn.word_count = entropy_len * 3 / 4;
     #pragma unroll
-    for (int i = 0; i < 24; i++) {
+    for (uint i = 0; i < 24; i++) {
+        if (i >= mn.word_count) break;
         uint byte_idx = WORD_BYTE_IDX[i];
         uint bit_shift = WORD_BIT_SHIFT[i];
         
-        // 从 entropy(0..31) + checksum(32) 按需读取 3 字节窗口，避免 all_bits[33] 私有缓冲
-        uchar b0 = (byte_idx < 32) ? entropy[byte_idx] : checksum_bits;
-        uchar b1 = (byte_idx + 1 < 32) ? entropy[byte_idx + 1] : ((byte_idx + 1 == 32) ? checksum_bits : (uchar)0);
-        uchar b2 = (byte_idx + 2 < 32) ? entropy[byte_idx + 2] : ((byte_idx + 2 == 32) ? checksum_bits : (uchar)0);
+        // 从 entropy(0..len-1) + checksum(len) 按需读取 3 字节窗口，避免 all_bits[33] 私有缓冲
+        uchar b0 = (byte_idx < entropy_len) ? entropy[byte_idx] : checksum_bits;
+        uchar b1 = (byte_idx + 1 < entropy_len) ? entropy[byte_idx + 1] : ((byte_idx + 1 == entropy_len) ? checksum_bits : (uchar)0);
+        uchar b2 = (byte_idx + 2 < entropy_len) ? entropy[byte_idx + 2] : ((byte_idx + 2 == entropy_len) ? checksum_bits : (uchar)0);
         uint val = ((uint)b0 << 24) | ((uint)b1 << 16) | ((uint)b2 << 8);
         
         // 提取 11 位 (从大端序)
@@ -82,46 +132,327 @@ inline void derive_address_from_entropy(const uchar entropy[32], uchar address[2
     }
     // ===== 内联 entropy_to_mnemonic 结束 =====
     
-    // 助记词 -> 私钥 (BIP39 + BIP32)
-    uchar private_key[32];
-    get_ethereum_private_key_local(&mn, private_key);
-    
-    // 私钥 -> 公钥 (secp256k1)
+    // 助记词 -> 账户密钥 (BIP39 + BIP32)
+    get_ethereum_account_key_local(&mn, passphrase, passphrase_len, path, path_len, account_key);
+}
+
+// 从私钥直接生成以太坊地址
+inline void derive_address_from_private_key(const uchar private_key[32], uchar address[20]) {
     uchar public_key[65];
+    uchar hash[32];
     private_to_public(private_key, public_key);
-    
-    // 公钥 -> Keccak-256 哈希 (跳过 0x04 前缀)
-    // 复用上面的 hash 缓冲区
     keccak256(public_key + 1, 64, hash);
-    
-    // 取后 20 字节作为以太坊地址（逐字节复制，避免未对齐读写）
     #pragma unroll
     for (int i = 0; i < 20; i++) {
         address[i] = hash[12 + i];
     }
 }
 
-// 从私钥直接生成以太坊地址
-inline void derive_address_from_private_key(const uchar private_key[32], uchar address[20]) {
-    uchar public_key[65];
+// 由账户级扩展密钥生成地址索引 index 的以太坊地址
+inline void derive_address_from_account_key(const uchar account_key[64], uint path_len, uint index,
+                                            uchar address[20]) {
+    uchar private_key[32];
+    get_ethereum_address_key(account_key, path_len, index, private_key);
+    derive_address_from_private_key(private_key, address);
+}
+
+// 从熵生成以太坊地址 (派生路径的最后一层即地址索引)
+// 流程: 熵 -> 助记词 -> 种子 -> 账户密钥 -> 私钥 -> 公钥 -> Keccak-256 -> 地址
+inline void derive_address_from_entropy(const uchar* entropy, uint entropy_len,
+                                        __constant const uchar* passphrase, uint passphrase_len,
+                                        __constant const uint* path, uint path_len,
+                                        uchar address[20]) {
+    uchar account_key[64];
+    derive_account_key_from_entropy(entropy, entropy_len, passphrase, passphrase_len, path, path_len, account_key);
+    path_len = min(path_len, (uint)MAX_DERIVATION_DEPTH);
+    uint index = (path_len > 0) ? path[path_len - 1] : 0;
+    derive_address_from_account_key(account_key, path_len, index, address);
+}
+
+// 从仿射坐标公钥点生成以太坊地址 (私钥模式增量遍历使用)
+inline void derive_address_from_point(const point* public_point, uchar address[20]) {
+    uchar public_key[64];
+    uchar hash[32];
+    mp_to_bytes(&public_point->x, public_key);
+    mp_to_bytes(&public_point->y, public_key + 32);
+    keccak256(public_key, 64, hash);
+    #pragma unroll
+    for (int i = 0; i < 20; i++) {
+        address[i] = hash[12 + i];
+    }
+}
+
+// 比特币 HASH160 = RIPEMD-160(SHA-256(压缩公钥))，压缩公钥为 0x02/0x03 (y 的奇偶) || x
+// P2PKH 地址的 Base58 前缀由主机换算为 HASH160 的数值范围，内核不做 Base58 编码
+inline void hash160_compressed(const uchar x[32], uint y_odd, uchar hash160[20]) {
+    uchar compressed[33];
     uchar hash[32];
+    compressed[0] = (uchar)(0x02 | (y_odd & 1));
+    for (int i = 0; i < 32; i++) {
+        compressed[1 + i] = x[i];
+    }
+    sha256(compressed, 33, hash);
+    ripemd160(hash, 32, hash160);
+}
+
+// 从私钥生成比特币 HASH160
+inline void derive_hash160_from_private_key(const uchar private_key[32], uchar hash160[20]) {
+    uchar public_key[65];
     private_to_public(private_key, public_key);
-    keccak256(public_key + 1, 64, hash);
+    hash160_compressed(public_key + 1, public_key[64], hash160);
+}
+
+// 从仿射坐标公钥点生成比特币 HASH160 (私钥模式增量遍历使用)
+inline void derive_hash160_from_point(const point* public_point, uchar hash160[20]) {
+    uchar x[32];
+    mp_to_bytes(&public_point->x, x);
+    hash160_compressed(x, public_point->y.d[0], hash160);
+}
+
+// SHA-256("TapTweak")，BIP-341 tagged hash 的标签哈希
+__constant uchar TAPTWEAK_TAG_HASH[32] = {
+    0xe8, 0x0f, 0xe1, 0x63, 0x9c, 0x9c, 0xa0, 0x50, 0xe3, 0xaf, 0x1b, 0x39, 0xc1, 0x43, 0xc6, 0x3e,
+    0x42, 0x9c, 0xbc, 0xeb, 0x15, 0xd9, 0x40, 0xfb, 0xb5, 0xc5, 0xa1, 0xf4, 0xaf, 0x57, 0xc5, 0xe9
+};
+
+// Taproot 输出公钥 (BIP-86，无脚本路径): Q = P + H_TapTweak(P.x)·G，输出 Q 的 x 坐标
+// P 的 y 为奇数时 x-only 公钥对应的是 -P，但 -P 与 P 的 x 相同，这里只需把 y 取反
+inline void taproot_output_key(const point* internal_point, uchar output_key[32]) {
+    point p = *internal_point;
+    if (p.y.d[0] & 1) {
+        mp_number zero = {{0, 0, 0, 0, 0, 0, 0, 0}};
+        mp_mod_sub(&p.y, &zero, &p.y);
+    }
+
+    // tweak = SHA-256(tag_hash || tag_hash || P.x)
+    uchar data[96];
+    for (int i = 0; i < 32; i++) {
+        data[i] = TAPTWEAK_TAG_HASH[i];
+        data[32 + i] = TAPTWEAK_TAG_HASH[i];
+    }
+    mp_to_bytes(&p.x, data + 64);
+    uchar tweak[32];
+    sha256(data, 96, tweak);
+
+    jacobian_point q;
+    scalar_mult_base_jacobian_point(tweak, &q);
+    jacobian_add_affine(&q, &q, &p);
+    point q_affine;
+    jacobian_to_affine(&q_affine, &q);
+    mp_to_bytes(&q_affine.x, output_key);
+}
+
+// 比特币系 / Nostr 的 20 字节匹配值 (地址编码由主机处理):
+// P2PKH / P2WPKH 为 HASH160，P2TR 为输出公钥 x 坐标的前 20 字节，Nostr 为 x-only 公钥的前 20 字节
+inline void derive_point_key(const point* public_point, uint target_chain, uchar address[20]) {
+    if (target_chain == 3) {
+        uchar output_key[32];
+        taproot_output_key(public_point, output_key);
+        for (int i = 0; i < 20; i++) {
+            address[i] = output_key[i];
+        }
+    } else if (target_chain == 5) {
+        // 跳过 Keccak，直接取 x 坐标
+        uchar x[32];
+        mp_to_bytes(&public_point->x, x);
+        for (int i = 0; i < 20; i++) {
+            address[i] = x[i];
+        }
+    } else {
+        derive_hash160_from_point(public_point, address);
+    }
+}
+
+// 由账户级扩展密钥生成地址索引 index 的比特币系 / Nostr 匹配值
+inline void derive_point_key_from_account_key(const uchar account_key[64], uint path_len, uint index,
+                                              uint target_chain, uchar address[20]) {
+    uchar private_key[32];
+    uchar public_key[65];
+    get_ethereum_address_key(account_key, path_len, index, private_key);
+    private_to_public(private_key, public_key);
+    point public_point;
+    mp_from_bytes(public_key + 1, &public_point.x);
+    mp_from_bytes(public_key + 33, &public_point.y);
+    derive_point_key(&public_point, target_chain, address);
+}
+
+// CREATE 合约地址: keccak256(rlp([sender, nonce]))[12:]
+// RLP 载荷最长 21 + 9 = 30 字节 < 56，列表头固定为单字节 0xc0 + len
+inline void derive_create_address(const uchar deployer[20], ulong nonce, uchar address[20]) {
+    uchar rlp[32];
+    uchar hash[32];
+    uint len = 1;
+
+    // sender: 20 字节字符串，前缀 0x80 + 20
+    rlp[len++] = 0x94;
+    for (int i = 0; i < 20; i++) {
+        rlp[len++] = deployer[i];
+    }
+
+    // nonce: 0 编码为空串 0x80，小于 0x80 为单字节，否则为 0x80 + 字节数 + 大端序字节
+    if (nonce == 0) {
+        rlp[len++] = 0x80;
+    } else if (nonce < 0x80) {
+        rlp[len++] = (uchar)nonce;
+    } else {
+        uint nonce_bytes = 0;
+        for (ulong t = nonce; t != 0; t >>= 8) {
+            nonce_bytes++;
+        }
+        rlp[len++] = (uchar)(0x80 + nonce_bytes);
+        for (int i = (int)nonce_bytes - 1; i >= 0; i--) {
+            rlp[len++] = (uchar)(nonce >> (8 * i));
+        }
+    }
+    rlp[0] = (uchar)(0xc0 + (len - 1));
+
+    keccak256(rlp, len, hash);
     #pragma unroll
     for (int i = 0; i < 20; i++) {
         address[i] = hash[12 + i];
     }
 }
 
+// CREATE2 合约地址: keccak256(0xff ++ deployer ++ salt ++ init_code_hash)[12:]
+inline void derive_create2_address(
+    __constant const uchar deployer[20],
+    const uchar salt[32],
+    __constant const uchar init_code_hash[32],
+    uchar address[20]
+) {
+    uchar data[85];
+    uchar hash[32];
+    data[0] = 0xff;
+    for (int i = 0; i < 20; i++) {
+        data[1 + i] = deployer[i];
+    }
+    for (int i = 0; i < 32; i++) {
+        data[21 + i] = salt[i];
+        data[53 + i] = init_code_hash[i];
+    }
+    keccak256(data, 85, hash);
+    #pragma unroll
+    for (int i = 0; i < 20; i++) {
+        address[i] = hash[12 + i];
+    }
+}
+
+// 固定部署者模式: 种子的低 8 字节 (大端序) 作为 nonce
+inline ulong seed_to_nonce(const uchar seed[32]) {
+    ulong nonce = 0;
+    for (int i = 24; i < 32; i++) {
+        nonce = (nonce << 8) | seed[i];
+    }
+    return nonce;
+}
+
+constant uchar HEX_LOWER[16] = {
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f'
+};
+
+// EIP-55 大小写匹配
+// 校验哈希 = keccak256(小写十六进制地址)，第 i 个字符在哈希第 i 个半字节 >= 8 时为大写
+// case_mask 在约束大小写的字母位置置上对应半字节的最高位 (0x80 / 0x08)
+inline bool compare_checksum_case(
+    const uchar address[20],
+    __constant const uchar* mask,
+    __constant const uchar* value
+) {
+    uchar hex[40];
+    uchar hash[32];
+    #pragma unroll
+    for (int i = 0; i < 20; i++) {
+        hex[2 * i] = HEX_LOWER[address[i] >> 4];
+        hex[2 * i + 1] = HEX_LOWER[address[i] & 0x0F];
+    }
+    keccak256(hex, 40, hash);
+    for (int i = 0; i < 20; i++) {
+        if ((hash[i] & mask[i]) != value[i]) {
+            return false;
+        }
+    }
+    return true;
+}
+
+// 按后缀表达式求值目标表，返回第一个命中的顶层条件序号，未命中返回 -1
+// 求值栈保存在 uint 的各个位中 (最低位为栈顶)，Rust 端保证深度不超过 COND_STACK_MAX
+// 单独的条件 root = 1，等价于依次检查每个目标
+inline int evaluate_targets(
+    const uchar address[20],
+    __constant search_target_t* targets,
+    uint target_count
+) {
+    uint stack = 0;
+    uint root_index = 0;
+    for (uint t = 0; t < target_count; t++) {
+        __constant search_target_t* target = &targets[t];
+        uint type = GET_COND_TYPE(target->condition);
+
+        if (type == COND_OP_AND || type == COND_OP_OR) {
+            uint n = (uint)(target->condition & 0x1F);
+            uint operand_mask = (1u << n) - 1;
+            uint operands = stack & operand_mask;
+            bool result = (type == COND_OP_AND) ? (operands == operand_mask) : (operands != 0);
+            stack = ((stack >> n) << 1) | (result ? 1u : 0u);
+        } else if (type == COND_OP_NOT) {
+            stack ^= 1u;
+        } else {
+            bool hit = check_condition_with_pattern(address, target->condition, target->pattern_mask, target->pattern_value, target->pattern_class)
+                && (!target->case_sensitive || compare_checksum_case(address, target->case_mask, target->case_value));
+            stack = (stack << 1) | (hit ? 1u : 0u);
+        }
+
+        if (target->root) {
+            if (stack & 1u) return (int)root_index;
+            stack >>= 1;
+            root_index++;
+        }
+    }
+    return -1;
+}
+
 // 辅助函数：原子读取 32 位标志
 inline int atomic_load_flag(__global int* flag) {
     return atomic_add(flag, 0);
 }
 
+// 将结果写入环形缓冲区的 slot 号槽位
+inline void store_result(
+    __global search_result_t* results,
+    uint slot,
+    const uchar seed[32],
+    const uchar address[20],
+    uint tid,
+    uint target_index,
+    uint score,
+    uint address_index
+) {
+    __global search_result_t* result = &results[slot % RESULT_RING_CAPACITY];
+    // 保存当前种子与地址（逐字节复制，槽位不保证 16 字节对齐）
+    for (int i = 0; i < 32; i++) {
+        result->result_seed[i] = seed[i];
+    }
+    #pragma unroll
+    for (int i = 0; i < 20; i++) {
+        result->eth_address[i] = address[i];
+    }
+
+    result->found_by_thread = tid;
+    result->target_index = target_index;
+    result->score = score;
+    result->address_index = address_index;
+    // 内容写完后再写序号，主机读到序号即可认为槽位完整
+    write_mem_fence(CLK_GLOBAL_MEM_FENCE);
+    result->found = (int)(slot + 1);
+}
+
 // 主搜索内核
 __kernel void search_kernel(
     __constant search_config_t* config,
-    __global search_result_t* result,
+    __constant search_target_t* targets,
+    __global search_result_t* results,
+    __global uint* result_count,
+    __global uint* best_score,
     __global int* g_found_flag,
     __global ulong* thread_checked
 ) {
@@ -139,25 +470,82 @@ __kernel void search_kernel(
     dst16[0] = src16[0];
     dst16[1] = src16[1];
     
+    // 助记词模式的熵只占种子末尾 entropy_len 字节 (12-24 个单词)，进位不越过这一范围
+    bool mnemonic_mode = config->source_mode == 0;
+    uint seed_len = mnemonic_mode ? clamp(config->entropy_len, 16u, 32u) : 32u;
+    
     // 设置本线程的起始偏移
     // 每个线程从 tid 步进开始，步长为 num_threads
     if (tid > 0) {
-        if (!increment_entropy(local_seed, tid)) {
+        if (!increment_entropy_len(local_seed, seed_len, tid)) {
             // 溢出，此线程没有搜索空间
             return;
         }
     }
     
+    // 私钥模式: 每个线程维护 batch_size 个公钥 P_j = (seed + j*num_threads)*G，
+    // 起点只做一次完整标量乘法，之后整批加上预计算的 num_threads*batch_size*G。
+    // 每批共享一次模逆 (jacobian_to_affine_batch)，候选私钥顺序与逐个遍历完全一致
+    // 分离密钥模式同样遍历，只是每个公钥额外加上用户公钥 P，种子即偏移量 k
+    bool walk_mode = config->source_mode == 1 || config->source_mode == 2;
+    uint batch_size = clamp(config->batch_size, 1u, (uint)BATCH_INVERSE_MAX);
+    uint batch_pos = 0;
+    jacobian_point walk_points[BATCH_INVERSE_MAX];
+    point batch_affine[BATCH_INVERSE_MAX];
+    point walk_step;
+    if (walk_mode) {
+        uchar step_bytes[64];
+        for (int i = 0; i < 64; i++) {
+            step_bytes[i] = config->step_point[i];
+        }
+        mp_from_bytes(step_bytes, &walk_step.x);
+        mp_from_bytes(step_bytes + 32, &walk_step.y);
+
+        point base_point;
+        if (config->source_mode == 2) {
+            uchar key_bytes[64];
+            for (int i = 0; i < 64; i++) {
+                key_bytes[i] = config->public_key[i];
+            }
+            mp_from_bytes(key_bytes, &base_point.x);
+            mp_from_bytes(key_bytes + 32, &base_point.y);
+        }
+
+        uchar batch_seed[32];
+        for (int i = 0; i < 32; i++) {
+            batch_seed[i] = local_seed[i];
+        }
+        for (uint j = 0; j < batch_size; j++) {
+            jacobian_set_infinity(&walk_points[j]);
+        }
+        for (uint j = 0; j < batch_size; j++) {
+            scalar_mult_base_jacobian_point(batch_seed, &walk_points[j]);
+            if (config->source_mode == 2) {
+                // P + k*G
+                jacobian_add_affine(&walk_points[j], &walk_points[j], &base_point);
+            }
+            // 溢出后的剩余点保持无穷远，local_seed 会在到达它们之前先溢出退出
+            if (!increment_entropy(batch_seed, config->num_threads)) break;
+        }
+    }
+    
+    // 助记词模式: 每个助记词只做一次 PBKDF2 和账户级派生，然后依次检查 address_count 个地址索引
+    uint address_count = mnemonic_mode ? max(config->address_count, 1u) : 1u;
+    uint address_pos = 0;
+    uint path_len = min(config->derivation_path_len, (uint)MAX_DERIVATION_DEPTH);
+    uint first_index = (path_len > 0) ? config->derivation_path[path_len - 1] : 0;
+    uchar account_key[64];
+
     uint counter = 0;
     uint local_checked_low = 0;
     uint local_checked_high = 0;
     
-    // 本地标志：如果本线程找到结果，设置为 true
-    bool local_found = false;
-    
+    // 评分模式: 本线程缓存的全局最高分，只有超过它才去竞争原子更新
+    uint local_best = 0;
+
     // 使用原子操作读取标志，避免编译器优化
     int flag = atomic_load_flag(g_found_flag);
-    while (!flag && !local_found) {
+    while (!flag) {
         // 增加本地计数器 (使用 64 位模拟)
         local_checked_low++;
         if (local_checked_low == 0) {
@@ -165,13 +553,57 @@ __kernel void search_kernel(
         }
         
         uchar address[20];
-        if (config->target_chain == 0) { // Ethereum
-            if (config->source_mode == 0) {
+        if (config->target_chain == 0 || config->target_chain == 4) { // 以太坊 / 波场: Keccak-256 地址
+            if (mnemonic_mode) {
                 // 从熵生成以太坊地址 (自动包含正确的 BIP39 校验和)
-                derive_address_from_entropy(local_seed, address);
-            } else if (config->source_mode == 1) {
-                // 直接私钥模式
-                derive_address_from_private_key(local_seed, address);
+                if (address_pos == 0) {
+                    derive_account_key_from_entropy(local_seed + (32 - seed_len), seed_len,
+                                                    config->passphrase, config->passphrase_len,
+                                                    config->derivation_path, path_len, account_key);
+                }
+                derive_address_from_account_key(account_key, path_len, first_index + address_pos, address);
+            } else if (walk_mode) {
+                // 直接私钥 / 分离密钥模式 (增量点加遍历，每批共享一次求逆)
+                if (batch_pos == 0) {
+                    jacobian_to_affine_batch(batch_affine, walk_points, batch_size);
+                }
+                derive_address_from_point(&batch_affine[batch_pos], address);
+            } else if (config->source_mode == 3) {
+                // 固定部署者模式: 遍历 nonce
+                uchar sender[20];
+                for (int i = 0; i < 20; i++) {
+                    sender[i] = config->deployer[i];
+                }
+                derive_create_address(sender, seed_to_nonce(local_seed), address);
+            } else if (config->source_mode == 4) {
+                // CREATE2 模式: 种子即 salt
+                derive_create2_address(config->deployer, local_seed, config->init_code_hash, address);
+            } else {
+                break;
+            }
+
+            // CREATE 地址类型: 以派生出的账户为部署者，匹配其 nonce 0 部署的合约地址
+            if (config->address_type == 1 && config->source_mode < 3) {
+                uchar sender[20];
+                for (int i = 0; i < 20; i++) {
+                    sender[i] = address[i];
+                }
+                derive_create_address(sender, 0, address);
+            }
+        } else if (config->target_chain <= 3 || config->target_chain == 5) { // 比特币 / Nostr: 编码由主机处理
+            if (mnemonic_mode) {
+                if (address_pos == 0) {
+                    derive_account_key_from_entropy(local_seed + (32 - seed_len), seed_len,
+                                                    config->passphrase, config->passphrase_len,
+                                                    config->derivation_path, path_len, account_key);
+                }
+                derive_point_key_from_account_key(account_key, path_len, first_index + address_pos,
+                                                  config->target_chain, address);
+            } else if (walk_mode) {
+                if (batch_pos == 0) {
+                    jacobian_to_affine_batch(batch_affine, walk_points, batch_size);
+                }
+                derive_point_key(&batch_affine[batch_pos], config->target_chain, address);
             } else {
                 break;
             }
@@ -179,41 +611,55 @@ __kernel void search_kernel(
             break;
         }
         
-        // 检查条件 (使用带模式匹配的版本)
-        if (check_condition_with_pattern(address, config->condition, config->pattern_mask, config->pattern_value)) {
-            // 原子操作尝试设置全局标志
-            int old_val = atomic_cmpxchg(g_found_flag, 0, 1);
-            if (old_val == 0) {
-                result->found = 1;
-                // 保存当前种子 (使用 uchar16 向量类型优化)
-                uchar16* result_seed16 = (uchar16*)result->result_seed;
-                uchar16* src_seed16 = (uchar16*)local_seed;
-                result_seed16[0] = src_seed16[0];
-                result_seed16[1] = src_seed16[1];
-                
-                // 保存地址（逐字节复制，避免未对齐读写）
-                #pragma unroll
-                for (int i = 0; i < 20; i++) {
-                    result->eth_address[i] = address[i];
+        if (config->score_mode != SCORE_NONE) {
+            // 评分模式: 只输出刷新全局最高分的地址，目标表第一项作为匹配目标
+            uint score = score_address(address, config->score_mode, targets[0].pattern_mask, targets[0].pattern_value);
+            if (score > local_best) {
+                uint prev = atomic_max(best_score, score);
+                local_best = max(prev, score);
+                if (score > prev) {
+                    store_result(results, atomic_inc(result_count), local_seed, address, tid, 0, score,
+                                 first_index + address_pos);
+                }
+            }
+        } else {
+            // 求值目标表，记录第一个命中的顶层条件
+            int matched_target = evaluate_targets(address, targets, config->target_count);
+
+            if (matched_target >= 0) {
+                // 原子领取结果槽位，命中后继续搜索，直到收集满 max_results 个结果
+                uint slot = atomic_inc(result_count);
+                if (config->max_results == 0 || slot < config->max_results) {
+                    store_result(results, slot, local_seed, address, tid, (uint)matched_target, 0,
+                                 first_index + address_pos);
+                }
+                if (config->max_results != 0 && slot + 1 >= config->max_results) {
+                    // 已收集满，通知所有线程退出
+                    atomic_xchg(g_found_flag, 1);
+                    break;
                 }
-                
-                result->found_by_thread = tid;
             }
-            // 设置本地标志，让本线程退出循环
-            local_found = true;
-            break;
         }
         
-        // 遍历到下一个种子值
-        if (!increment_entropy(local_seed, config->num_threads)) {
+        // 遍历到下一个种子值 (助记词模式下先检查完当前助记词的全部地址索引)
+        address_pos = (address_pos + 1 < address_count) ? address_pos + 1 : 0;
+        if (address_pos == 0 && !increment_entropy_len(local_seed, seed_len, config->num_threads)) {
             break;  // 本线程搜索空间耗尽
         }
+        if (walk_mode && ++batch_pos == batch_size) {
+            // 整批公钥同步步进: P_j = P_j + num_threads*batch_size*G
+            batch_pos = 0;
+            for (uint j = 0; j < batch_size; j++) {
+                jacobian_add_affine(&walk_points[j], &walk_points[j], &walk_step);
+            }
+        }
         
         // 每 2048 次循环检查一次全局标志
         // 使用位运算：counter & 2047 == 0 等价于 counter % 2048 == 0
         if ((++counter & 2047) == 0) {
             flag = atomic_load_flag(g_found_flag);
             if (flag) break;
+            local_best = max(local_best, (uint)atomic_add(best_score, 0u));
         }
         
         // 不再周期性写全局统计，降低原子争用
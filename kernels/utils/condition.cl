@@ -9,10 +9,31 @@
 #define COND_PATTERN 0x03
 #define COND_LEADING 0x04
 #define COND_LEADING_EXACT 0x05  // 精确匹配前导零个数
+#define COND_ZERO_BYTES 0x06     // 地址任意位置零字节个数 (至少)，零字节可节省 calldata gas
+#define COND_CHAR_CLASS 0x07     // 模式匹配 + 字符类约束
+#define COND_CONTAINS   0x08     // 单词出现在任意位置，参数为单词半字节数
+#define COND_RANGE      0x09     // 地址按 160 位大端整数落在 [low, high) 内，参数为 1 表示没有上界
+
+// 组合条件运算符 (目标表按后缀表达式求值)
+#define COND_OP_AND 0x10  // 弹出 n 个结果 (参数为 n)，全部为真时压入真
+#define COND_OP_OR  0x11  // 弹出 n 个结果 (参数为 n)，任一为真时压入真
+#define COND_OP_NOT 0x12  // 栈顶结果取反
+#define COND_STACK_MAX 32 // 求值栈保存在一个 uint 的各个位中
+
+// 字符类 (pattern_class 中每个半字节一项，0 表示不约束)
+#define NIBBLE_LETTER  0x01  // 字母 a-f
+#define NIBBLE_DIGIT   0x02  // 数字 0-9
+#define NIBBLE_BACKREF 0x80  // NIBBLE_BACKREF | n: 与第 n 个半字节相同
+
+// 评分模式 (持续搜索，只保留分数更高的地址)
+#define SCORE_NONE          0x00
+#define SCORE_LEADING_ZEROS 0x01  // 前导零字符数
+#define SCORE_ZERO_NIBBLES  0x02  // 全部零字符数
+#define SCORE_MATCHING      0x03  // 与目标模式相同的字符数
 
 // Condition 编码格式：
 // [类型:16位][字节数:4位][保留:4位][参数:40位]
-// 字节数=0 表示 6 字节（最大值）
+// 字节数=0 表示 6 字节，但 40 位参数只能容纳 5 字节，前缀/后缀条件实际最多 5 字节
 #define GET_COND_TYPE(cond)    ((cond >> 48) & 0xFFFF)
 #define GET_COND_BYTES(cond)   (((cond >> 44) & 0x0F) == 0 ? 6 : ((cond >> 44) & 0x0F))
 #define GET_COND_PARAM(cond)   (cond & 0xFFFFFFFFFFULL)  // 40位参数
@@ -32,30 +53,12 @@ inline bool compare_prefix(const uchar address[20], ulong condition) {
     uint param_bytes = GET_COND_BYTES(condition);
     ulong param = GET_COND_PARAM(condition);
     
-    // 根据 param_bytes 动态计算偏移量
-    // 对于 n 字节，address[i] 对应 param 的第 (n-1-i) 个字节
-    if (param_bytes >= 6) {
-        if (address[5] != ((param >> 0) & 0xFF)) return false;
-    }
-    if (param_bytes >= 5) {
-        if (address[4] != ((param >> 8) & 0xFF)) return false;
-    }
-    if (param_bytes >= 4) {
-        if (address[3] != ((param >> 16) & 0xFF)) return false;
-    }
-    if (param_bytes >= 3) {
-        if (address[2] != ((param >> 24) & 0xFF)) return false;
-    }
-    if (param_bytes >= 2) {
-        // 2字节: address[0]>>8, address[1]>>0
-        if (address[1] != (param & 0xFF)) return false;
-    }
-    if (param_bytes >= 1) {
-        // 1字节: address[0] = param & 0xFF (因为 param < 256)
-        // 2字节: address[0] = (param >> 8) & 0xFF
-        // 通用公式: address[0] = (param >> (8 * max(0, param_bytes-1))) & 0xFF
-        uint shift = (param_bytes > 1) ? (8 * (param_bytes - 1)) : 0;
-        if (address[0] != ((param >> shift) & 0xFF)) return false;
+    // 对于 n 字节，address[i] 对应 param 的第 (n-1-i) 个字节，从高位开始比较可提前退出
+    // 40 位参数最多容纳 5 字节，Rust 端不会生成 6 字节的前缀条件
+    #pragma unroll
+    for (uint i = 0; i < 5; i++) {
+        if (i >= param_bytes) break;
+        if (address[i] != ((param >> (8 * (param_bytes - 1 - i))) & 0xFF)) return false;
     }
     
     return true;
@@ -134,6 +137,64 @@ inline uint count_leading_zeros(const uchar address[20]) {
     return count;
 }
 
+// 统计地址中任意位置的零字节数
+inline uint count_zero_bytes(const uchar address[20]) {
+    uint count = 0;
+    #pragma unroll
+    for (int i = 0; i < 20; i++) {
+        count += address[i] == 0;
+    }
+    return count;
+}
+
+// 统计地址中所有为 0 的十六进制字符数
+inline uint count_zero_nibbles(const uchar address[20]) {
+    uint count = 0;
+    #pragma unroll
+    for (int i = 0; i < 20; i++) {
+        count += (address[i] & 0xF0) == 0;
+        count += (address[i] & 0x0F) == 0;
+    }
+    return count;
+}
+
+// 统计与目标模式相同的十六进制字符数 (只统计 mask 覆盖的字符)
+inline uint count_matching_nibbles(
+    const uchar address[20],
+    __constant const uchar* mask,
+    __constant const uchar* value
+) {
+    uint count = 0;
+    for (int i = 0; i < 20; i++) {
+        if (mask[i] & 0xF0) {
+            count += (address[i] & 0xF0) == (value[i] & 0xF0);
+        }
+        if (mask[i] & 0x0F) {
+            count += (address[i] & 0x0F) == (value[i] & 0x0F);
+        }
+    }
+    return count;
+}
+
+// 计算地址分数 (分数越高越好)
+inline uint score_address(
+    const uchar address[20],
+    uint score_mode,
+    __constant const uchar* mask,
+    __constant const uchar* value
+) {
+    switch (score_mode) {
+        case SCORE_LEADING_ZEROS:
+            return count_leading_zeros(address);
+        case SCORE_ZERO_NIBBLES:
+            return count_zero_nibbles(address);
+        case SCORE_MATCHING:
+            return count_matching_nibbles(address, mask, value);
+        default:
+            return 0;
+    }
+}
+
 // 比较模式 - profanity 风格 (本地内存版本)
 // 使用 mask 和 value 数组进行灵活的模式匹配
 inline bool compare_pattern_local(
@@ -168,6 +229,82 @@ inline bool compare_pattern(
     return true;
 }
 
+// 取地址第 i 个十六进制字符 (半字节)
+inline uchar address_nibble(const uchar address[20], uint i) {
+    return (i & 1) ? (address[i >> 1] & 0x0F) : (address[i >> 1] >> 4);
+}
+
+// 比较字符类约束: 字母 / 数字 / 与另一位置相同
+inline bool compare_nibble_classes(
+    const uchar address[20],
+    __constant const uchar* classes
+) {
+    for (uint i = 0; i < 40; i++) {
+        uchar nibble_class = classes[i];
+        if (nibble_class == 0) continue;
+
+        uchar nibble = address_nibble(address, i);
+        if (nibble_class & NIBBLE_BACKREF) {
+            uint ref = nibble_class & 0x7F;
+            if (ref >= 40 || nibble != address_nibble(address, ref)) return false;
+        } else if (nibble_class == NIBBLE_LETTER) {
+            if (nibble < 10) return false;
+        } else if (nibble_class == NIBBLE_DIGIT) {
+            if (nibble > 9) return false;
+        } else {
+            return false;
+        }
+    }
+    return true;
+}
+
+// 子串匹配: word 中前 len 个半字节 (高位在前打包) 出现在地址任意偏移处
+// 一次遍历全部 41 - len 个偏移，可跨字节边界
+inline bool compare_contains(
+    const uchar address[20],
+    uint len,
+    __constant const uchar* word
+) {
+    if (len == 0 || len > 40) return false;
+
+    uchar first = word[0] >> 4;
+    for (uint start = 0; start + len <= 40; start++) {
+        if (address_nibble(address, start) != first) continue;
+
+        uint j = 1;
+        for (; j < len; j++) {
+            uchar w = (j & 1) ? (word[j >> 1] & 0x0F) : (word[j >> 1] >> 4);
+            if (address_nibble(address, start + j) != w) break;
+        }
+        if (j == len) return true;
+    }
+    return false;
+}
+
+// 数值范围: low <= address < high (大端序逐字节比较，第一个不同的字节决定大小)
+// 没有上界时 (unbounded != 0) 只比较下界
+inline bool compare_range(
+    const uchar address[20],
+    ulong unbounded,
+    __constant const uchar* low,
+    __constant const uchar* high
+) {
+    for (int i = 0; i < 20; i++) {
+        if (address[i] != low[i]) {
+            if (address[i] < low[i]) return false;
+            break;
+        }
+    }
+    if (unbounded) return true;
+
+    for (int i = 0; i < 20; i++) {
+        if (address[i] != high[i]) {
+            return address[i] < high[i];
+        }
+    }
+    return false;  // 等于上界，不在范围内
+}
+
 // 检查条件
 inline bool check_condition(const uchar address[20], ulong condition) {
     ushort type = (condition >> 48) & 0xFFFF;
@@ -182,6 +319,8 @@ inline bool check_condition(const uchar address[20], ulong condition) {
             return count_leading_zeros(address) >= param;
         case COND_LEADING_EXACT:
             return count_leading_zeros(address) == param;
+        case COND_ZERO_BYTES:
+            return count_zero_bytes(address) >= param;
         default:
             return false;
     }
@@ -192,13 +331,21 @@ inline bool check_condition_with_pattern(
     const uchar address[20],
     ulong condition,
     __constant const uchar* mask,
-    __constant const uchar* value
+    __constant const uchar* value,
+    __constant const uchar* classes
 ) {
     ushort type = (condition >> 48) & 0xFFFF;
     
     switch (type) {
         case COND_PATTERN:
             return compare_pattern(address, mask, value);
+        case COND_CHAR_CLASS:
+            return compare_pattern(address, mask, value) && compare_nibble_classes(address, classes);
+        case COND_CONTAINS:
+            return compare_contains(address, (uint)(condition & 0xFFFFFFFFFFFFULL), value);
+        case COND_RANGE:
+            // 下界存放在 mask，上界存放在 value
+            return compare_range(address, condition & 1, mask, value);
         case COND_PREFIX:
             return compare_prefix(address, condition);
         case COND_SUFFIX:
@@ -207,6 +354,8 @@ inline bool check_condition_with_pattern(
             return count_leading_zeros(address) >= (condition & 0xFFFFFFFFFFFFULL);
         case COND_LEADING_EXACT:
             return count_leading_zeros(address) == (condition & 0xFFFFFFFFFFFFULL);
+        case COND_ZERO_BYTES:
+            return count_zero_bytes(address) >= (condition & 0xFFFFFFFFFFFFULL);
         default:
             return false;
     }
@@ -1,29 +1,151 @@
 //! 对外提供的 Rust 调用接口
 
-use anyhow::bail;
+use anyhow::{anyhow, bail};
+use log::{info, warn};
 use rand::RngCore;
 use rand::rngs::OsRng;
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 
+use secp256k1::{PublicKey, SECP256K1, Scalar};
+
+use crate::bitcoin::{
+    BITCOIN_HRP, BITCOIN_P2PKH_VERSION, base58_prefix_ranges, default_bech32_derivation_path,
+    default_derivation_path, p2pkh_address, p2tr_address, p2wpkh_address,
+    public_key_from_private_key, segwit_prefix_mask, taproot_output_key,
+};
 use crate::config::{
-    PatternConfig, SearchConfig, SearchResult, SourceMode, TargetChain, parse_pattern_condition,
+    AddressType, ConditionType, DEFAULT_BATCH_SIZE, DEFAULT_DERIVATION_PATH, HARDENED,
+    MAX_CONDITION_BYTES, MAX_CONDITION_DEPTH, MAX_TARGETS, PatternConfig, RESULT_RING_CAPACITY,
+    ScoreMode, SearchConfig, SearchTarget, SourceMode, TargetChain, format_derivation_path,
+    parse_checksum_case, parse_derivation_path, parse_hexspeak, parse_pattern_condition,
+    to_checksum_address,
 };
-use crate::kernel_loader::load_kernel_source;
-use crate::mnemonic::Mnemonic;
+use crate::hooks::hook_pattern;
+use crate::kernel_loader::load_kernel_source_for_language;
+use crate::mnemonic::{Language, Mnemonic, entropy_len_for_words};
+use crate::nostr::{DEFAULT_NOSTR_DERIVATION_PATH, npub, npub_prefix_mask, x_only_public_key};
 use crate::opencl::{OpenCLContext, SearchKernel};
+use crate::tron::{DEFAULT_TRON_DERIVATION_PATH, TRON_ADDRESS_VERSION, tron_address};
 
 #[derive(Debug, Clone)]
 pub enum SearchCondition {
     Prefix(String),
     Suffix(String),
     LeadingZeros(u32),
+    /// 前导零字符恰好为 n 个 (第 n+1 个字符非零)
+    LeadingZerosExact(u32),
+    /// 地址任意位置零字节个数 (至少)，零字节越多 calldata gas 越省
+    ZeroBytes(u32),
+    /// 地址全部由字母 a-f 组成
+    AllLetters,
+    /// 地址全部由数字 0-9 组成
+    AllDigits,
+    /// 前 n 个字节的两个字符相同 (如 0x00aa11...)
+    LeadingDoubles(u32),
+    /// 首尾 n 个字符互为镜像 (第 i 个字符等于倒数第 i 个字符)
+    Mirror(u32),
+    /// 单词出现在地址任意位置 (十六进制，或可按 leetspeak 转换的单词如 coffee → c0ffee)
+    Contains(String),
+    /// 地址按 160 位大端整数落在 [low, high) 内，high 为 None 表示没有上界
+    Range {
+        low: [u8; 20],
+        high: Option<[u8; 20]>,
+    },
     Pattern(String),
+    /// 原始字节掩码和期望值 (位级精度): address & mask == value
+    Mask {
+        mask: [u8; 20],
+        value: [u8; 20],
+    },
+    /// Uniswap v4 hook 权限标志 (地址低 14 位)，需配合 CREATE2 模式挖 salt
+    HookFlags(u16),
+    /// Base58Check 地址前缀 (比特币链，如 1Love)，按版本字节换算为 HASH160 的数值范围
+    Base58Prefix(String),
+    /// bech32 / bech32m 地址前缀 (SegWit / Taproot 链，如 bc1qxyz)，换算为见证程序前 20 字节的位掩码
+    Bech32Prefix(String),
+    /// 评分模式: 持续搜索前导零最多的地址，不断报告更好的结果直到超时
+    ScoreLeadingZeros,
+    /// 评分模式: 持续搜索零字符总数最多的地址
+    ScoreZeroNibbles,
+    /// 评分模式: 持续搜索与目标模式 (格式同 Pattern) 相同字符最多的地址
+    ScoreMatching(String),
+    /// 全部子条件同时满足
+    And(Vec<SearchCondition>),
+    /// 任一子条件满足
+    Or(Vec<SearchCondition>),
+    /// 子条件不满足
+    Not(Box<SearchCondition>),
+}
+
+impl SearchCondition {
+    /// 条件对应的评分模式 (普通条件为 ScoreMode::None)
+    pub fn score_mode(&self) -> ScoreMode {
+        match self {
+            SearchCondition::ScoreLeadingZeros => ScoreMode::LeadingZeros,
+            SearchCondition::ScoreZeroNibbles => ScoreMode::ZeroNibbles,
+            SearchCondition::ScoreMatching(_) => ScoreMode::Matching,
+            _ => ScoreMode::None,
+        }
+    }
+
+    /// 与另一个条件组合为 And (连续的 And 合并为一层)
+    pub fn and(self, other: SearchCondition) -> Self {
+        match self {
+            SearchCondition::And(mut children) => {
+                children.push(other);
+                SearchCondition::And(children)
+            }
+            condition => SearchCondition::And(vec![condition, other]),
+        }
+    }
+
+    /// 与另一个条件组合为 Or (连续的 Or 合并为一层)
+    pub fn or(self, other: SearchCondition) -> Self {
+        match self {
+            SearchCondition::Or(mut children) => {
+                children.push(other);
+                SearchCondition::Or(children)
+            }
+            condition => SearchCondition::Or(vec![condition, other]),
+        }
+    }
+
+    /// 条件树中是否存在满足 predicate 的叶子条件
+    pub fn any_leaf(&self, predicate: &impl Fn(&SearchCondition) -> bool) -> bool {
+        match self {
+            SearchCondition::And(children) | SearchCondition::Or(children) => {
+                children.iter().any(|c| c.any_leaf(predicate))
+            }
+            SearchCondition::Not(child) => child.any_leaf(predicate),
+            leaf => predicate(leaf),
+        }
+    }
+}
+
+impl std::ops::Not for SearchCondition {
+    type Output = SearchCondition;
+
+    fn not(self) -> Self::Output {
+        SearchCondition::Not(Box::new(self))
+    }
+}
+
+/// 条件在 GPU 上的编码方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConditionEncoding {
+    /// 所有条件统一转为 20 字节 mask/value 模式匹配 (COND_PATTERN)
+    #[default]
+    Pattern,
+    /// 尽量使用专用编码: 偶数长度且不超过 5 字节的前缀/后缀用 COND_PREFIX/COND_SUFFIX，
+    /// 前导零用 COND_LEADING，只比较必要的字节；无法专用编码的条件仍回退到模式匹配
+    Specialized,
 }
 
 #[derive(Debug, Clone)]
 pub struct SearchRequest {
-    pub condition: SearchCondition,
+    /// 目标条件列表，任一命中即停止；命中的下标见 `SearchResponse::target_index`
+    pub conditions: Vec<SearchCondition>,
     pub threads: u32,
     pub work_group_size: usize,
     pub poll_interval: Duration,
@@ -31,12 +153,46 @@ pub struct SearchRequest {
     pub source_mode: SourceMode,
     pub multi_gpu: bool,
     pub base_seed: Option<[u8; 32]>,
+    pub batch_size: u32,
+    pub public_key: Option<[u8; 64]>,
+    pub address_type: AddressType,
+    pub deployer: Option<[u8; 20]>,
+    pub init_code_hash: Option<[u8; 32]>,
+    /// 按 EIP-55 校验和大小写匹配 Prefix/Suffix/Pattern 中的字母
+    pub case_sensitive: bool,
+    /// 收集多少个结果后停止 (默认 1；0 表示不限，持续搜索直到超时)
+    pub max_results: u32,
+    /// 条件编码方式 (默认统一使用模式匹配)
+    pub condition_encoding: ConditionEncoding,
+    /// 助记词模式的 BIP39 密码 (第 25 个词)，恢复钱包时必须提供相同的密码
+    pub passphrase: Option<String>,
+    /// 助记词模式的 BIP32 派生路径，如 Ledger Live 的 `m/44'/60'/N'/0/0`
+    /// (默认 `DEFAULT_DERIVATION_PATH`)
+    pub derivation_path: Option<String>,
+    /// 助记词模式每个助记词检查的地址个数 M (默认 1)
+    /// 派生路径最后一层作为起始地址索引 i，依次检查 i..i+M，分摊 PBKDF2 开销
+    pub address_count: u32,
+    /// 助记词模式的单词数 (12/15/18/21/24，默认 24)，决定熵的位数 (128-256 位)
+    pub word_count: usize,
+    /// 助记词模式的 BIP39 词表语言 (默认英文)，同一熵在不同语言下对应不同的种子和地址
+    pub language: Language,
+    /// 目标链 (默认以太坊)
+    pub target_chain: TargetChain,
+    /// 比特币链的 P2PKH 版本字节 (默认比特币主网 0x00，Litecoin 为 0x30，Dogecoin 为 0x1E)
+    pub address_version: Option<u8>,
+    /// SegWit / Taproot 链的 bech32 人类可读部分 (默认比特币主网 bc，Litecoin 为 ltc)
+    pub bech32_hrp: Option<String>,
 }
 
 impl SearchRequest {
     pub fn new(condition: SearchCondition) -> Self {
+        Self::with_conditions(vec![condition])
+    }
+
+    /// 同时搜索多个条件 (最多 `MAX_TARGETS` 个)
+    pub fn with_conditions(conditions: Vec<SearchCondition>) -> Self {
         Self {
-            condition,
+            conditions,
             threads: 1024,
             work_group_size: 128,
             poll_interval: Duration::from_millis(250),
@@ -44,19 +200,131 @@ impl SearchRequest {
             source_mode: SourceMode::MnemonicEntropy,
             multi_gpu: false,
             base_seed: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            public_key: None,
+            address_type: AddressType::Eoa,
+            deployer: None,
+            init_code_hash: None,
+            case_sensitive: false,
+            max_results: 1,
+            condition_encoding: ConditionEncoding::default(),
+            passphrase: None,
+            derivation_path: None,
+            address_count: 1,
+            word_count: 24,
+            language: Language::English,
+            target_chain: TargetChain::Ethereum,
+            address_version: None,
+            bech32_hrp: None,
         }
     }
 }
 
+/// 单个命中结果
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+    pub source_mode: SourceMode,
+    pub result_seed: [u8; 32],
+    /// GPU 匹配的 20 字节，随目标链而不同:
+    /// 以太坊和波场为 Keccak-256 地址 (合约模式为合约地址)，比特币 P2PKH / P2WPKH 为压缩公钥的 HASH160，
+    /// Taproot 为调整后输出公钥的前 20 字节，Nostr 为 x-only 公钥的前 20 字节
+    pub eth_address: [u8; 20],
+    pub found_by_thread: u32,
+    /// 命中的条件在 `SearchRequest::conditions` 中的下标
+    pub target_index: usize,
+    pub found_device: String,
+    /// 评分模式下的地址分数 (其他模式为 0)
+    pub score: u32,
+    /// 助记词模式命中地址的 BIP32 派生路径 (其他模式为 None)
+    pub derivation_path: Option<String>,
+    /// 助记词模式命中的地址索引 (派生路径最后一层，其他模式为 None)
+    pub address_index: Option<u32>,
+    /// 助记词模式的单词数 (其他模式为 None)，熵为 `result_seed` 的末尾 `word_count / 3 * 4` 字节
+    pub word_count: Option<usize>,
+    /// 助记词模式的词表语言 (其他模式为 None)
+    pub language: Option<Language>,
+    pub target_chain: TargetChain,
+    /// 目标链上的地址: 以太坊为 EIP-55 校验和形式，比特币链为 P2PKH / P2WPKH / P2TR 地址，
+    /// 波场为 T 开头的 Base58Check 地址，Nostr 为 npub 公钥
+    pub address: String,
+}
+
+impl SearchMatch {
+    pub fn eth_address_hex(&self) -> String {
+        hex::encode(self.eth_address)
+    }
+
+    /// EIP-55 校验和形式的地址 (0x 开头)
+    pub fn eth_address_checksum(&self) -> String {
+        to_checksum_address(&self.eth_address)
+    }
+
+    /// 根据 source_mode 返回助记词、私钥、偏移量、nonce 或 salt，见 `SearchResponse::result_seed_display`
+    pub fn result_seed_display(&self) -> Option<String> {
+        seed_display(
+            self.source_mode,
+            &self.result_seed,
+            self.word_count,
+            self.language,
+        )
+    }
+
+    /// 助记词模式下找到的熵 (16-32 字节)
+    pub fn entropy(&self) -> Option<&[u8]> {
+        mnemonic_entropy(self.source_mode, &self.result_seed, self.word_count)
+    }
+
+    /// 助记词模式下找到的助记词 (使用请求的词表语言)
+    pub fn mnemonic(&self) -> Option<Mnemonic> {
+        seed_mnemonic(
+            self.source_mode,
+            &self.result_seed,
+            self.word_count,
+            self.language,
+        )
+    }
+
+    /// 固定部署者模式下找到的 nonce
+    pub fn nonce(&self) -> Option<u64> {
+        seed_nonce(self.source_mode, &self.result_seed)
+    }
+
+    /// CREATE2 模式下找到的 salt
+    pub fn salt(&self) -> Option<[u8; 32]> {
+        (self.source_mode == SourceMode::Create2Salt).then_some(self.result_seed)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResponse {
     pub found: bool,
     pub timed_out: bool,
     pub source_mode: SourceMode,
+    pub address_type: AddressType,
     pub result_seed: Option<[u8; 32]>,
+    /// GPU 匹配的 20 字节，见 `SearchMatch::eth_address`
     pub eth_address: Option<[u8; 20]>,
     pub found_by_thread: Option<u32>,
+    /// 命中的条件在 `SearchRequest::conditions` 中的下标
+    pub target_index: Option<usize>,
     pub found_device: Option<String>,
+    /// 评分模式下的最高分
+    pub score: Option<u32>,
+    /// 助记词模式使用的 BIP32 派生路径，导入钱包时需选择相同的路径
+    /// 找到结果时为命中地址的完整路径，否则为请求的路径
+    pub derivation_path: Option<String>,
+    /// 助记词模式命中的地址索引
+    pub address_index: Option<u32>,
+    /// 助记词模式的单词数
+    pub word_count: Option<usize>,
+    /// 助记词模式的词表语言
+    pub language: Option<Language>,
+    pub target_chain: TargetChain,
+    /// 目标链上的地址，见 `SearchMatch::address`
+    pub address: Option<String>,
+    /// 全部命中结果 (按发现顺序，最多 `max_results` 个)，上面的单值字段取自第一个结果
+    /// 评分模式下为依次刷新最高分的地址 (分数递增)，单值字段取自最后一个即最高分结果
+    pub results: Vec<SearchMatch>,
     pub elapsed: Duration,
     pub total_checked: u64,
     pub speed: f64,
@@ -67,6 +335,11 @@ impl SearchResponse {
         self.eth_address.map(hex::encode)
     }
 
+    /// EIP-55 校验和形式的地址 (0x 开头)
+    pub fn eth_address_checksum(&self) -> Option<String> {
+        self.eth_address.as_ref().map(to_checksum_address)
+    }
+
     pub fn result_seed_hex(&self) -> Option<String> {
         self.result_seed.map(hex::encode)
     }
@@ -74,14 +347,185 @@ impl SearchResponse {
     /// 根据 source_mode 返回助记词或私钥字符串
     /// - MnemonicEntropy: 返回助记词
     /// - PrivateKey: 返回私钥 (0x 开头的十六进制字符串)
+    /// - SplitKey: 返回偏移量 k (0x 开头)，需与用户私钥合并后才是最终私钥
+    /// - DeployerNonce: 返回部署 nonce (十进制)
+    /// - Create2Salt: 返回 salt (0x 开头)
     pub fn result_seed_display(&self) -> Option<String> {
-        let seed = self.result_seed?;
+        seed_display(
+            self.source_mode,
+            &self.result_seed?,
+            self.word_count,
+            self.language,
+        )
+    }
+
+    /// 固定部署者模式下找到的 nonce (种子低 8 字节，大端序)
+    pub fn nonce(&self) -> Option<u64> {
+        seed_nonce(self.source_mode, &self.result_seed?)
+    }
+
+    /// CREATE2 模式下找到的 salt
+    pub fn salt(&self) -> Option<[u8; 32]> {
+        if self.source_mode != SourceMode::Create2Salt {
+            return None;
+        }
+        self.result_seed
+    }
+}
+
+fn seed_display(
+    source_mode: SourceMode,
+    seed: &[u8; 32],
+    word_count: Option<usize>,
+    language: Option<Language>,
+) -> Option<String> {
+    match source_mode {
+        SourceMode::MnemonicEntropy => {
+            seed_mnemonic(source_mode, seed, word_count, language).map(|m| m.to_string())
+        }
+        SourceMode::PrivateKey | SourceMode::SplitKey | SourceMode::Create2Salt => {
+            Some(format!("0x{}", hex::encode(seed)))
+        }
+        SourceMode::DeployerNonce => seed_nonce(source_mode, seed).map(|nonce| nonce.to_string()),
+    }
+}
+
+/// 助记词模式的熵: 种子末尾的 `word_count / 3 * 4` 字节
+fn mnemonic_entropy(
+    source_mode: SourceMode,
+    seed: &[u8; 32],
+    word_count: Option<usize>,
+) -> Option<&[u8]> {
+    if source_mode != SourceMode::MnemonicEntropy {
+        return None;
+    }
+    let entropy_len = entropy_len_for_words(word_count?).ok()?;
+    Some(&seed[32 - entropy_len..])
+}
+
+/// 助记词模式的助记词: 用指定语言的词表编码种子末尾的熵
+fn seed_mnemonic(
+    source_mode: SourceMode,
+    seed: &[u8; 32],
+    word_count: Option<usize>,
+    language: Option<Language>,
+) -> Option<Mnemonic> {
+    let entropy = mnemonic_entropy(source_mode, seed, word_count)?;
+    Mnemonic::from_entropy_in(language?, entropy).ok()
+}
+
+/// 命中地址的派生路径: 将请求路径的最后一层替换为命中的地址索引
+fn match_derivation_path(path: &[u32], address_index: u32) -> String {
+    let mut path = path.to_vec();
+    if let Some(last) = path.last_mut() {
+        *last = address_index;
+    }
+    format_derivation_path(&path)
+}
+
+fn seed_nonce(source_mode: SourceMode, seed: &[u8; 32]) -> Option<u64> {
+    if source_mode != SourceMode::DeployerNonce {
+        return None;
+    }
+    Some(u64::from_be_bytes(seed[24..].try_into().ok()?))
+}
+
+/// 命中结果中与请求相关的公共字段
+struct MatchContext<'a> {
+    source_mode: SourceMode,
+    /// 助记词模式的派生路径 (其他模式为 None)
+    derivation_path: Option<&'a [u32]>,
+    word_count: Option<usize>,
+    language: Option<Language>,
+    target_chain: TargetChain,
+    address_format: AddressFormat,
+    /// 助记词模式的 BIP39 密码 (重新推导 Taproot 公钥用)
+    passphrase: &'a str,
+    /// 分离密钥模式的用户公钥 P
+    public_key: Option<[u8; 64]>,
+}
+
+/// 目标链地址的编码方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AddressFormat {
+    /// EIP-55 校验和形式的十六进制地址
+    Ethereum,
+    /// Base58Check 地址 (版本字节): 比特币系 P2PKH
+    Base58(u8),
+    /// 波场 T 地址 (版本字节 0x41 的 Base58Check)
+    Tron,
+    /// bech32 P2WPKH 地址 (人类可读部分)
+    P2wpkh(String),
+    /// bech32m P2TR 地址 (人类可读部分)，GPU 只返回输出公钥的前 20 字节
+    P2tr(String),
+    /// Nostr npub 公钥，GPU 只返回 x 坐标的前 20 字节
+    Npub,
+}
+
+impl MatchContext<'_> {
+    /// 渲染命中地址，Taproot 需要在主机端重新推导公钥，计算完整的 32 字节输出公钥
+    fn render_address(
+        &self,
+        seed: &[u8; 32],
+        address: &[u8; 20],
+        derivation_path: Option<&str>,
+    ) -> anyhow::Result<String> {
+        Ok(match &self.address_format {
+            AddressFormat::Ethereum => to_checksum_address(address),
+            AddressFormat::Base58(version) => p2pkh_address(*version, address),
+            AddressFormat::Tron => tron_address(address),
+            AddressFormat::P2wpkh(hrp) => p2wpkh_address(hrp, address),
+            AddressFormat::P2tr(hrp) => {
+                let output_key =
+                    taproot_output_key(&self.recover_public_key(seed, derivation_path)?)?;
+                if output_key[..20] != address[..] {
+                    bail!("host-computed taproot output key does not match the GPU result");
+                }
+                p2tr_address(hrp, &output_key)
+            }
+            AddressFormat::Npub => {
+                let x_only = x_only_public_key(&self.recover_public_key(seed, derivation_path)?);
+                if x_only[..20] != address[..] {
+                    bail!("host-computed nostr public key does not match the GPU result");
+                }
+                npub(&x_only)
+            }
+        })
+    }
+
+    /// 由命中种子恢复公钥: 助记词按命中路径派生，分离密钥为 P + k·G
+    fn recover_public_key(
+        &self,
+        seed: &[u8; 32],
+        derivation_path: Option<&str>,
+    ) -> anyhow::Result<PublicKey> {
         match self.source_mode {
+            SourceMode::PrivateKey => public_key_from_private_key(seed),
             SourceMode::MnemonicEntropy => {
-                let mnemonic = Mnemonic::from_entropy(&seed).ok()?;
-                Some(mnemonic.to_string())
+                let mnemonic =
+                    seed_mnemonic(self.source_mode, seed, self.word_count, self.language)
+                        .ok_or_else(|| anyhow!("invalid mnemonic entropy in search result"))?;
+                let path = derivation_path
+                    .ok_or_else(|| anyhow!("mnemonic result is missing its derivation path"))?;
+                public_key_from_private_key(&mnemonic.derive_private_key(self.passphrase, path)?)
+            }
+            SourceMode::SplitKey => {
+                let public_key = self
+                    .public_key
+                    .ok_or_else(|| anyhow!("split-key mode requires a public key"))?;
+                let mut serialized = [0x04u8; 65];
+                serialized[1..].copy_from_slice(&public_key);
+                let user_key = PublicKey::from_slice(&serialized)
+                    .map_err(|e| anyhow!("Invalid public key: {}", e))?;
+                let offset = Scalar::from_be_bytes(*seed)
+                    .map_err(|_| anyhow!("split-key offset is out of range for secp256k1"))?;
+                user_key
+                    .add_exp_tweak(SECP256K1, &offset)
+                    .map_err(|e| anyhow!("Invalid combined public key: {}", e))
+            }
+            SourceMode::DeployerNonce | SourceMode::Create2Salt => {
+                bail!("contract address modes do not derive public keys")
             }
-            SourceMode::PrivateKey => Some(format!("0x{}", hex::encode(seed))),
         }
     }
 }
@@ -90,15 +534,285 @@ struct SearchWorker {
     ctx: OpenCLContext,
     kernel: SearchKernel,
     threads: usize,
+    /// 下一个待读取的结果序号
+    read_cursor: u32,
+}
+
+impl SearchWorker {
+    /// 取走内核新写入的结果 (最多读到 max_results 个序号)
+    fn drain_results(
+        &mut self,
+        context: &MatchContext,
+        max_results: u32,
+        results: &mut Vec<SearchMatch>,
+    ) -> anyhow::Result<()> {
+        let mut count = self.kernel.read_result_count()?;
+        if max_results != 0 {
+            count = count.min(max_results);
+        }
+        if count - self.read_cursor > RESULT_RING_CAPACITY as u32 {
+            let skipped = count - self.read_cursor - RESULT_RING_CAPACITY as u32;
+            warn!(
+                "结果缓冲区已被覆盖，丢失 {} 个结果 (可减小 poll_interval)",
+                skipped
+            );
+            self.read_cursor += skipped;
+        }
+
+        for result in self.kernel.read_results(self.read_cursor, count)? {
+            self.read_cursor += 1;
+            // 评分模式下多设备各自刷新最高分，只保留全局意义上的提升
+            if result.score > 0 && results.last().is_some_and(|m| m.score >= result.score) {
+                continue;
+            }
+            let derivation_path = context
+                .derivation_path
+                .map(|path| match_derivation_path(path, result.address_index));
+            let address = context.render_address(
+                &result.result_seed,
+                &result.eth_address,
+                derivation_path.as_deref(),
+            )?;
+            let found = SearchMatch {
+                source_mode: context.source_mode,
+                result_seed: result.result_seed,
+                eth_address: result.eth_address,
+                found_by_thread: result.found_by_thread,
+                target_index: result.target_index as usize,
+                found_device: self
+                    .ctx
+                    .device
+                    .name()
+                    .unwrap_or_else(|_| String::from("<unknown>")),
+                score: result.score,
+                derivation_path,
+                address_index: context
+                    .derivation_path
+                    .filter(|path| !path.is_empty())
+                    .map(|_| result.address_index & !HARDENED),
+                word_count: context.word_count,
+                language: context.language,
+                target_chain: context.target_chain,
+                address,
+            };
+            if found.score > 0 {
+                info!("新的最高分 {}: {}", found.score, found.address);
+            } else {
+                info!("找到第 {} 个结果: {}", results.len() + 1, found.address);
+            }
+            results.push(found);
+        }
+        Ok(())
+    }
 }
 
 pub fn search(request: SearchRequest) -> anyhow::Result<SearchResponse> {
     if request.threads == 0 {
         bail!("threads must be greater than 0");
     }
+    if request.source_mode == SourceMode::SplitKey && request.public_key.is_none() {
+        bail!("split-key mode requires a public key");
+    }
+    let nonce_mode = request.source_mode == SourceMode::DeployerNonce;
+    if nonce_mode && request.deployer.is_none() {
+        bail!("deployer-nonce mode requires a deployer address");
+    }
+    if nonce_mode && request.multi_gpu {
+        bail!("deployer-nonce mode does not support multi_gpu");
+    }
+    if request.source_mode == SourceMode::Create2Salt
+        && (request.deployer.is_none() || request.init_code_hash.is_none())
+    {
+        bail!("create2 mode requires a deployer address and an init code hash");
+    }
+    let mnemonic_mode = request.source_mode == SourceMode::MnemonicEntropy;
+    if request.passphrase.is_some() && !mnemonic_mode {
+        bail!("passphrase is only supported in mnemonic mode");
+    }
+    if request.derivation_path.is_some() && !mnemonic_mode {
+        bail!("derivation path is only supported in mnemonic mode");
+    }
+    if request.address_version.is_some() && request.target_chain != TargetChain::Bitcoin {
+        bail!("address_version is only supported for the bitcoin target chain");
+    }
+    if request.bech32_hrp.is_some()
+        && !matches!(
+            request.target_chain,
+            TargetChain::BitcoinSegwit | TargetChain::BitcoinTaproot
+        )
+    {
+        bail!("bech32_hrp is only supported for segwit and taproot target chains");
+    }
+    let address_format = match request.target_chain {
+        TargetChain::Ethereum => AddressFormat::Ethereum,
+        TargetChain::Tron => AddressFormat::Tron,
+        TargetChain::Nostr => AddressFormat::Npub,
+        TargetChain::Bitcoin => {
+            AddressFormat::Base58(request.address_version.unwrap_or(BITCOIN_P2PKH_VERSION))
+        }
+        TargetChain::BitcoinSegwit | TargetChain::BitcoinTaproot => {
+            let hrp = request
+                .bech32_hrp
+                .as_deref()
+                .unwrap_or(BITCOIN_HRP)
+                .trim()
+                .to_ascii_lowercase();
+            if hrp.is_empty() || !hrp.bytes().all(|b| (33..=126).contains(&b)) {
+                bail!("Invalid bech32 human-readable part '{}'", hrp);
+            }
+            if request.target_chain == TargetChain::BitcoinTaproot {
+                AddressFormat::P2tr(hrp)
+            } else {
+                AddressFormat::P2wpkh(hrp)
+            }
+        }
+    };
+    let default_path = match &address_format {
+        AddressFormat::Ethereum => DEFAULT_DERIVATION_PATH.to_string(),
+        AddressFormat::Base58(version) => default_derivation_path(*version).to_string(),
+        AddressFormat::Tron => DEFAULT_TRON_DERIVATION_PATH.to_string(),
+        AddressFormat::P2wpkh(hrp) => default_bech32_derivation_path(hrp, false),
+        AddressFormat::P2tr(hrp) => default_bech32_derivation_path(hrp, true),
+        AddressFormat::Npub => DEFAULT_NOSTR_DERIVATION_PATH.to_string(),
+    };
+    let derivation_path =
+        parse_derivation_path(request.derivation_path.as_deref().unwrap_or(&default_path))?;
+    if request.address_count == 0 {
+        bail!("address_count must be greater than 0");
+    }
+    if request.address_count > 1 {
+        if !mnemonic_mode {
+            bail!("address_count is only supported in mnemonic mode");
+        }
+        let Some(&first_index) = derivation_path.last() else {
+            bail!("address_count requires a derivation path with an address index level");
+        };
+        // 地址索引不能越过硬化 / 非硬化的边界
+        if (first_index & !HARDENED) as u64 + request.address_count as u64 > HARDENED as u64 {
+            bail!("address index range exceeds the maximum BIP32 index");
+        }
+    }
+    let mnemonic_path = mnemonic_mode.then_some(derivation_path.as_slice());
+    if request.word_count != 24 && !mnemonic_mode {
+        bail!("word_count is only supported in mnemonic mode");
+    }
+    let entropy_len = entropy_len_for_words(request.word_count)?;
+    let word_count = mnemonic_mode.then_some(request.word_count);
+    if request.language != Language::English && !mnemonic_mode {
+        bail!("language is only supported in mnemonic mode");
+    }
+    let language = mnemonic_mode.then_some(request.language);
+    if request.conditions.is_empty() || request.conditions.len() > MAX_TARGETS {
+        bail!(
+            "conditions must contain between 1 and {} entries, got {}",
+            MAX_TARGETS,
+            request.conditions.len()
+        );
+    }
+    if request
+        .conditions
+        .iter()
+        .any(|c| c.any_leaf(&|leaf| matches!(leaf, SearchCondition::HookFlags(_))))
+        && request.source_mode != SourceMode::Create2Salt
+    {
+        bail!("uniswap v4 hook flags require create2 mode");
+    }
+    let is_base58 = |leaf: &SearchCondition| matches!(leaf, SearchCondition::Base58Prefix(_));
+    let is_bech32 = |leaf: &SearchCondition| matches!(leaf, SearchCondition::Bech32Prefix(_));
+    if !matches!(
+        request.target_chain,
+        TargetChain::Bitcoin | TargetChain::Tron
+    ) && request.conditions.iter().any(|c| c.any_leaf(&is_base58))
+    {
+        bail!("base58 prefix conditions require the bitcoin or tron target chain");
+    }
+    if !request.target_chain.is_bech32()
+        && request.conditions.iter().any(|c| c.any_leaf(&is_bech32))
+    {
+        bail!("bech32 prefix conditions require a segwit, taproot or nostr target chain");
+    }
+    let conditions = if address_format != AddressFormat::Ethereum {
+        let chain_name = match request.target_chain {
+            TargetChain::Tron => "tron",
+            TargetChain::Nostr => "nostr",
+            _ => "bitcoin",
+        };
+        if !matches!(
+            request.source_mode,
+            SourceMode::MnemonicEntropy | SourceMode::PrivateKey | SourceMode::SplitKey
+        ) {
+            bail!(
+                "{} target chain requires mnemonic, private-key or split-key mode",
+                chain_name
+            );
+        }
+        if request.address_type != AddressType::Eoa {
+            bail!(
+                "{} target chain does not support contract addresses",
+                chain_name
+            );
+        }
+        if request.case_sensitive {
+            bail!(
+                "case_sensitive is not supported for the {} target chain",
+                chain_name
+            );
+        }
+        let bech32 = request.target_chain.is_bech32();
+        if request.conditions.iter().any(|c| {
+            c.any_leaf(&|leaf| {
+                if bech32 {
+                    !is_bech32(leaf)
+                } else {
+                    !is_base58(leaf)
+                }
+            })
+        }) {
+            if bech32 {
+                bail!(
+                    "{} target chain only supports bech32 prefix conditions",
+                    chain_name
+                );
+            }
+            bail!(
+                "{} target chain only supports base58 prefix conditions",
+                chain_name
+            );
+        }
+        request
+            .conditions
+            .iter()
+            .map(|c| expand_address_prefixes(c, &address_format))
+            .collect::<anyhow::Result<Vec<_>>>()?
+    } else {
+        request.conditions.clone()
+    };
+    let score_mode = request.conditions[0].score_mode();
+    if request
+        .conditions
+        .iter()
+        .any(|c| c.any_leaf(&|leaf| leaf.score_mode() != ScoreMode::None))
+        && (request.conditions.len() != 1 || score_mode == ScoreMode::None)
+    {
+        bail!("score conditions cannot be combined with other conditions");
+    }
 
-    let (condition, pattern_config) = parse_condition(&request.condition)?;
-    let base_seed = request.base_seed.unwrap_or_else(random_nonzero_seed);
+    let targets = build_targets(
+        &conditions,
+        request.case_sensitive,
+        request.condition_encoding,
+    )?;
+    let max_score = score_mode.max_score(&targets[0].pattern_config);
+    // nonce 越小越实用，固定部署者模式默认从 nonce 0 开始顺序遍历
+    let mut base_seed = if nonce_mode {
+        request.base_seed.unwrap_or([0; 32])
+    } else {
+        request.base_seed.unwrap_or_else(random_nonzero_seed)
+    };
+    if mnemonic_mode {
+        // 少于 24 个单词时熵只占种子末尾 entropy_len 字节，高位字节清零
+        base_seed[..32 - entropy_len].fill(0);
+    }
 
     let contexts = if request.multi_gpu {
         let gpu_contexts = OpenCLContext::all_gpu_contexts()?;
@@ -112,33 +826,44 @@ pub fn search(request: SearchRequest) -> anyhow::Result<SearchResponse> {
     };
 
     let thread_plan = split_threads(request.threads as usize, contexts.len());
-    let kernel_source = load_kernel_source()?;
+    // 词表编译进内核，非英文助记词需要加载对应语言的词表
+    let kernel_source = load_kernel_source_for_language(request.language)?;
 
     let mut workers = Vec::new();
-    for (idx, (ctx, threads)) in contexts
-        .into_iter()
-        .zip(thread_plan.into_iter())
-        .enumerate()
-    {
+    for (idx, (ctx, threads)) in contexts.into_iter().zip(thread_plan).enumerate() {
         if threads == 0 {
             continue;
         }
 
         let kernel = SearchKernel::new(&ctx, &kernel_source, threads)?;
-        let worker_seed = seed_with_offset(base_seed, idx as u64 + 1);
-        let config = if let Some(pattern) = pattern_config {
-            SearchConfig::new_with_pattern(worker_seed, threads as u32, condition, pattern)
+        let worker_seed = if nonce_mode {
+            base_seed
         } else {
-            SearchConfig::new(worker_seed, threads as u32, condition)
-        }
-        .with_source_mode(request.source_mode)
-        .with_target_chain(TargetChain::Ethereum);
+            seed_with_offset(base_seed, idx as u64 + 1)
+        };
+        let config = SearchConfig::new(worker_seed, threads as u32)
+            .with_source_mode(request.source_mode)
+            .with_target_chain(request.target_chain)
+            .with_target_count(targets.len() as u32)
+            .with_batch_size(request.batch_size)
+            .with_public_key(request.public_key.unwrap_or([0; 64]))
+            .with_address_type(request.address_type)
+            .with_deployer(request.deployer.unwrap_or([0; 20]))
+            .with_init_code_hash(request.init_code_hash.unwrap_or([0; 32]))
+            .with_max_results(request.max_results)
+            .with_score_mode(score_mode)
+            .with_passphrase(request.passphrase.as_deref().unwrap_or(""))?
+            .with_derivation_path(&derivation_path)?
+            .with_address_count(request.address_count)
+            .with_word_count(request.word_count)?;
 
         kernel.set_config(&config)?;
+        kernel.set_targets(&targets)?;
         workers.push(SearchWorker {
             ctx,
             kernel,
             threads,
+            read_cursor: 0,
         });
     }
 
@@ -152,11 +877,27 @@ pub fn search(request: SearchRequest) -> anyhow::Result<SearchResponse> {
             .launch(worker.threads, Some(request.work_group_size))?;
     }
 
+    let context = MatchContext {
+        source_mode: request.source_mode,
+        derivation_path: mnemonic_path,
+        word_count,
+        language,
+        target_chain: request.target_chain,
+        address_format,
+        passphrase: request.passphrase.as_deref().unwrap_or(""),
+        public_key: request.public_key,
+    };
     let start_time = Instant::now();
-    let mut found: Option<usize> = None;
-    let mut result = SearchResult::default();
+    // 评分模式不限结果数量，直到达到最高分或超时
+    let max_results = if score_mode == ScoreMode::None {
+        request.max_results
+    } else {
+        0
+    };
+    let mut results: Vec<SearchMatch> = Vec::new();
     let mut timed_out_in_loop = false;
 
+    // 轮询期间内核持续运行，新结果一出现就取走
     loop {
         let timed_out = request
             .timeout
@@ -166,85 +907,74 @@ pub fn search(request: SearchRequest) -> anyhow::Result<SearchResponse> {
             break;
         }
 
-        for (idx, worker) in workers.iter_mut().enumerate() {
-            if let Some(is_found) = worker.kernel.poll_found()? {
-                if is_found {
-                    found = Some(idx);
-                    result = worker.kernel.read_result()?;
-                    break;
-                }
-            }
+        for worker in workers.iter_mut() {
+            worker.drain_results(&context, max_results, &mut results)?;
         }
 
-        if found.is_some() {
+        if max_results != 0 && results.len() >= max_results as usize {
+            break;
+        }
+        if score_mode != ScoreMode::None && results.last().is_some_and(|m| m.score >= max_score) {
             break;
         }
 
         sleep(request.poll_interval);
     }
 
-    if !timed_out_in_loop && found.is_none() {
-        for (idx, worker) in workers.iter().enumerate() {
-            if let Ok(r) = worker.kernel.read_result() {
-                if r.found != 0 {
-                    found = Some(idx);
-                    result = r;
-                    break;
-                }
-            }
-        }
+    // 通知所有设备停止，等待内核退出后再收尾
+    for worker in &workers {
+        let _ = worker.kernel.request_stop();
+    }
+    for worker in &workers {
+        let _ = worker.kernel.wait();
+    }
+    for worker in workers.iter_mut() {
+        worker.drain_results(&context, max_results, &mut results)?;
+    }
+    if max_results != 0 {
+        results.truncate(max_results as usize);
     }
 
     let elapsed = start_time.elapsed();
     let timed_out = timed_out_in_loop || request.timeout.is_some_and(|timeout| elapsed >= timeout);
-    let total_checked = if timed_out_in_loop {
-        0
-    } else {
-        let total_checked: u64 = workers
-            .iter()
-            .map(|w| w.kernel.read_total_checked(w.threads).unwrap_or(0))
-            .sum();
-        if total_checked > 0 {
-            total_checked
-        } else {
-            result.total_checked()
-        }
-    };
+    let total_checked: u64 = workers
+        .iter()
+        .map(|w| w.kernel.read_total_checked(w.threads).unwrap_or(0))
+        .sum();
     let speed = if elapsed.as_secs_f64() > 0.0 {
         total_checked as f64 / elapsed.as_secs_f64()
     } else {
         0.0
     };
 
-    if found.is_some() {
-        sleep(Duration::from_millis(500));
-    } else if !timed_out_in_loop {
-        for worker in &workers {
-            let _ = worker.kernel.wait();
-        }
-    }
-
-    let found_device = if let Some(idx) = found {
-        Some(
-            workers[idx]
-                .ctx
-                .device
-                .name()
-                .unwrap_or_else(|_| String::from("<unknown>")),
-        )
+    let first = if score_mode == ScoreMode::None {
+        results.first()
     } else {
-        None
+        results.last()
     };
-
-    let found_flag = found.is_some() && result.found != 0;
+    let found_flag = first.is_some();
     Ok(SearchResponse {
         found: found_flag,
         timed_out: !found_flag && timed_out,
         source_mode: request.source_mode,
-        result_seed: found_flag.then_some(result.result_seed),
-        eth_address: found_flag.then_some(result.eth_address),
-        found_by_thread: found_flag.then_some(result.found_by_thread),
-        found_device,
+        address_type: request.address_type,
+        result_seed: first.map(|m| m.result_seed),
+        eth_address: first.map(|m| m.eth_address),
+        found_by_thread: first.map(|m| m.found_by_thread),
+        target_index: first.map(|m| m.target_index),
+        found_device: first.map(|m| m.found_device.clone()),
+        score: first
+            .filter(|_| score_mode != ScoreMode::None)
+            .map(|m| m.score),
+        derivation_path: first
+            .and_then(|m| m.derivation_path.clone())
+            .or_else(|| mnemonic_path.map(format_derivation_path)),
+        address_index: first.and_then(|m| m.address_index),
+        word_count,
+        language,
+        target_chain: request.target_chain,
+        address: first.map(|m| m.address.clone()),
+        results,
         elapsed,
         total_checked,
         speed,
@@ -268,10 +998,258 @@ fn parse_condition(condition: &SearchCondition) -> anyhow::Result<(u64, Option<P
             let (condition, pattern) = parse_pattern_condition(&pattern)?;
             Ok((condition, Some(pattern)))
         }
+        SearchCondition::LeadingZerosExact(value) => {
+            if *value > 40 {
+                bail!("Leading zeros cannot exceed 40");
+            }
+            Ok((ConditionType::LeadingExact.encode(*value as u64), None))
+        }
+        SearchCondition::ZeroBytes(value) => {
+            if *value == 0 || *value > 20 {
+                bail!("zero byte count must be between 1 and 20, got {}", value);
+            }
+            Ok((ConditionType::ZeroBytes.encode(*value as u64), None))
+        }
+        SearchCondition::AllLetters => Ok((
+            ConditionType::CharClass.encode(0),
+            Some(PatternConfig::all_letters()),
+        )),
+        SearchCondition::AllDigits => Ok((
+            ConditionType::CharClass.encode(0),
+            Some(PatternConfig::all_digits()),
+        )),
+        SearchCondition::LeadingDoubles(count) => Ok((
+            ConditionType::CharClass.encode(0),
+            Some(PatternConfig::leading_doubles(*count)?),
+        )),
+        SearchCondition::Mirror(count) => Ok((
+            ConditionType::CharClass.encode(0),
+            Some(PatternConfig::mirror(*count)?),
+        )),
+        SearchCondition::Contains(word) => {
+            let nibbles = parse_hexspeak(word.trim())?;
+            let pattern = PatternConfig::contains(&nibbles)?;
+            Ok((
+                ConditionType::Contains.encode(nibbles.len() as u64),
+                Some(pattern),
+            ))
+        }
+        SearchCondition::Range { low, high } => {
+            let (condition, pattern) = PatternConfig::range(*low, *high)?;
+            Ok((condition, Some(pattern)))
+        }
         SearchCondition::Pattern(value) => {
             let (condition, pattern) = parse_pattern_condition(value)?;
             Ok((condition, Some(pattern)))
         }
+        SearchCondition::Mask { mask, value } => {
+            let pattern = PatternConfig::from_mask_value(*mask, *value)?;
+            Ok((ConditionType::Pattern.encode(0), Some(pattern)))
+        }
+        SearchCondition::HookFlags(flags) => {
+            let pattern = hook_pattern(*flags)?;
+            Ok((ConditionType::Pattern.encode(0), Some(pattern)))
+        }
+        // 评分模式不做匹配判断，目标表第一项只为 ScoreMatching 提供目标模式
+        SearchCondition::ScoreLeadingZeros | SearchCondition::ScoreZeroNibbles => Ok((
+            ConditionType::Pattern.encode(0),
+            Some(PatternConfig::default()),
+        )),
+        SearchCondition::ScoreMatching(value) => {
+            let (condition, pattern) = parse_pattern_condition(value)?;
+            Ok((condition, Some(pattern)))
+        }
+        SearchCondition::Base58Prefix(_) | SearchCondition::Bech32Prefix(_) => {
+            bail!("address prefix conditions must be expanded for the target chain first")
+        }
+        SearchCondition::And(_) | SearchCondition::Or(_) | SearchCondition::Not(_) => {
+            bail!("composite conditions must be compiled with build_targets")
+        }
+    }
+}
+
+/// 将条件树中的地址前缀换算为 20 字节匹配值上的条件:
+/// Base58 前缀换算为 HASH160 数值范围 (多个范围之间为 Or)，bech32 前缀换算为位掩码
+fn expand_address_prefixes(
+    condition: &SearchCondition,
+    format: &AddressFormat,
+) -> anyhow::Result<SearchCondition> {
+    let expand_all = |children: &[SearchCondition]| {
+        children
+            .iter()
+            .map(|c| expand_address_prefixes(c, format))
+            .collect::<anyhow::Result<Vec<_>>>()
+    };
+    Ok(match condition {
+        SearchCondition::Base58Prefix(prefix) => {
+            let version = match *format {
+                AddressFormat::Base58(version) => version,
+                AddressFormat::Tron => TRON_ADDRESS_VERSION,
+                _ => bail!("base58 prefix conditions require a base58 address format"),
+            };
+            let mut ranges: Vec<SearchCondition> = base58_prefix_ranges(prefix.trim(), version)?
+                .into_iter()
+                .map(|(low, high)| SearchCondition::Range { low, high })
+                .collect();
+            if ranges.len() == 1 {
+                ranges.remove(0)
+            } else {
+                SearchCondition::Or(ranges)
+            }
+        }
+        SearchCondition::Bech32Prefix(prefix) => {
+            let (mask, value) = match format {
+                AddressFormat::P2wpkh(hrp) => segwit_prefix_mask(prefix.trim(), hrp, 0)?,
+                AddressFormat::P2tr(hrp) => segwit_prefix_mask(prefix.trim(), hrp, 1)?,
+                AddressFormat::Npub => npub_prefix_mask(prefix.trim())?,
+                _ => bail!("bech32 prefix conditions require a bech32 address format"),
+            };
+            SearchCondition::Mask { mask, value }
+        }
+        SearchCondition::And(children) => SearchCondition::And(expand_all(children)?),
+        SearchCondition::Or(children) => SearchCondition::Or(expand_all(children)?),
+        SearchCondition::Not(child) => !expand_address_prefixes(child, format)?,
+        leaf => leaf.clone(),
+    })
+}
+
+/// 尝试将条件编码为专用的 COND_PREFIX/COND_SUFFIX/COND_LEADING，不适用时返回 None
+fn parse_specialized_condition(condition: &SearchCondition) -> anyhow::Result<Option<u64>> {
+    let (cond_type, value, kind) = match condition {
+        SearchCondition::Prefix(value) => (ConditionType::Prefix, value, PatternKind::Prefix),
+        SearchCondition::Suffix(value) => (ConditionType::Suffix, value, PatternKind::Suffix),
+        SearchCondition::LeadingZeros(value) => {
+            if *value > 40 {
+                bail!("Leading zeros cannot exceed 40");
+            }
+            return Ok(Some(ConditionType::Leading.encode(*value as u64)));
+        }
+        _ => return Ok(None),
+    };
+
+    // 先按模式路径校验输入
+    normalize_hex_pattern(value, kind)?;
+    let hex = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+    if hex.len() % 2 != 0 || hex.len() / 2 > MAX_CONDITION_BYTES {
+        return Ok(None);
+    }
+    Ok(Some(cond_type.encode_bytes(&hex::decode(hex)?)?))
+}
+
+/// 按指定编码方式解析条件
+fn parse_condition_with_encoding(
+    condition: &SearchCondition,
+    encoding: ConditionEncoding,
+) -> anyhow::Result<(u64, Option<PatternConfig>)> {
+    if encoding == ConditionEncoding::Specialized
+        && let Some(encoded) = parse_specialized_condition(condition)?
+    {
+        return Ok((encoded, None));
+    }
+    parse_condition(condition)
+}
+
+/// 将单个条件编译为目标表中的一项
+fn build_target(
+    condition: &SearchCondition,
+    case_sensitive: bool,
+    encoding: ConditionEncoding,
+) -> anyhow::Result<SearchTarget> {
+    let (encoded, pattern_config) = parse_condition_with_encoding(condition, encoding)?;
+    let target = match pattern_config {
+        Some(pattern) => SearchTarget::new_with_pattern(encoded, pattern),
+        None => SearchTarget::new(encoded),
+    };
+    if case_sensitive {
+        Ok(target.with_case_pattern(parse_case_condition(condition)?))
+    } else {
+        Ok(target)
+    }
+}
+
+/// 将全部条件编译为目标表
+///
+/// 条件树按后缀顺序展开: 叶子条件各占一项，And/Or/Not 作为运算符项跟在操作数之后，
+/// 每个顶层条件的最后一项标记为 root。结果可直接传给 `SearchKernel::set_targets`。
+pub fn build_targets(
+    conditions: &[SearchCondition],
+    case_sensitive: bool,
+    encoding: ConditionEncoding,
+) -> anyhow::Result<Vec<SearchTarget>> {
+    let mut targets = Vec::new();
+    for condition in conditions {
+        let start = targets.len();
+        let depth = compile_condition(condition, case_sensitive, encoding, &mut targets)?;
+        if depth > MAX_CONDITION_DEPTH {
+            bail!(
+                "condition is nested too deeply: needs {} stack entries, at most {} are supported",
+                depth,
+                MAX_CONDITION_DEPTH
+            );
+        }
+        let last = targets.len() - 1;
+        for (i, target) in targets[start..].iter_mut().enumerate() {
+            target.root = u32::from(start + i == last);
+        }
+    }
+    if targets.len() > MAX_TARGETS {
+        bail!(
+            "conditions compile to {} target entries, at most {} are supported",
+            targets.len(),
+            MAX_TARGETS
+        );
+    }
+    Ok(targets)
+}
+
+/// 后缀展开单个条件，返回该子树求值时的最大栈深度
+fn compile_condition(
+    condition: &SearchCondition,
+    case_sensitive: bool,
+    encoding: ConditionEncoding,
+    targets: &mut Vec<SearchTarget>,
+) -> anyhow::Result<usize> {
+    match condition {
+        SearchCondition::And(children) | SearchCondition::Or(children) => {
+            let op = if matches!(condition, SearchCondition::And(_)) {
+                ConditionType::And
+            } else {
+                ConditionType::Or
+            };
+            // 第 i 个操作数求值时，栈中已有前 i 个操作数的结果
+            let mut depth = 0;
+            for (i, child) in children.iter().enumerate() {
+                depth = depth.max(i + compile_condition(child, case_sensitive, encoding, targets)?);
+            }
+            targets.push(SearchTarget::operator(op, children.len() as u32)?);
+            Ok(depth)
+        }
+        SearchCondition::Not(child) => {
+            let depth = compile_condition(child, case_sensitive, encoding, targets)?;
+            targets.push(SearchTarget::operator(ConditionType::Not, 0)?);
+            Ok(depth)
+        }
+        leaf => {
+            targets.push(build_target(leaf, case_sensitive, encoding)?);
+            Ok(1)
+        }
+    }
+}
+
+/// 解析大小写敏感匹配的 EIP-55 大小写约束
+fn parse_case_condition(condition: &SearchCondition) -> anyhow::Result<PatternConfig> {
+    match condition {
+        SearchCondition::Prefix(value) => {
+            parse_checksum_case(&normalize_hex_pattern(value, PatternKind::Prefix)?)
+        }
+        SearchCondition::Suffix(value) => {
+            parse_checksum_case(&normalize_hex_pattern(value, PatternKind::Suffix)?)
+        }
+        SearchCondition::Pattern(value) => parse_checksum_case(value),
+        _ => bail!("case-sensitive matching requires a prefix, suffix or pattern condition"),
     }
 }
 
@@ -350,7 +1328,6 @@ fn split_threads(total_threads: usize, workers: usize) -> Vec<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::ConditionType;
 
     #[test]
     fn test_request_defaults() {
@@ -362,6 +1339,783 @@ mod tests {
         assert_eq!(req.source_mode, SourceMode::MnemonicEntropy);
         assert!(!req.multi_gpu);
         assert!(req.base_seed.is_none());
+        assert!(req.passphrase.is_none());
+        assert!(req.derivation_path.is_none());
+        assert_eq!(req.address_count, 1);
+        assert_eq!(req.word_count, 24);
+        assert_eq!(req.language, Language::English);
+        assert_eq!(req.conditions.len(), 1);
+    }
+
+    #[test]
+    fn test_passphrase_requires_mnemonic_mode() {
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.source_mode = SourceMode::PrivateKey;
+        req.passphrase = Some(String::from("TREZOR"));
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("mnemonic mode"));
+    }
+
+    #[test]
+    fn test_derivation_path_validation() {
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.source_mode = SourceMode::PrivateKey;
+        req.derivation_path = Some(String::from("m/44'/60'/1'/0/0"));
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("mnemonic mode"));
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.derivation_path = Some(String::from("44'/60'/1'/0/0"));
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("must start with 'm'"));
+    }
+
+    #[test]
+    fn test_address_count_validation() {
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.address_count = 0;
+        assert!(search(req).is_err());
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.source_mode = SourceMode::PrivateKey;
+        req.address_count = 16;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("mnemonic mode"));
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.derivation_path = Some(String::from("m"));
+        req.address_count = 16;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("address index level"));
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.derivation_path = Some(String::from("m/44'/60'/0'/0/2147483640"));
+        req.address_count = 16;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("maximum BIP32 index"));
+    }
+
+    #[test]
+    fn test_word_count_validation() {
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.word_count = 13;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("12, 15, 18, 21 or 24 words"));
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.source_mode = SourceMode::PrivateKey;
+        req.word_count = 12;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("mnemonic mode"));
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.source_mode = SourceMode::PrivateKey;
+        req.language = Language::Japanese;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("mnemonic mode"));
+    }
+
+    #[test]
+    fn test_bitcoin_target_validation() {
+        let bitcoin_request = |condition: SearchCondition| {
+            let mut req = SearchRequest::new(condition);
+            req.target_chain = TargetChain::Bitcoin;
+            req
+        };
+
+        let err = search(SearchRequest::new(SearchCondition::Base58Prefix(
+            String::from("1Love"),
+        )))
+        .unwrap_err();
+        assert!(err.to_string().contains("bitcoin or tron"));
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.address_version = Some(0x30);
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("address_version"));
+
+        let err = search(bitcoin_request(SearchCondition::Prefix(String::from("00")))).unwrap_err();
+        assert!(err.to_string().contains("base58 prefix"));
+
+        let mut req = bitcoin_request(SearchCondition::Base58Prefix(String::from("1Love")));
+        req.source_mode = SourceMode::Create2Salt;
+        req.deployer = Some([0x11; 20]);
+        req.init_code_hash = Some([0x22; 32]);
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("split-key mode"));
+
+        let mut req = bitcoin_request(SearchCondition::Base58Prefix(String::from("1Love")));
+        req.address_type = AddressType::Create;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("contract addresses"));
+
+        let mut req = bitcoin_request(SearchCondition::Base58Prefix(String::from("1Love")));
+        req.case_sensitive = true;
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("case_sensitive"));
+
+        // 比特币 P2PKH 地址不可能以 3 开头
+        let err = search(bitcoin_request(SearchCondition::Base58Prefix(
+            String::from("3"),
+        )))
+        .unwrap_err();
+        assert!(err.to_string().contains("cannot occur"));
+    }
+
+    #[test]
+    fn test_expand_base58_prefixes() {
+        let condition = SearchCondition::Base58Prefix(String::from("1Love"))
+            .or(!SearchCondition::Base58Prefix(String::from("11")));
+        let format = AddressFormat::Base58(BITCOIN_P2PKH_VERSION);
+        let expanded = expand_address_prefixes(&condition, &format).unwrap();
+        assert!(!expanded.any_leaf(&|leaf| matches!(leaf, SearchCondition::Base58Prefix(_))));
+        // 每个范围一项，再加上 Not 和 Or 运算符项
+        let ranges = base58_prefix_ranges("1Love", BITCOIN_P2PKH_VERSION)
+            .unwrap()
+            .len()
+            + base58_prefix_ranges("11", BITCOIN_P2PKH_VERSION)
+                .unwrap()
+                .len();
+        let targets = build_targets(&[expanded], false, ConditionEncoding::Pattern).unwrap();
+        assert!(targets.len() > ranges);
+
+        // 单个范围不额外包一层 Or
+        let expanded =
+            expand_address_prefixes(&SearchCondition::Base58Prefix(String::from("1")), &format)
+                .unwrap();
+        assert!(matches!(
+            expanded,
+            SearchCondition::Range { low, high: None } if low == [0u8; 20]
+        ));
+
+        // bech32 前缀换算为位掩码，见证版本字符需与地址格式一致
+        let format = AddressFormat::P2tr(String::from(BITCOIN_HRP));
+        let expanded = expand_address_prefixes(
+            &SearchCondition::Bech32Prefix(String::from("bc1pl")),
+            &format,
+        )
+        .unwrap();
+        assert!(matches!(
+            expanded,
+            SearchCondition::Mask { mask, value } if mask[0] == 0xf8 && value[0] == 0xf8
+        ));
+        assert!(
+            expand_address_prefixes(
+                &SearchCondition::Bech32Prefix(String::from("bc1ql")),
+                &format
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_tron_target_validation() {
+        let tron_request = |condition: SearchCondition| {
+            let mut req = SearchRequest::new(condition);
+            req.target_chain = TargetChain::Tron;
+            req
+        };
+
+        let err = search(tron_request(SearchCondition::Prefix(String::from("00")))).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("tron target chain only supports base58")
+        );
+
+        let mut req = tron_request(SearchCondition::Base58Prefix(String::from("TRX")));
+        req.source_mode = SourceMode::DeployerNonce;
+        req.deployer = Some([0x11; 20]);
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("tron target chain requires"));
+
+        let mut req = tron_request(SearchCondition::Base58Prefix(String::from("TRX")));
+        req.address_version = Some(0x41);
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("address_version"));
+
+        // 波场地址都以 T 开头
+        let err = search(tron_request(SearchCondition::Base58Prefix(String::from(
+            "1",
+        ))))
+        .unwrap_err();
+        assert!(err.to_string().contains("cannot occur"));
+
+        let expanded = expand_address_prefixes(
+            &SearchCondition::Base58Prefix(String::from("TRX")),
+            &AddressFormat::Tron,
+        )
+        .unwrap();
+        assert!(!expanded.any_leaf(&|leaf| matches!(leaf, SearchCondition::Base58Prefix(_))));
+    }
+
+    #[test]
+    fn test_bech32_target_validation() {
+        let segwit_request = |condition: SearchCondition| {
+            let mut req = SearchRequest::new(condition);
+            req.target_chain = TargetChain::BitcoinSegwit;
+            req
+        };
+
+        let err = search(SearchRequest::new(SearchCondition::Bech32Prefix(
+            String::from("bc1qxyz"),
+        )))
+        .unwrap_err();
+        assert!(err.to_string().contains("segwit, taproot or nostr"));
+
+        let mut req = SearchRequest::new(SearchCondition::Prefix(String::from("00")));
+        req.bech32_hrp = Some(String::from("ltc"));
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("bech32_hrp"));
+
+        let mut req = segwit_request(SearchCondition::Bech32Prefix(String::from("bc1qxyz")));
+        req.address_version = Some(0x30);
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("address_version"));
+
+        let err = search(segwit_request(SearchCondition::Base58Prefix(String::from(
+            "1Love",
+        ))))
+        .unwrap_err();
+        assert!(err.to_string().contains("bitcoin or tron"));
+
+        let err = search(segwit_request(SearchCondition::Prefix(String::from("00")))).unwrap_err();
+        assert!(err.to_string().contains("bech32 prefix"));
+
+        // 人类可读部分不符
+        let mut req = segwit_request(SearchCondition::Bech32Prefix(String::from("bc1qxyz")));
+        req.bech32_hrp = Some(String::from("ltc"));
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("ltc1q"));
+
+        let err = search(segwit_request(SearchCondition::Bech32Prefix(String::from(
+            "bc1qb",
+        ))))
+        .unwrap_err();
+        assert!(err.to_string().contains("bech32 character"));
+    }
+
+    #[test]
+    fn test_nostr_target_validation() {
+        let nostr_request = |condition: SearchCondition| {
+            let mut req = SearchRequest::new(condition);
+            req.target_chain = TargetChain::Nostr;
+            req
+        };
+
+        let err = search(nostr_request(SearchCondition::Prefix(String::from("00")))).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("nostr target chain only supports bech32")
+        );
+
+        let mut req = nostr_request(SearchCondition::Bech32Prefix(String::from("npub1xyz")));
+        req.bech32_hrp = Some(String::from("npub"));
+        let err = search(req).unwrap_err();
+        assert!(err.to_string().contains("bech32_hrp"));
+
+        let err = search(nostr_request(SearchCondition::Bech32Prefix(String::from(
+            "bc1qxyz",
+        ))))
+        .unwrap_err();
+        assert!(err.to_string().contains("npub1"));
+    }
+
+    #[test]
+    fn test_render_npub() {
+        // NIP-06 测试向量
+        let mnemonic = Mnemonic::from_string(
+            "leader monkey parrot ring guide accident before fence cannon height naive bean",
+        )
+        .unwrap();
+        let (entropy, _) = mnemonic.to_entropy();
+        let mut seed = [0u8; 32];
+        seed[32 - entropy.len()..].copy_from_slice(&entropy);
+        let context = MatchContext {
+            source_mode: SourceMode::MnemonicEntropy,
+            derivation_path: None,
+            word_count: Some(12),
+            language: Some(Language::English),
+            target_chain: TargetChain::Nostr,
+            address_format: AddressFormat::Npub,
+            passphrase: "",
+            public_key: None,
+        };
+        let x_only: [u8; 32] =
+            hex::decode("17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let address = context
+            .render_address(
+                &seed,
+                &x_only[..20].try_into().unwrap(),
+                Some(DEFAULT_NOSTR_DERIVATION_PATH),
+            )
+            .unwrap();
+        assert_eq!(address, npub(&x_only));
+    }
+
+    #[test]
+    fn test_render_tron_address() {
+        let context = MatchContext {
+            source_mode: SourceMode::PrivateKey,
+            derivation_path: None,
+            word_count: None,
+            language: None,
+            target_chain: TargetChain::Tron,
+            address_format: AddressFormat::Tron,
+            passphrase: "",
+            public_key: None,
+        };
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let address = crate::split_key::eth_address_from_private_key(&private_key).unwrap();
+        assert_eq!(
+            context
+                .render_address(&private_key, &address, None)
+                .unwrap(),
+            "TMVQGm1qAQYVdetCeGRRkTWYYrLXuHK2HC"
+        );
+    }
+
+    #[test]
+    fn test_render_taproot_address() {
+        let context = MatchContext {
+            source_mode: SourceMode::PrivateKey,
+            derivation_path: None,
+            word_count: None,
+            language: None,
+            target_chain: TargetChain::BitcoinTaproot,
+            address_format: AddressFormat::P2tr(String::from(BITCOIN_HRP)),
+            passphrase: "",
+            public_key: None,
+        };
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let output_key =
+            taproot_output_key(&public_key_from_private_key(&private_key).unwrap()).unwrap();
+        let address = context
+            .render_address(&private_key, &output_key[..20].try_into().unwrap(), None)
+            .unwrap();
+        assert_eq!(address, p2tr_address(BITCOIN_HRP, &output_key));
+
+        // 分离密钥模式: P = 1·G，偏移量 k = 1，合并私钥为 2
+        let mut public_key = [0u8; 64];
+        public_key.copy_from_slice(
+            &public_key_from_private_key(&private_key)
+                .unwrap()
+                .serialize_uncompressed()[1..],
+        );
+        let context = MatchContext {
+            source_mode: SourceMode::SplitKey,
+            public_key: Some(public_key),
+            ..context
+        };
+        let mut combined = [0u8; 32];
+        combined[31] = 2;
+        let output_key =
+            taproot_output_key(&public_key_from_private_key(&combined).unwrap()).unwrap();
+        let address = context
+            .render_address(&private_key, &output_key[..20].try_into().unwrap(), None)
+            .unwrap();
+        assert_eq!(address, p2tr_address(BITCOIN_HRP, &output_key));
+
+        // 主机端输出公钥与 GPU 结果不一致时报错，而不是返回主机端的地址
+        let err = context
+            .render_address(&private_key, &[0u8; 20], None)
+            .unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+    }
+
+    #[test]
+    fn test_search_match_mnemonic_display() {
+        let mut seed = [0u8; 32];
+        seed[..16].fill(0xff);
+        let mut found = SearchMatch {
+            source_mode: SourceMode::MnemonicEntropy,
+            result_seed: seed,
+            eth_address: [0u8; 20],
+            found_by_thread: 0,
+            target_index: 0,
+            found_device: String::from("test"),
+            score: 0,
+            derivation_path: None,
+            address_index: None,
+            word_count: Some(12),
+            language: Some(Language::English),
+            target_chain: TargetChain::Ethereum,
+            address: String::new(),
+        };
+        // 12 个单词: 熵为种子末尾 16 字节
+        assert_eq!(found.entropy(), Some(&[0u8; 16][..]));
+        assert_eq!(
+            found.result_seed_display().as_deref(),
+            Some(
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+            )
+        );
+
+        found.word_count = Some(24);
+        assert_eq!(found.entropy().map(<[u8]>::len), Some(32));
+        assert_eq!(found.result_seed_display().unwrap().split(' ').count(), 24);
+
+        // 日文助记词以全角空格分隔
+        found.language = Some(Language::Japanese);
+        let mnemonic = found.mnemonic().unwrap();
+        assert_eq!(mnemonic.language, Language::Japanese);
+        assert_eq!(
+            found
+                .result_seed_display()
+                .unwrap()
+                .split('\u{3000}')
+                .count(),
+            24
+        );
+    }
+
+    #[test]
+    fn test_match_derivation_path() {
+        let path = parse_derivation_path("m/44'/60'/0'/0/0").unwrap();
+        assert_eq!(match_derivation_path(&path, 7), "m/44'/60'/0'/0/7");
+        // Ledger Live 风格的硬化账户层作为地址索引
+        let path = parse_derivation_path("m/44'/60'/0'").unwrap();
+        assert_eq!(match_derivation_path(&path, 3 | HARDENED), "m/44'/60'/3'");
+        assert_eq!(match_derivation_path(&[], 0), "m");
+    }
+
+    #[test]
+    fn test_search_match_seed_display() {
+        let mut seed = [0u8; 32];
+        seed[31] = 42;
+        let mut found = SearchMatch {
+            source_mode: SourceMode::DeployerNonce,
+            result_seed: seed,
+            eth_address: [0u8; 20],
+            found_by_thread: 0,
+            target_index: 0,
+            found_device: String::from("test"),
+            score: 0,
+            derivation_path: None,
+            address_index: None,
+            word_count: None,
+            language: None,
+            target_chain: TargetChain::Ethereum,
+            address: String::new(),
+        };
+        assert_eq!(found.nonce(), Some(42));
+        assert_eq!(found.result_seed_display().as_deref(), Some("42"));
+        assert!(found.salt().is_none());
+
+        found.source_mode = SourceMode::Create2Salt;
+        assert!(found.nonce().is_none());
+        assert_eq!(found.salt(), Some(seed));
+        assert!(found.result_seed_display().unwrap().ends_with("2a"));
+    }
+
+    #[test]
+    fn test_parse_zero_bytes_condition_via_api() {
+        let (condition, pattern) = parse_condition(&SearchCondition::ZeroBytes(3)).unwrap();
+        assert!(pattern.is_none());
+        assert_eq!((condition >> 48) & 0xFFFF, ConditionType::ZeroBytes as u64);
+        assert_eq!(condition & 0xFFFFFFFFFFFF, 3);
+        assert!(parse_condition(&SearchCondition::ZeroBytes(0)).is_err());
+        assert!(parse_condition(&SearchCondition::ZeroBytes(21)).is_err());
+    }
+
+    #[test]
+    fn test_parse_contains_condition_via_api() {
+        use crate::config::matches_condition;
+
+        let (condition, pattern) =
+            parse_condition(&SearchCondition::Contains(String::from("beef"))).unwrap();
+        let pattern = pattern.unwrap();
+        assert_eq!((condition >> 48) & 0xFFFF, ConditionType::Contains as u64);
+        assert_eq!(condition & 0xFFFFFFFFFFFF, 4);
+        let mut address = [0x12u8; 20];
+        address[9..12].copy_from_slice(&[0x3b, 0xee, 0xf4]);
+        assert!(matches_condition(&address, condition, &pattern));
+
+        assert!(parse_condition(&SearchCondition::Contains(String::from("rust"))).is_err());
+        assert!(parse_condition(&SearchCondition::Contains(String::new())).is_err());
+    }
+
+    #[test]
+    fn test_parse_range_condition_via_api() {
+        use crate::config::matches_condition;
+
+        let mut high = [0u8; 20];
+        high[2] = 0x10;
+        let (condition, pattern) = parse_condition(&SearchCondition::Range {
+            low: [0u8; 20],
+            high: Some(high),
+        })
+        .unwrap();
+        let pattern = pattern.unwrap();
+        assert_eq!((condition >> 48) & 0xFFFF, ConditionType::Range as u64);
+        let mut address = [0xFFu8; 20];
+        address[..3].copy_from_slice(&[0x00, 0x00, 0x0F]);
+        assert!(matches_condition(&address, condition, &pattern));
+        address[2] = 0x10;
+        assert!(!matches_condition(&address, condition, &pattern));
+
+        assert!(
+            parse_condition(&SearchCondition::Range {
+                low: high,
+                high: Some(high),
+            })
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_char_class_conditions_via_api() {
+        use crate::config::matches_condition;
+
+        let (condition, pattern) = parse_condition(&SearchCondition::AllLetters).unwrap();
+        let pattern = pattern.unwrap();
+        assert_eq!((condition >> 48) & 0xFFFF, ConditionType::CharClass as u64);
+        assert!(matches_condition(&[0xFA; 20], condition, &pattern));
+        assert!(!matches_condition(&[0xF0; 20], condition, &pattern));
+
+        let (condition, pattern) = parse_condition(&SearchCondition::Mirror(2)).unwrap();
+        let pattern = pattern.unwrap();
+        let mut address = [0x77u8; 20];
+        address[0] = 0xAB;
+        address[19] = 0xBA;
+        assert!(matches_condition(&address, condition, &pattern));
+        address[19] = 0xAB;
+        assert!(!matches_condition(&address, condition, &pattern));
+
+        assert!(parse_condition(&SearchCondition::LeadingDoubles(0)).is_err());
+        assert!(parse_condition(&SearchCondition::Mirror(21)).is_err());
+
+        // 字符类只能配合模式使用大小写匹配
+        let target = build_target(
+            &SearchCondition::Pattern(format!("0xAB{{L}}{}", "X".repeat(37))),
+            true,
+            ConditionEncoding::Pattern,
+        )
+        .unwrap();
+        assert_eq!(target.case_mask[0], 0x88);
+        assert_eq!(
+            target.pattern_config.classes[2],
+            crate::config::NIBBLE_LETTER
+        );
+        assert!(
+            build_target(
+                &SearchCondition::AllDigits,
+                true,
+                ConditionEncoding::Pattern
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_specialized_encoding_matches_pattern_path() {
+        use crate::config::matches_condition;
+
+        let conditions = [
+            SearchCondition::Prefix(String::from("12")),
+            SearchCondition::Prefix(String::from("0x1234")),
+            SearchCondition::Prefix(String::from("123456")),
+            SearchCondition::Prefix(String::from("1234567890")),
+            SearchCondition::Suffix(String::from("ad")),
+            SearchCondition::Suffix(String::from("beef")),
+            SearchCondition::Suffix(String::from("00dead")),
+            SearchCondition::Suffix(String::from("ABCDEF0123")),
+            SearchCondition::LeadingZeros(0),
+            SearchCondition::LeadingZeros(3),
+            SearchCondition::LeadingZeros(4),
+        ];
+
+        let mut rng = rand::thread_rng();
+        for condition in &conditions {
+            let (specialized, none) =
+                parse_condition_with_encoding(condition, ConditionEncoding::Specialized).unwrap();
+            assert!(
+                none.is_none(),
+                "{:?} should use a specialized encoding",
+                condition
+            );
+            assert_ne!((specialized >> 48) & 0xFFFF, ConditionType::Pattern as u64);
+            let (pattern_cond, pattern) = parse_condition(condition).unwrap();
+            let pattern = pattern.unwrap();
+
+            // 随机地址几乎不会命中，再构造一个命中的地址 (把模式的 value 写进去)
+            for i in 0..2000 {
+                let mut address = [0u8; 20];
+                rng.fill_bytes(&mut address);
+                if i % 2 == 0 {
+                    for (a, (m, v)) in address
+                        .iter_mut()
+                        .zip(pattern.mask.iter().zip(pattern.value.iter()))
+                    {
+                        *a = (*a & !m) | v;
+                    }
+                }
+                assert_eq!(
+                    matches_condition(&address, specialized, &PatternConfig::default()),
+                    matches_condition(&address, pattern_cond, &pattern),
+                    "{:?} disagrees on {}",
+                    condition,
+                    hex::encode(address)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_specialized_encoding_falls_back_to_pattern() {
+        // 奇数长度和超过 5 字节的前缀/后缀无法专用编码
+        for condition in [
+            SearchCondition::Prefix(String::from("000")),
+            SearchCondition::Prefix(String::from("123456789012")),
+            SearchCondition::Suffix(String::from("dead00beef01")),
+            SearchCondition::Pattern(String::from("0xXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXdead")),
+        ] {
+            let (condition, pattern) =
+                parse_condition_with_encoding(&condition, ConditionEncoding::Specialized).unwrap();
+            assert!(pattern.is_some());
+            assert_eq!((condition >> 48) & 0xFFFF, ConditionType::Pattern as u64);
+        }
+        assert!(
+            parse_condition_with_encoding(
+                &SearchCondition::Prefix(String::from("xyz")),
+                ConditionEncoding::Specialized
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_leading_zeros_exact_condition() {
+        let (condition, pattern) = parse_condition(&SearchCondition::LeadingZerosExact(6)).unwrap();
+        assert!(pattern.is_none());
+        assert_eq!(
+            (condition >> 48) & 0xFFFF,
+            ConditionType::LeadingExact as u64
+        );
+        assert_eq!(condition & 0xFFFFFFFFFFFF, 6);
+        assert!(parse_condition(&SearchCondition::LeadingZerosExact(41)).is_err());
+    }
+
+    #[test]
+    fn test_score_conditions() {
+        assert_eq!(
+            SearchCondition::ScoreZeroNibbles.score_mode(),
+            ScoreMode::ZeroNibbles
+        );
+        assert_eq!(
+            SearchCondition::Prefix(String::from("00")).score_mode(),
+            ScoreMode::None
+        );
+
+        let target = build_target(
+            &SearchCondition::ScoreMatching(String::from(
+                "0xdeadXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            )),
+            false,
+            ConditionEncoding::Pattern,
+        )
+        .unwrap();
+        assert_eq!(ScoreMode::Matching.max_score(&target.pattern_config), 4);
+        let target = build_target(
+            &SearchCondition::ScoreLeadingZeros,
+            false,
+            ConditionEncoding::Pattern,
+        )
+        .unwrap();
+        assert!(target.pattern_config.mask.iter().all(|m| *m == 0));
+    }
+
+    #[test]
+    fn test_build_targets_for_condition_tree() {
+        use crate::config::evaluate_targets;
+
+        // #0: (prefix dead OR prefix beef) AND NOT suffix 00, #1: suffix 11
+        let tree = SearchCondition::Prefix(String::from("dead"))
+            .or(SearchCondition::Prefix(String::from("beef")))
+            .and(!SearchCondition::Suffix(String::from("00")));
+        let conditions = vec![tree, SearchCondition::Suffix(String::from("11"))];
+        let targets = build_targets(&conditions, false, ConditionEncoding::Specialized).unwrap();
+        assert_eq!(targets.len(), 7);
+        let roots: Vec<u32> = targets.iter().map(|t| t.root).collect();
+        assert_eq!(roots, [0, 0, 0, 0, 0, 1, 1]);
+        assert_eq!(targets[2].condition >> 48, ConditionType::Or as u64);
+        assert_eq!(targets[4].condition >> 48, ConditionType::Not as u64);
+        assert_eq!(targets[5].condition & 0xFF, 2);
+
+        let mut address = [0x55u8; 20];
+        address[..2].copy_from_slice(&[0xde, 0xad]);
+        assert_eq!(evaluate_targets(&address, &targets), Some(0));
+        address[19] = 0x00;
+        assert_eq!(evaluate_targets(&address, &targets), None);
+        address[19] = 0x11;
+        address[0] = 0x00;
+        assert_eq!(evaluate_targets(&address, &targets), Some(1));
+
+        // 单独的条件与原来的目标表一致
+        let plain = build_targets(
+            &[SearchCondition::Prefix(String::from("00"))],
+            false,
+            ConditionEncoding::Pattern,
+        )
+        .unwrap();
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain[0].root, 1);
+
+        assert!(
+            build_targets(
+                &[SearchCondition::And(vec![])],
+                false,
+                ConditionEncoding::Pattern
+            )
+            .is_err()
+        );
+        assert!(
+            build_targets(
+                &[SearchCondition::Or(vec![
+                    SearchCondition::LeadingZeros(2);
+                    32
+                ])],
+                false,
+                ConditionEncoding::Pattern,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_build_targets_for_multiple_conditions() {
+        let conditions = [
+            SearchCondition::Prefix(String::from("dead")),
+            SearchCondition::Suffix(String::from("BEEF")),
+        ];
+        let targets: Vec<_> = conditions
+            .iter()
+            .map(|c| build_target(c, true, ConditionEncoding::Pattern).unwrap())
+            .collect();
+        assert_eq!(targets[0].pattern_config.value[0], 0xDE);
+        assert_eq!(targets[0].case_sensitive, 1);
+        assert_eq!(targets[1].pattern_config.value[19], 0xEF);
+        assert_eq!(targets[1].case_value[19], 0x88);
+
+        let target = build_target(
+            &SearchCondition::LeadingZeros(4),
+            false,
+            ConditionEncoding::Pattern,
+        )
+        .unwrap();
+        assert_eq!(target.case_sensitive, 0);
+        assert!(
+            build_target(
+                &SearchCondition::LeadingZeros(4),
+                true,
+                ConditionEncoding::Pattern
+            )
+            .is_err()
+        );
     }
 
     #[test]
@@ -391,6 +2145,46 @@ mod tests {
         assert_eq!(cond_type, ConditionType::Pattern as u64);
     }
 
+    #[test]
+    fn test_parse_mask_and_hook_conditions_via_api() {
+        let mut mask = [0u8; 20];
+        let mut value = [0u8; 20];
+        mask[19] = 0x0F;
+        value[19] = 0x05;
+        let (condition, pattern) = parse_condition(&SearchCondition::Mask { mask, value }).unwrap();
+        assert_eq!((condition >> 48) & 0xFFFF, ConditionType::Pattern as u64);
+        assert_eq!(pattern.unwrap().value[19], 0x05);
+
+        value[19] = 0x50;
+        assert!(parse_condition(&SearchCondition::Mask { mask, value }).is_err());
+
+        let (_, pattern) = parse_condition(&SearchCondition::HookFlags(
+            crate::hooks::BEFORE_SWAP | crate::hooks::AFTER_SWAP,
+        ))
+        .unwrap();
+        let pattern = pattern.unwrap();
+        assert_eq!(pattern.mask[18], 0x3F);
+        assert_eq!(pattern.value[19], 0xC0);
+    }
+
+    #[test]
+    fn test_parse_case_condition_via_api() {
+        let case_pattern =
+            parse_case_condition(&SearchCondition::Prefix(String::from("DeAd"))).unwrap();
+        assert_eq!(case_pattern.mask[0], 0x88);
+        assert_eq!(case_pattern.value[0], 0x80);
+        assert_eq!(case_pattern.mask[1], 0x88);
+        assert_eq!(case_pattern.value[1], 0x80);
+
+        let case_pattern =
+            parse_case_condition(&SearchCondition::Suffix(String::from("bEEF"))).unwrap();
+        assert_eq!(case_pattern.mask[18], 0x88);
+        assert_eq!(case_pattern.value[18], 0x08);
+        assert_eq!(case_pattern.value[19], 0x88);
+
+        assert!(parse_case_condition(&SearchCondition::LeadingZeros(4)).is_err());
+    }
+
     #[test]
     fn test_parse_leading_zeros_condition_via_api_uses_pattern() {
         let (condition, pattern) = parse_condition(&SearchCondition::LeadingZeros(4)).unwrap();
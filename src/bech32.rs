@@ -0,0 +1,215 @@
+//! Bech32 / Bech32m 编码 (BIP-173 / BIP-350)
+//!
+//! SegWit、Taproot 地址和 Nostr 密钥都使用 bech32: `hrp || "1" || 数据字符 || 6 字符校验和`，
+//! 每个数据字符表示 5 位。地址前缀的每个字符恰好固定数据中连续的 5 位，
+//! 因此前缀可以换算为 20 字节匹配值上的位掩码，交给内核已有的模式匹配。
+
+/// 数据字符表 (下标即 5 位的值)
+pub const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// 校验和常数不同的两种变体
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// BIP-173: SegWit v0 地址、Nostr 密钥
+    Bech32,
+    /// BIP-350: SegWit v1+ (Taproot) 地址
+    Bech32m,
+}
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc8_30a3,
+        }
+    }
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut chk = 1u32;
+    for value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ value as u32;
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+/// 8 位字节按大端位序重新分组为 5 位一组，末尾不足 5 位时补零
+pub fn to_base32(data: &[u8]) -> Vec<u8> {
+    let mut groups = Vec::with_capacity((data.len() * 8).div_ceil(5));
+    let mut acc = 0u32;
+    let mut bits = 0;
+    for byte in data {
+        acc = (acc << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            groups.push(((acc >> bits) & 0x1f) as u8);
+        }
+    }
+    if bits > 0 {
+        groups.push(((acc << (5 - bits)) & 0x1f) as u8);
+    }
+    groups
+}
+
+/// 编码为 bech32 字符串 (data 为 5 位一组的值)
+pub fn encode(hrp: &str, data: &[u8], variant: Variant) -> String {
+    let hrp = hrp.to_ascii_lowercase();
+    let expanded = hrp
+        .bytes()
+        .map(|b| b >> 5)
+        .chain([0])
+        .chain(hrp.bytes().map(|b| b & 0x1f));
+    let checksum = polymod(expanded.chain(data.iter().copied()).chain([0; 6])) ^ variant.constant();
+
+    let charset = CHARSET.as_bytes();
+    let mut encoded = hrp.clone();
+    encoded.push('1');
+    encoded.extend(data.iter().map(|d| charset[*d as usize] as char));
+    encoded.extend((0..6).map(|i| charset[((checksum >> (5 * (5 - i))) & 0x1f) as usize] as char));
+    encoded
+}
+
+/// 将数据字符前缀换算为前 20 字节上的位掩码和期望值
+///
+/// 第 i 个字符固定第 5i 到 5i+4 位 (大端位序)，20 字节最多容纳 32 个字符。
+/// 字符不区分大小写 (bech32 只允许全小写或全大写)。
+///
+/// # Errors
+/// 含有 bech32 字符表以外的字符 (如 1、b、i、o) 或超过 32 个字符时返回错误
+pub fn prefix_mask(chars: &str) -> anyhow::Result<([u8; 20], [u8; 20])> {
+    if chars.len() > 32 {
+        anyhow::bail!(
+            "bech32 prefix can constrain at most 32 data characters, got {}",
+            chars.len()
+        );
+    }
+    let mut mask = [0u8; 20];
+    let mut value = [0u8; 20];
+    for (i, c) in chars.chars().enumerate() {
+        let group = CHARSET.find(c.to_ascii_lowercase()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid bech32 character '{}' (1, b, i and o are not used)",
+                c
+            )
+        })?;
+        for bit in 0..5 {
+            let pos = 5 * i + bit;
+            let byte_bit = 0x80 >> (pos % 8);
+            mask[pos / 8] |= byte_bit;
+            if (group >> (4 - bit)) & 1 == 1 {
+                value[pos / 8] |= byte_bit;
+            }
+        }
+    }
+    Ok((mask, value))
+}
+
+/// 将以 head (如 `bc1q`、`npub1`) 开头的完整地址前缀换算为位掩码和期望值，见 `prefix_mask`
+///
+/// # Errors
+/// 开头不符、大小写混用或数据字符无效时返回错误
+pub fn address_prefix_mask(prefix: &str, head: &str) -> anyhow::Result<([u8; 20], [u8; 20])> {
+    let lower = prefix.to_ascii_lowercase();
+    if prefix != lower && prefix != prefix.to_ascii_uppercase() {
+        anyhow::bail!("bech32 prefix '{}' cannot mix upper and lower case", prefix);
+    }
+    let Some(rest) = lower.strip_prefix(head) else {
+        anyhow::bail!("bech32 prefix '{}' must start with '{}'", prefix, head);
+    };
+    prefix_mask(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_chars(chars: &str) -> Vec<u8> {
+        chars
+            .chars()
+            .map(|c| CHARSET.find(c).unwrap() as u8)
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_vectors() {
+        // BIP-173 / BIP-350 的有效字符串
+        assert_eq!(encode("a", &[], Variant::Bech32), "a12uel5l");
+        assert_eq!(encode("A", &[], Variant::Bech32), "a12uel5l");
+        assert_eq!(encode("a", &[], Variant::Bech32m), "a1lqfn3a");
+
+        let data: Vec<u8> = (0..32).collect();
+        assert_eq!(
+            encode("abcdef", &data, Variant::Bech32),
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"
+        );
+        let data: Vec<u8> = (0..32).rev().collect();
+        assert_eq!(
+            encode("abcdef", &data, Variant::Bech32m),
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx"
+        );
+
+        let data = decode_chars("checkupstagehandshakeupstreamerranterredcaperred");
+        assert_eq!(
+            encode("split", &data, Variant::Bech32),
+            "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w"
+        );
+        assert_eq!(
+            encode("split", &data, Variant::Bech32m),
+            "split1checkupstagehandshakeupstreamerranterredcaperredlc445v"
+        );
+    }
+
+    #[test]
+    fn test_to_base32() {
+        assert_eq!(to_base32(&[0xff]), vec![31, 28]);
+        assert_eq!(to_base32(&[0u8; 20]).len(), 32);
+        assert_eq!(to_base32(&[0u8; 32]).len(), 52);
+    }
+
+    #[test]
+    fn test_prefix_mask() {
+        let (mask, value) = prefix_mask("").unwrap();
+        assert_eq!(mask, [0u8; 20]);
+        assert_eq!(value, [0u8; 20]);
+
+        // "l" = 31 固定前 5 位，"q" = 0 固定接下来的 5 位
+        let (mask, value) = prefix_mask("lq").unwrap();
+        assert_eq!(&mask[..2], &[0xff, 0xc0]);
+        assert_eq!(&value[..2], &[0xf8, 0x00]);
+        assert_eq!(prefix_mask("LQ").unwrap(), (mask, value));
+
+        // 掩码内的位与编码结果一致
+        let data = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        let chars: String = to_base32(&data)
+            .iter()
+            .map(|d| CHARSET.as_bytes()[*d as usize] as char)
+            .collect();
+        assert_eq!(decode_chars(&chars), to_base32(&data));
+        let (mask, value) = prefix_mask(&chars).unwrap();
+        assert_eq!(mask, [0xff; 20]);
+        assert_eq!(value.to_vec(), data);
+
+        assert!(prefix_mask("b").is_err());
+        assert_eq!(
+            address_prefix_mask("NPUB1LQ", "npub1").unwrap(),
+            prefix_mask("lq").unwrap()
+        );
+        assert!(address_prefix_mask("Npub1lq", "npub1").is_err());
+        assert!(address_prefix_mask("nsec1lq", "npub1").is_err());
+        assert!(prefix_mask(&"q".repeat(33)).is_err());
+    }
+}
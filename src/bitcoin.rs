@@ -0,0 +1,433 @@
+//! 比特币 P2PKH 地址 (Base58Check)
+//!
+//! P2PKH 地址是 `Base58Check(版本字节 || HASH160(压缩公钥))`，其中
+//! HASH160 = RIPEMD-160(SHA-256(公钥))。Litecoin、Dogecoin 等分叉币结构相同，只有版本字节不同。
+//!
+//! GPU 只计算 HASH160，不做 Base58 编码: 25 字节载荷 (版本 || HASH160 || 4 字节校验和)
+//! 按 200 位大端整数编码为 Base58，固定长度下以某个前缀开头的载荷恰好构成一个整数区间，
+//! 因此地址前缀可以换算为 HASH160 上的若干个数值范围，交给内核已有的 Range 条件匹配。
+//!
+//! SegWit (P2WPKH, `bc1q...`) 地址是 HASH160 的 bech32 编码，Taproot (P2TR, `bc1p...`)
+//! 地址是 BIP-341 调整后输出公钥 x 坐标的 bech32m 编码。bech32 每个字符固定 5 位，
+//! 前缀直接换算为见证程序前 20 字节上的位掩码。
+
+use num_bigint::BigUint;
+use ripemd::Ripemd160;
+use secp256k1::{PublicKey, SECP256K1, Scalar, SecretKey};
+use sha2::{Digest, Sha256};
+
+use crate::bech32::{self, Variant};
+
+/// Base58 字母表 (不含 0、O、I、l)
+pub const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// 比特币主网 P2PKH 版本字节 (地址以 1 开头)
+pub const BITCOIN_P2PKH_VERSION: u8 = 0x00;
+/// Litecoin P2PKH 版本字节 (地址以 L 开头)
+pub const LITECOIN_P2PKH_VERSION: u8 = 0x30;
+/// Dogecoin P2PKH 版本字节 (地址以 D 开头)
+pub const DOGECOIN_P2PKH_VERSION: u8 = 0x1E;
+
+/// 比特币主网 SegWit / Taproot 地址的 bech32 前缀
+pub const BITCOIN_HRP: &str = "bc";
+/// Litecoin SegWit / Taproot 地址的 bech32 前缀
+pub const LITECOIN_HRP: &str = "ltc";
+
+/// 默认派生路径 (BIP44 比特币第一个接收地址)
+pub const DEFAULT_BITCOIN_DERIVATION_PATH: &str = "m/44'/0'/0'/0/0";
+/// Litecoin 默认派生路径 (SLIP-44 币种 2)
+pub const DEFAULT_LITECOIN_DERIVATION_PATH: &str = "m/44'/2'/0'/0/0";
+/// Dogecoin 默认派生路径 (SLIP-44 币种 3)
+pub const DEFAULT_DOGECOIN_DERIVATION_PATH: &str = "m/44'/3'/0'/0/0";
+
+/// 载荷字节数: 版本 (1) + HASH160 (20) + 校验和 (4)
+const PAYLOAD_BYTES: usize = 25;
+
+/// 版本字节对应币种的默认 BIP44 派生路径 (未知版本字节使用比特币的路径)
+pub fn default_derivation_path(version: u8) -> &'static str {
+    match version {
+        LITECOIN_P2PKH_VERSION => DEFAULT_LITECOIN_DERIVATION_PATH,
+        DOGECOIN_P2PKH_VERSION => DEFAULT_DOGECOIN_DERIVATION_PATH,
+        _ => DEFAULT_BITCOIN_DERIVATION_PATH,
+    }
+}
+
+/// bech32 前缀对应币种的默认派生路径: P2WPKH 使用 BIP84，P2TR 使用 BIP86
+pub fn default_bech32_derivation_path(hrp: &str, taproot: bool) -> String {
+    let purpose = if taproot { 86 } else { 84 };
+    let coin_type = match hrp {
+        LITECOIN_HRP => 2,
+        "tb" | "bcrt" => 1,
+        _ => 0,
+    };
+    format!("m/{}'/{}'/0'/0/0", purpose, coin_type)
+}
+
+/// HASH160 = RIPEMD-160(SHA-256(data))
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(Sha256::digest(data)).into()
+}
+
+/// 由私钥计算公钥
+pub fn public_key_from_private_key(private_key: &[u8; 32]) -> anyhow::Result<PublicKey> {
+    let secret_key = SecretKey::from_slice(private_key)
+        .map_err(|e| anyhow::anyhow!("Invalid secret key: {}", e))?;
+    Ok(PublicKey::from_secret_key(SECP256K1, &secret_key))
+}
+
+/// 由私钥计算压缩公钥的 HASH160 (主机端，用于校验和显示)
+pub fn hash160_from_private_key(private_key: &[u8; 32]) -> anyhow::Result<[u8; 20]> {
+    Ok(hash160(
+        &public_key_from_private_key(private_key)?.serialize(),
+    ))
+}
+
+/// Taproot 输出公钥 (BIP-86，无脚本路径): Q = P + H_TapTweak(P.x)·G，返回 Q 的 x 坐标
+///
+/// 内部公钥 P 取 y 为偶数的那个点，tagged hash 为 SHA-256(SHA-256(tag) || SHA-256(tag) || P.x)。
+pub fn taproot_output_key(internal_key: &PublicKey) -> anyhow::Result<[u8; 32]> {
+    let (x_only, _) = internal_key.x_only_public_key();
+    let tag = Sha256::digest(b"TapTweak");
+    let tweak: [u8; 32] = Sha256::new()
+        .chain_update(tag)
+        .chain_update(tag)
+        .chain_update(x_only.serialize())
+        .finalize()
+        .into();
+    let tweak = Scalar::from_be_bytes(tweak)
+        .map_err(|_| anyhow::anyhow!("Taproot tweak is out of range for secp256k1"))?;
+    let (output_key, _) = x_only
+        .add_tweak(SECP256K1, &tweak)
+        .map_err(|e| anyhow::anyhow!("Taproot output key is invalid: {}", e))?;
+    Ok(output_key.serialize())
+}
+
+/// P2WPKH 地址: bech32(hrp, 见证版本 0 || hash160)
+pub fn p2wpkh_address(hrp: &str, hash160: &[u8; 20]) -> String {
+    let mut data = vec![0u8];
+    data.extend(bech32::to_base32(hash160));
+    bech32::encode(hrp, &data, Variant::Bech32)
+}
+
+/// P2TR 地址: bech32m(hrp, 见证版本 1 || 输出公钥 x 坐标)
+pub fn p2tr_address(hrp: &str, output_key: &[u8; 32]) -> String {
+    let mut data = vec![1u8];
+    data.extend(bech32::to_base32(output_key));
+    bech32::encode(hrp, &data, Variant::Bech32m)
+}
+
+/// 将 SegWit 地址前缀 (如 bc1qxyz、bc1pxyz) 换算为见证程序前 20 字节上的位掩码和期望值
+///
+/// 前缀必须以 `hrp || "1" || 见证版本字符` 开头 (P2WPKH 为 q，P2TR 为 p)，之后最多 32 个字符。
+///
+/// # Errors
+/// 开头不符、大小写混用或含有非 bech32 字符时返回错误
+pub fn segwit_prefix_mask(
+    prefix: &str,
+    hrp: &str,
+    witness_version: u8,
+) -> anyhow::Result<([u8; 20], [u8; 20])> {
+    let version_char = bech32::CHARSET.as_bytes()[witness_version as usize & 0x1f] as char;
+    let head = format!("{}1{}", hrp.to_ascii_lowercase(), version_char);
+    bech32::address_prefix_mask(prefix, &head)
+}
+
+/// P2PKH 地址: Base58Check(version || hash160)
+pub fn p2pkh_address(version: u8, hash160: &[u8; 20]) -> String {
+    let mut payload = Vec::with_capacity(21);
+    payload.push(version);
+    payload.extend_from_slice(hash160);
+    bs58::encode(payload).with_check().into_string()
+}
+
+/// 压缩公钥对应的 WIF 私钥: Base58Check(version + 0x80 || 私钥 || 0x01)
+///
+/// 比特币 (0x80)、Litecoin (0xB0)、Dogecoin (0x9E) 的 WIF 版本字节都是地址版本字节加 0x80。
+pub fn private_key_to_wif(private_key: &[u8; 32], address_version: u8) -> String {
+    let mut payload = Vec::with_capacity(34);
+    payload.push(address_version.wrapping_add(0x80));
+    payload.extend_from_slice(private_key);
+    payload.push(0x01);
+    bs58::encode(payload).with_check().into_string()
+}
+
+/// HASH160 上的数值范围 `[low, high)`，high 为 None 表示没有上界
+pub type Hash160Range = ([u8; 20], Option<[u8; 20]>);
+
+/// 将 Base58Check 地址前缀换算为 HASH160 上的数值范围 `[low, high)`，high 为 None 表示没有上界
+///
+/// 前导的 `1` 与载荷的前导零字节一一对应，其余字符在每种可能的地址长度下各对应一个整数区间。
+/// 区间边界上的 HASH160 是否命中取决于校验和，这里直接排除 (概率可以忽略)，保证不会误报。
+///
+/// # Errors
+/// 前缀为空、含有非 Base58 字符，或在该版本字节下不可能出现时返回错误
+pub fn base58_prefix_ranges(prefix: &str, version: u8) -> anyhow::Result<Vec<Hash160Range>> {
+    if prefix.is_empty() {
+        anyhow::bail!("Base58 prefix cannot be empty");
+    }
+    let digits = prefix
+        .chars()
+        .map(|c| {
+            BASE58_ALPHABET.find(c).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid Base58 character '{}' in prefix (0, O, I and l are not used)",
+                    c
+                )
+            })
+        })
+        .collect::<anyhow::Result<Vec<usize>>>()?;
+
+    let ones = digits.iter().take_while(|d| **d == 0).count();
+    let rest = &digits[ones..];
+    let byte_power = |bytes: usize| BigUint::from(1u8) << (8 * bytes as u32);
+
+    if ones + usize::from(!rest.is_empty()) > PAYLOAD_BYTES {
+        anyhow::bail!("Base58 prefix '{}' is too long", prefix);
+    }
+    // 载荷前导零字节数: 只有 '1' 时至少为 ones，否则恰好为 ones
+    let zero_high = byte_power(PAYLOAD_BYTES - ones);
+    let zero_low = if rest.is_empty() {
+        BigUint::default()
+    } else {
+        byte_power(PAYLOAD_BYTES - 1 - ones)
+    };
+
+    // 其余字符: 地址多出 extra 个字符时，整数落在 [r * 58^extra, (r + 1) * 58^extra)
+    let mut intervals = Vec::new();
+    if rest.is_empty() {
+        intervals.push((zero_low, zero_high));
+    } else {
+        let r = rest
+            .iter()
+            .fold(BigUint::default(), |acc, d| acc * 58u32 + *d as u32);
+        let mut scale = BigUint::from(1u8);
+        loop {
+            let low = &r * &scale;
+            if low >= zero_high {
+                break;
+            }
+            let high = (&r + 1u32) * &scale;
+            intervals.push((low.max(zero_low.clone()), high.min(zero_high.clone())));
+            scale *= 58u32;
+        }
+    }
+
+    // 版本字节固定: 载荷 = version * 2^192 + hash160 * 2^32 + 校验和
+    let version_base = BigUint::from(version) << 192u32;
+    let version_end = BigUint::from(version as u32 + 1) << 192u32;
+    let hash_limit = BigUint::from(1u8) << 160u32;
+    let mut ranges = Vec::new();
+    for (low, high) in intervals {
+        let low = low.max(version_base.clone());
+        let high = high.min(version_end.clone());
+        if low >= high {
+            continue;
+        }
+        // 只保留任意校验和都落在区间内的 HASH160
+        let low = &low - &version_base;
+        let high = &high - &version_base;
+        let hash_low = (low + u32::MAX) >> 32u32;
+        let hash_high = high >> 32u32;
+        if hash_low >= hash_high {
+            continue;
+        }
+        let high = (hash_high < hash_limit).then(|| to_hash160_bytes(&hash_high));
+        ranges.push((to_hash160_bytes(&hash_low), high));
+    }
+
+    if ranges.is_empty() {
+        anyhow::bail!(
+            "Base58 prefix '{}' cannot occur with version byte 0x{:02x}",
+            prefix,
+            version
+        );
+    }
+    Ok(ranges)
+}
+
+/// 小于 2^160 的整数转为 20 字节大端序
+fn to_hash160_bytes(value: &BigUint) -> [u8; 20] {
+    let bytes = value.to_bytes_be();
+    let mut out = [0u8; 20];
+    out[20 - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn in_ranges(ranges: &[Hash160Range], hash: &[u8; 20]) -> bool {
+        ranges
+            .iter()
+            .any(|(low, high)| hash >= low && high.is_none_or(|high| *hash < high))
+    }
+
+    #[test]
+    fn test_p2pkh_address() {
+        // 私钥 1 的压缩公钥
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let hash = hash160_from_private_key(&private_key).unwrap();
+        assert_eq!(
+            hex::encode(hash),
+            "751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert_eq!(
+            p2pkh_address(BITCOIN_P2PKH_VERSION, &hash),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+        );
+        assert_eq!(
+            private_key_to_wif(&private_key, BITCOIN_P2PKH_VERSION),
+            "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn"
+        );
+        assert!(p2pkh_address(LITECOIN_P2PKH_VERSION, &hash).starts_with('L'));
+        assert!(p2pkh_address(DOGECOIN_P2PKH_VERSION, &hash).starts_with('D'));
+    }
+
+    #[test]
+    fn test_segwit_addresses() {
+        // BIP-173 示例: 私钥 1 的 P2WPKH 地址
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let hash = hash160_from_private_key(&private_key).unwrap();
+        assert_eq!(
+            p2wpkh_address(BITCOIN_HRP, &hash),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+
+        // BIP-86 测试向量: m/86'/0'/0'/0/0
+        let mnemonic = crate::mnemonic::Mnemonic::from_string(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        let private_key = mnemonic.derive_private_key("", "m/86'/0'/0'/0/0").unwrap();
+        let internal_key = public_key_from_private_key(&private_key).unwrap();
+        assert_eq!(
+            hex::encode(internal_key.x_only_public_key().0.serialize()),
+            "cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115"
+        );
+        let output_key = taproot_output_key(&internal_key).unwrap();
+        assert_eq!(
+            hex::encode(output_key),
+            "a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c"
+        );
+        assert_eq!(
+            p2tr_address(BITCOIN_HRP, &output_key),
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+
+        assert_eq!(
+            default_bech32_derivation_path(BITCOIN_HRP, true),
+            "m/86'/0'/0'/0/0"
+        );
+        assert_eq!(
+            default_bech32_derivation_path(LITECOIN_HRP, false),
+            "m/84'/2'/0'/0/0"
+        );
+    }
+
+    #[test]
+    fn test_segwit_prefix_mask() {
+        let hash: [u8; 20] = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let matches = |(mask, value): ([u8; 20], [u8; 20]), data: &[u8]| {
+            mask.iter()
+                .zip(value.iter())
+                .zip(data.iter())
+                .all(|((m, v), d)| d & m == *v)
+        };
+        assert!(matches(
+            segwit_prefix_mask("bc1qw508d", BITCOIN_HRP, 0).unwrap(),
+            &hash
+        ));
+        assert!(matches(
+            segwit_prefix_mask("BC1QW508D", BITCOIN_HRP, 0).unwrap(),
+            &hash
+        ));
+        assert!(!matches(
+            segwit_prefix_mask("bc1qw508e", BITCOIN_HRP, 0).unwrap(),
+            &hash
+        ));
+        assert!(matches(
+            segwit_prefix_mask("bc1q", BITCOIN_HRP, 0).unwrap(),
+            &hash
+        ));
+
+        let output_key =
+            hex::decode("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c")
+                .unwrap();
+        assert!(matches(
+            segwit_prefix_mask("bc1p5cyxnux", BITCOIN_HRP, 1).unwrap(),
+            &output_key
+        ));
+
+        // 见证版本、hrp 不符，大小写混用
+        assert!(segwit_prefix_mask("bc1pw508d", BITCOIN_HRP, 0).is_err());
+        assert!(segwit_prefix_mask("ltc1qw508d", BITCOIN_HRP, 0).is_err());
+        assert!(segwit_prefix_mask("bc1qW508d", BITCOIN_HRP, 0).is_err());
+        assert!(segwit_prefix_mask("bc1qb", BITCOIN_HRP, 0).is_err());
+    }
+
+    #[test]
+    fn test_base58_prefix_ranges() {
+        // 创世区块地址 1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa
+        let genesis: [u8; 20] = hex::decode("62e907b15cbf27d5425399ebf6f0fb50ebb88f18")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(in_ranges(
+            &base58_prefix_ranges("1A1zP1", 0).unwrap(),
+            &genesis
+        ));
+        assert!(!in_ranges(
+            &base58_prefix_ranges("1A1zP2", 0).unwrap(),
+            &genesis
+        ));
+        assert!(!in_ranges(
+            &base58_prefix_ranges("11", 0).unwrap(),
+            &genesis
+        ));
+
+        // 范围内的 HASH160 编码后都以前缀开头，范围外的都不以前缀开头
+        let limit = BigUint::from(1u8) << 160u32;
+        for (prefix, version) in [
+            ("1Love", BITCOIN_P2PKH_VERSION),
+            ("11", BITCOIN_P2PKH_VERSION),
+            ("112", BITCOIN_P2PKH_VERSION),
+            ("1z", BITCOIN_P2PKH_VERSION),
+            ("LTC", LITECOIN_P2PKH_VERSION),
+            ("DCA", DOGECOIN_P2PKH_VERSION),
+        ] {
+            let ranges = base58_prefix_ranges(prefix, version).unwrap();
+            let address = |value: &BigUint| p2pkh_address(version, &to_hash160_bytes(value));
+            for (low, high) in &ranges {
+                let low = BigUint::from_bytes_be(low);
+                let high = high.map_or(limit.clone(), |h| BigUint::from_bytes_be(&h));
+                for inside in [low.clone(), (&low + &high) >> 1, &high - 1u32] {
+                    assert!(address(&inside).starts_with(prefix), "{}", address(&inside));
+                }
+                // 跳过紧邻下界、是否命中取决于校验和的那个值
+                let mut outside = vec![&high + 1u32];
+                if low >= BigUint::from(2u8) {
+                    outside.push(&low - 2u32);
+                }
+                for value in outside {
+                    if value < limit && !in_ranges(&ranges, &to_hash160_bytes(&value)) {
+                        assert!(!address(&value).starts_with(prefix), "{}", address(&value));
+                    }
+                }
+            }
+        }
+
+        // 比特币 P2PKH 地址都以 1 开头
+        let ranges = base58_prefix_ranges("1", BITCOIN_P2PKH_VERSION).unwrap();
+        assert_eq!(ranges, vec![([0u8; 20], None)]);
+        assert!(base58_prefix_ranges("3", BITCOIN_P2PKH_VERSION).is_err());
+        assert!(base58_prefix_ranges("DoGe", DOGECOIN_P2PKH_VERSION).is_err());
+        assert!(base58_prefix_ranges("1O", BITCOIN_P2PKH_VERSION).is_err());
+        assert!(base58_prefix_ranges("", BITCOIN_P2PKH_VERSION).is_err());
+    }
+}
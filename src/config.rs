@@ -1,5 +1,10 @@
 //! 搜索配置和数据结构定义
 
+use secp256k1::{PublicKey, SECP256K1, SecretKey};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::mnemonic::entropy_len_for_words;
+
 /// 模式匹配配置 (用于 profanity 风格的模式匹配)
 /// 支持类似 0xXXXXXXXXXXXXabcdXXXXXXXXXXXXXXXXXXXXXXXX 的格式
 #[repr(C)]
@@ -11,24 +16,198 @@ pub struct PatternConfig {
     /// 期望值数组 (20字节) - 对应 OpenCL uchar[20]
     /// 需要匹配的具体值
     pub value: [u8; 20],
+    /// 每个半字节的字符类约束 (40字节) - 对应 OpenCL uchar[40]
+    /// 取值见 `NIBBLE_LETTER` / `NIBBLE_DIGIT` / `NIBBLE_BACKREF`，0 表示不约束。
+    /// 仅当条件类型为 CharClass 时内核才检查
+    pub classes: [u8; 40],
 }
 
+/// 字符类: 该位置必须是字母 a-f
+pub const NIBBLE_LETTER: u8 = 0x01;
+/// 字符类: 该位置必须是数字 0-9
+pub const NIBBLE_DIGIT: u8 = 0x02;
+/// 反向引用: `NIBBLE_BACKREF | n` 表示该位置必须与第 n 个半字节相同
+pub const NIBBLE_BACKREF: u8 = 0x80;
+
 impl Default for PatternConfig {
     fn default() -> Self {
         Self {
-            mask: [0u8; 20],
-            value: [0u8; 20],
+            mask: [0; 20],
+            value: [0; 20],
+            classes: [0; 40],
         }
     }
 }
 
+impl PatternConfig {
+    /// 由原始字节掩码和期望值创建 (位级精度)
+    ///
+    /// 内核按字节比较 `address & mask == value`，因此 value 中不能有掩码之外的位。
+    pub fn from_mask_value(mask: [u8; 20], value: [u8; 20]) -> anyhow::Result<Self> {
+        if mask.iter().zip(value.iter()).any(|(m, v)| v & !m != 0) {
+            anyhow::bail!("Pattern value has bits set outside of the mask");
+        }
+        Ok(Self {
+            mask,
+            value,
+            ..Self::default()
+        })
+    }
+
+    /// 全部 40 个字符都是字母 a-f (profanity --letters)
+    pub fn all_letters() -> Self {
+        Self {
+            classes: [NIBBLE_LETTER; 40],
+            ..Self::default()
+        }
+    }
+
+    /// 全部 40 个字符都是数字 0-9 (profanity --numbers)
+    pub fn all_digits() -> Self {
+        Self {
+            classes: [NIBBLE_DIGIT; 40],
+            ..Self::default()
+        }
+    }
+
+    /// 前 count 个字节的两个字符相同，如 0x00aa11... (profanity --leading-doubles)
+    pub fn leading_doubles(count: u32) -> anyhow::Result<Self> {
+        if count == 0 || count > 20 {
+            anyhow::bail!("Leading doubles must be between 1 and 20, got {}", count);
+        }
+        let mut pattern = Self::default();
+        for k in 0..count as usize {
+            pattern.classes[2 * k + 1] = NIBBLE_BACKREF | (2 * k) as u8;
+        }
+        Ok(pattern)
+    }
+
+    /// 首尾 count 个字符互为镜像，即第 i 个字符等于倒数第 i 个字符 (profanity --mirror)
+    pub fn mirror(count: u32) -> anyhow::Result<Self> {
+        if count == 0 || count > 20 {
+            anyhow::bail!("Mirror length must be between 1 and 20, got {}", count);
+        }
+        let mut pattern = Self::default();
+        for i in 0..count as usize {
+            pattern.classes[39 - i] = NIBBLE_BACKREF | i as u8;
+        }
+        Ok(pattern)
+    }
+
+    /// 子串匹配的单词 (半字节序列，每项 0-15)，按顺序从高位打包进 value
+    ///
+    /// mask 保持为 0: Contains 条件只使用 value 中的前 n 个半字节 (n 由条件参数给出)，
+    /// 单词可以出现在地址的任意位置。
+    pub fn contains(word: &[u8]) -> anyhow::Result<Self> {
+        if word.is_empty() || word.len() > 40 {
+            anyhow::bail!(
+                "Word must be between 1 and 40 hex characters, got {}",
+                word.len()
+            );
+        }
+        let mut pattern = Self::default();
+        for (i, nibble) in word.iter().enumerate() {
+            if *nibble > 0x0F {
+                anyhow::bail!("Invalid nibble 0x{:x} in word", nibble);
+            }
+            pattern.value[i / 2] |= if i % 2 == 0 { nibble << 4 } else { *nibble };
+        }
+        Ok(pattern)
+    }
+
+    /// 数值范围 [low, high) (地址按 160 位大端整数比较)，high 为 None 表示没有上界
+    ///
+    /// 返回 Range 条件编码和对应的配置: 下界存放在 mask，上界存放在 value。
+    pub fn range(low: [u8; 20], high: Option<[u8; 20]>) -> anyhow::Result<(u64, Self)> {
+        if let Some(high) = high
+            && low >= high
+        {
+            anyhow::bail!(
+                "Range is empty: low 0x{} must be below high 0x{}",
+                hex::encode(low),
+                hex::encode(high)
+            );
+        }
+        let pattern = Self {
+            mask: low,
+            value: high.unwrap_or_default(),
+            ..Self::default()
+        };
+        Ok((
+            ConditionType::Range.encode(u64::from(high.is_none())),
+            pattern,
+        ))
+    }
+
+    /// 是否带有字符类约束 (需要使用 CharClass 条件)
+    pub fn has_classes(&self) -> bool {
+        self.classes.iter().any(|c| *c != 0)
+    }
+
+    /// 主机端检查字符类约束，与内核 compare_nibble_classes 一致 (仅供测试)
+    #[cfg(test)]
+    pub(crate) fn matches_classes(&self, address: &[u8; 20]) -> bool {
+        let nibble = |i: usize| {
+            if i.is_multiple_of(2) {
+                address[i / 2] >> 4
+            } else {
+                address[i / 2] & 0x0F
+            }
+        };
+        self.classes
+            .iter()
+            .enumerate()
+            .all(|(i, class)| match *class {
+                0 => true,
+                NIBBLE_LETTER => nibble(i) >= 10,
+                NIBBLE_DIGIT => nibble(i) <= 9,
+                c if c & NIBBLE_BACKREF != 0 && ((c & 0x7F) as usize) < 40 => {
+                    nibble(i) == nibble((c & 0x7F) as usize)
+                }
+                _ => false,
+            })
+    }
+
+    /// 约束地址最低 bit_count 位 (大端序末尾) 等于 value 的对应位
+    ///
+    /// 可与已有掩码叠加，例如 Uniswap v4 hook 地址的低 14 位权限标志。
+    pub fn with_low_bits(mut self, bit_count: u32, value: u64) -> anyhow::Result<Self> {
+        if bit_count > 64 {
+            anyhow::bail!(
+                "Low bit constraint supports at most 64 bits, got {}",
+                bit_count
+            );
+        }
+        if bit_count < 64 && value >> bit_count != 0 {
+            anyhow::bail!("Value 0x{:x} does not fit in {} bits", value, bit_count);
+        }
+
+        for bit in 0..bit_count {
+            let byte_idx = 19 - (bit / 8) as usize;
+            let bit_mask = 1u8 << (bit % 8);
+            self.mask[byte_idx] |= bit_mask;
+            if (value >> bit) & 1 == 1 {
+                self.value[byte_idx] |= bit_mask;
+            } else {
+                self.value[byte_idx] &= !bit_mask;
+            }
+        }
+        Ok(self)
+    }
+}
+
 /// 搜索任务配置 (传递给 GPU)
 ///
 /// 注意：必须与 OpenCL 的 search_config_t 结构体完全匹配
 /// OpenCL 布局: base_seed[32] @0, num_threads @32, source_mode @36, target_chain @40,
-///              _padding1[4] @44, condition @48, check_interval @56, _padding2[4] @60,
-///              pattern_mask[20] @64, pattern_value[20] @84
-/// 总大小: 104 bytes
+///              target_count @44, check_interval @48, batch_size @52, step_point[64] @56,
+///              public_key[64] @120, address_type @184, deployer[20] @188,
+///              init_code_hash[32] @208, max_results @240, score_mode @244,
+///              passphrase_len @248, passphrase[116] @252, derivation_path_len @368,
+///              derivation_path[10] @372, address_count @412, entropy_len @416
+/// 总大小: 424 bytes (OpenCL 端 420 bytes，末尾为 8 字节对齐填充)
+///
+/// 搜索条件不在此结构体中，而是以 `SearchTarget` 目标表的形式单独上传
 ///
 /// 使用 `#[repr(C, align(8))]` 确保 8 字节对齐，与 OpenCL 端保持一致
 #[repr(C, align(8))]
@@ -43,73 +222,571 @@ pub struct SearchConfig {
     pub source_mode: u32,
     /// 目标链类型 - 对应 OpenCL uint
     pub target_chain: u32,
-    /// 填充以对齐 condition 到 8 字节边界 - 对应 OpenCL _padding1[4]
-    /// 使用 explicit padding 字段确保内存布局正确
-    pub _padding1: [u8; 4],
-    /// 搜索条件编码 - 对应 OpenCL ulong
-    /// 高16位: 条件类型, 低48位: 条件参数
-    pub condition: u64,
+    /// 目标表中的条件数量 - 对应 OpenCL uint
+    pub target_count: u32,
     /// 检查标志间隔 (迭代次数) - 对应 OpenCL uint
     pub check_interval: u32,
-    /// 填充以对齐 pattern_config - 对应 OpenCL _padding2[4]
-    pub _padding2: [u8; 4],
-    /// 模式匹配配置 - 用于 profanity 风格的模式匹配
-    /// 当 condition 类型为 Pattern 时使用
-    pub pattern_config: PatternConfig,
+    /// 私钥模式每批点数 (共享一次模逆) - 对应 OpenCL uint
+    /// 取值范围 1..=MAX_BATCH_SIZE，请通过 `with_batch_size` 设置以同步更新 step_point
+    pub batch_size: u32,
+    /// 私钥模式的步进公钥 num_threads * batch_size * G (x||y, 大端序) - 对应 OpenCL uchar[64]
+    /// 内核每处理完一批候选私钥就把整批公钥加上该点，避免重复标量乘法
+    pub step_point: [u8; 64],
+    /// 分离密钥模式的用户公钥 P (x||y, 大端序) - 对应 OpenCL uchar[64]
+    /// 内核搜索偏移量 k 使 P + k*G 的地址满足条件，其他模式下全零
+    pub public_key: [u8; 64],
+    /// 匹配的地址类型 - 对应 OpenCL uint
+    /// 0: 外部账户地址, 1: 该账户以 nonce 0 部署的 CREATE 合约地址
+    pub address_type: u32,
+    /// 固定部署者 / CREATE2 模式的部署者地址 - 对应 OpenCL uchar[20]
+    /// 固定部署者模式下种子的低 8 字节作为 nonce 遍历
+    pub deployer: [u8; 20],
+    /// CREATE2 模式的 keccak256(init_code) - 对应 OpenCL uchar[32]
+    pub init_code_hash: [u8; 32],
+    /// 收集到多少个结果后停止 - 对应 OpenCL uint
+    /// 1: 找到第一个即停止, 0: 不限数量 (持续搜索直到超时)
+    pub max_results: u32,
+    /// 评分模式 - 对应 OpenCL uint
+    /// 非 0 时内核持续搜索，只输出刷新最高分的地址，忽略 max_results
+    pub score_mode: u32,
+    /// BIP39 密码字节数 - 对应 OpenCL uint，0 表示无密码
+    pub passphrase_len: u32,
+    /// 助记词模式的 BIP39 密码 (NFKD 规范化后的 UTF-8) - 对应 OpenCL uchar[116]
+    /// 请通过 `with_passphrase` 设置
+    pub passphrase: [u8; MAX_PASSPHRASE_BYTES],
+    /// BIP32 派生路径层数 - 对应 OpenCL uint
+    pub derivation_path_len: u32,
+    /// 助记词模式的 BIP32 派生路径 (硬化索引带 `HARDENED` 标志) - 对应 OpenCL uint[10]
+    /// 请通过 `with_derivation_path` 设置
+    pub derivation_path: [u32; MAX_DERIVATION_DEPTH],
+    /// 助记词模式每个助记词检查的地址索引个数 - 对应 OpenCL uint
+    /// 派生路径最后一层依次取 i..i+n，PBKDF2 和前几层派生只做一次
+    pub address_count: u32,
+    /// 助记词模式的熵字节数 (16/20/24/28/32，对应 12-24 个单词) - 对应 OpenCL uint
+    /// 熵位于 base_seed 的末尾 entropy_len 字节，其余字节为零；请通过 `with_word_count` 设置
+    pub entropy_len: u32,
 }
 
 impl SearchConfig {
-    pub fn new(base_seed: [u8; 32], num_threads: u32, condition: u64) -> Self {
+    pub fn new(base_seed: [u8; 32], num_threads: u32) -> Self {
         Self {
             base_seed,
             num_threads,
             source_mode: SourceMode::MnemonicEntropy as u32,
             target_chain: TargetChain::Ethereum as u32,
-            _padding1: [0; 4],
-            condition,
+            target_count: 0,
             check_interval: 2048, // 每2048次迭代检查一次，降低原子写入频率
-            _padding2: [0; 4],
-            pattern_config: PatternConfig::default(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            step_point: private_key_step_point(num_threads as u64 * DEFAULT_BATCH_SIZE as u64),
+            public_key: [0; 64],
+            address_type: AddressType::Eoa as u32,
+            deployer: [0; 20],
+            init_code_hash: [0; 32],
+            max_results: 1,
+            score_mode: ScoreMode::None as u32,
+            passphrase_len: 0,
+            passphrase: [0; MAX_PASSPHRASE_BYTES],
+            derivation_path_len: 5,
+            derivation_path: [44 | HARDENED, 60 | HARDENED, HARDENED, 0, 0, 0, 0, 0, 0, 0],
+            address_count: 1,
+            entropy_len: 32,
+        }
+    }
+
+    pub fn with_source_mode(mut self, source_mode: SourceMode) -> Self {
+        self.source_mode = source_mode as u32;
+        self
+    }
+
+    pub fn with_target_chain(mut self, target_chain: TargetChain) -> Self {
+        self.target_chain = target_chain as u32;
+        self
+    }
+
+    /// 设置目标表中的条件数量 (需与上传的 SearchTarget 数量一致)
+    pub fn with_target_count(mut self, target_count: u32) -> Self {
+        self.target_count = target_count;
+        self
+    }
+
+    /// 设置私钥模式的批大小 K (自动限制到 1..=MAX_BATCH_SIZE)，并重新计算步进点
+    pub fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = batch_size.clamp(1, MAX_BATCH_SIZE);
+        self.step_point = private_key_step_point(self.num_threads as u64 * self.batch_size as u64);
+        self
+    }
+
+    /// 设置分离密钥模式的用户公钥 P (x||y, 大端序)
+    pub fn with_public_key(mut self, public_key: [u8; 64]) -> Self {
+        self.public_key = public_key;
+        self
+    }
+
+    /// 设置匹配的地址类型: 外部账户地址或 CREATE 合约地址
+    pub fn with_address_type(mut self, address_type: AddressType) -> Self {
+        self.address_type = address_type as u32;
+        self
+    }
+
+    /// 设置固定部署者 / CREATE2 模式的部署者地址
+    pub fn with_deployer(mut self, deployer: [u8; 20]) -> Self {
+        self.deployer = deployer;
+        self
+    }
+
+    /// 设置 CREATE2 模式的 init code 哈希
+    pub fn with_init_code_hash(mut self, init_code_hash: [u8; 32]) -> Self {
+        self.init_code_hash = init_code_hash;
+        self
+    }
+
+    /// 设置收集结果的数量上限 (0 表示不限)
+    pub fn with_max_results(mut self, max_results: u32) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    pub fn with_score_mode(mut self, score_mode: ScoreMode) -> Self {
+        self.score_mode = score_mode as u32;
+        self
+    }
+
+    /// 设置助记词模式的 BIP39 密码 (按 BIP39 要求先做 NFKD 规范化)
+    ///
+    /// # Errors
+    /// 规范化后超过 `MAX_PASSPHRASE_BYTES` 字节时返回错误
+    pub fn with_passphrase(mut self, passphrase: &str) -> anyhow::Result<Self> {
+        let normalized: String = passphrase.nfkd().collect();
+        let bytes = normalized.as_bytes();
+        if bytes.len() > MAX_PASSPHRASE_BYTES {
+            anyhow::bail!(
+                "Passphrase must be at most {} bytes after NFKD normalization, got {}",
+                MAX_PASSPHRASE_BYTES,
+                bytes.len()
+            );
+        }
+        self.passphrase = [0; MAX_PASSPHRASE_BYTES];
+        self.passphrase[..bytes.len()].copy_from_slice(bytes);
+        self.passphrase_len = bytes.len() as u32;
+        Ok(self)
+    }
+
+    /// 设置助记词模式的 BIP32 派生路径 (见 `parse_derivation_path`)
+    ///
+    /// # Errors
+    /// 超过 `MAX_DERIVATION_DEPTH` 层时返回错误
+    pub fn with_derivation_path(mut self, path: &[u32]) -> anyhow::Result<Self> {
+        if path.len() > MAX_DERIVATION_DEPTH {
+            anyhow::bail!(
+                "Derivation path must have at most {} levels, got {}",
+                MAX_DERIVATION_DEPTH,
+                path.len()
+            );
         }
+        self.derivation_path = [0; MAX_DERIVATION_DEPTH];
+        self.derivation_path[..path.len()].copy_from_slice(path);
+        self.derivation_path_len = path.len() as u32;
+        Ok(self)
+    }
+
+    /// 设置助记词模式的单词数 (12/15/18/21/24)
+    ///
+    /// # Errors
+    /// 单词数不受支持时返回错误
+    pub fn with_word_count(mut self, word_count: usize) -> anyhow::Result<Self> {
+        self.entropy_len = entropy_len_for_words(word_count)? as u32;
+        Ok(self)
+    }
+
+    /// 设置助记词模式每个助记词检查的地址索引个数 (至少为 1)
+    pub fn with_address_count(mut self, address_count: u32) -> Self {
+        self.address_count = address_count.max(1);
+        self
+    }
+}
+
+/// 搜索目标 (目标表中的一项，传递给 GPU)
+///
+/// 注意：必须与 OpenCL 的 search_target_t 结构体完全匹配
+/// OpenCL 布局: condition @0, pattern_mask[20] @8, pattern_value[20] @28, pattern_class[40] @48,
+///              case_mask[20] @88, case_value[20] @108, case_sensitive @128, root @132
+/// 总大小: 136 bytes
+///
+/// 目标表按后缀表达式求值: 普通条件压入匹配结果，And/Or/Not 条件弹出操作数并压入运算结果，
+/// root 非 0 的项结束一个顶层条件，其结果为真即命中，target_index 为顶层条件的序号。
+#[repr(C, align(8))]
+#[derive(Debug, Clone, Copy)]
+pub struct SearchTarget {
+    /// 搜索条件编码 - 对应 OpenCL ulong
+    /// 高16位: 条件类型, 低48位: 条件参数
+    pub condition: u64,
+    /// 模式匹配配置 - 用于 profanity 风格的模式匹配
+    /// 当 condition 类型为 Pattern / CharClass 时使用
+    pub pattern_config: PatternConfig,
+    /// EIP-55 大小写约束掩码 (作用于校验哈希 keccak256(小写十六进制地址)) - 对应 OpenCL uchar[20]
+    pub case_mask: [u8; 20],
+    /// EIP-55 大小写约束期望值 - 对应 OpenCL uchar[20]
+    pub case_value: [u8; 20],
+    /// 是否启用大小写匹配 (0/1) - 对应 OpenCL uint
+    pub case_sensitive: u32,
+    /// 是否为顶层条件的最后一项 (0/1) - 对应 OpenCL uint
+    /// 单独的条件默认为 1，组合条件只有最后一个运算符为 1
+    pub root: u32,
+}
+
+impl SearchTarget {
+    pub fn new(condition: u64) -> Self {
+        Self::new_with_pattern(condition, PatternConfig::default())
     }
 
-    /// 创建带模式匹配的配置
-    pub fn new_with_pattern(
-        base_seed: [u8; 32],
-        num_threads: u32,
-        condition: u64,
-        pattern_config: PatternConfig,
-    ) -> Self {
+    /// 创建带模式匹配的目标
+    pub fn new_with_pattern(condition: u64, pattern_config: PatternConfig) -> Self {
         Self {
-            base_seed,
-            num_threads,
-            source_mode: SourceMode::MnemonicEntropy as u32,
-            target_chain: TargetChain::Ethereum as u32,
-            _padding1: [0; 4],
             condition,
-            check_interval: 2048,
-            _padding2: [0; 4],
             pattern_config,
+            case_mask: [0; 20],
+            case_value: [0; 20],
+            case_sensitive: 0,
+            root: 1,
         }
     }
 
-    pub fn with_source_mode(mut self, source_mode: SourceMode) -> Self {
-        self.source_mode = source_mode as u32;
-        self
+    /// 组合条件的运算符项: And/Or 弹出 operands 个结果，Not 对栈顶取反
+    pub fn operator(op: ConditionType, operands: u32) -> anyhow::Result<Self> {
+        match op {
+            ConditionType::And | ConditionType::Or => {
+                if operands == 0 || operands > MAX_OPERANDS {
+                    anyhow::bail!(
+                        "Operator must have between 1 and {} operands, got {}",
+                        MAX_OPERANDS,
+                        operands
+                    );
+                }
+                Ok(Self::new(op.encode(operands as u64)))
+            }
+            ConditionType::Not => Ok(Self::new(op.encode(0))),
+            _ => anyhow::bail!("{:?} is not a logical operator", op),
+        }
     }
 
-    pub fn with_target_chain(mut self, target_chain: TargetChain) -> Self {
-        self.target_chain = target_chain as u32;
+    /// 启用 EIP-55 大小写匹配，case_pattern 由 `parse_checksum_case` 生成
+    pub fn with_case_pattern(mut self, case_pattern: PatternConfig) -> Self {
+        self.case_mask = case_pattern.mask;
+        self.case_value = case_pattern.value;
+        self.case_sensitive = 1;
         self
     }
 }
 
-/// 搜索结果 (从 GPU 传回)
+/// 目标表最多容纳的条件数量 (目标表位于 __constant 内存)
+pub const MAX_TARGETS: usize = 128;
+
+/// 组合条件求值栈的最大深度 - 对应 OpenCL COND_STACK_MAX (栈保存在一个 uint 的各个位中)
+pub const MAX_CONDITION_DEPTH: usize = 32;
+
+/// And/Or 运算符最多的操作数个数
+pub const MAX_OPERANDS: u32 = 31;
+
+/// 结果环形缓冲区的槽位数 - 对应 OpenCL RESULT_RING_CAPACITY
+///
+/// 主机需在内核写满一圈之前取走结果，否则较早的结果会被覆盖。
+pub const RESULT_RING_CAPACITY: usize = 256;
+
+/// 私钥模式默认批大小
+pub const DEFAULT_BATCH_SIZE: u32 = 8;
+
+/// 私钥模式最大批大小 - 对应 OpenCL BATCH_INVERSE_MAX
+pub const MAX_BATCH_SIZE: u32 = 16;
+
+/// BIP39 密码最大字节数 (NFKD 规范化后) - 对应 OpenCL MAX_PASSPHRASE_LEN
+///
+/// 内核 PBKDF2 的 salt 缓冲区为 128 字节，需容纳 "mnemonic" 前缀和 4 字节块序号。
+pub const MAX_PASSPHRASE_BYTES: usize = 116;
+
+/// BIP32 派生路径最大层数 - 对应 OpenCL MAX_DERIVATION_DEPTH
+pub const MAX_DERIVATION_DEPTH: usize = 10;
+
+/// 默认派生路径 (标准以太坊路径，MetaMask 等钱包的第一个账户)
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/60'/0'/0/0";
+
+/// BIP32 硬化派生标志位
+pub const HARDENED: u32 = 0x8000_0000;
+
+/// 计算私钥模式的步进公钥 step * G (step = num_threads * batch_size)
+///
+/// 返回未压缩公钥去掉 0x04 前缀后的 64 字节 (x||y, 大端序)。
+/// step 为 0 时没有合法的步进点，返回全零。
+pub fn private_key_step_point(step: u64) -> [u8; 64] {
+    let mut scalar = [0u8; 32];
+    scalar[24..].copy_from_slice(&step.to_be_bytes());
+
+    let mut step_point = [0u8; 64];
+    if let Ok(secret_key) = SecretKey::from_slice(&scalar) {
+        let public_key = PublicKey::from_secret_key(SECP256K1, &secret_key);
+        step_point.copy_from_slice(&public_key.serialize_uncompressed()[1..]);
+    }
+    step_point
+}
+
+/// 解析 EIP-55 大小写约束
+///
+/// 输入与 `parse_pattern_condition` 相同的 40 字符模式，大写字母 A-F 要求校验和形式为大写，
+/// 小写字母 a-f 要求为小写，数字和通配符不约束大小写。
+/// 返回的掩码作用于校验哈希 keccak256(小写十六进制地址) 的前 20 字节:
+/// 第 i 个字符对应哈希第 i 个半字节的最高位。
+pub fn parse_checksum_case(pattern: &str) -> anyhow::Result<PatternConfig> {
+    let tokens = pattern_tokens(pattern)?;
+
+    let mut case_pattern = PatternConfig::default();
+    for (i, token) in tokens.iter().enumerate() {
+        let bit = if i % 2 == 0 { 0x80 } else { 0x08 };
+        // 字符类 {..} 占一个位置，但不约束大小写
+        match token.chars().next() {
+            Some('A'..='F') if token.len() == 1 => {
+                case_pattern.mask[i / 2] |= bit;
+                case_pattern.value[i / 2] |= bit;
+            }
+            Some('a'..='f') if token.len() == 1 => {
+                case_pattern.mask[i / 2] |= bit;
+            }
+            _ => {}
+        }
+    }
+    Ok(case_pattern)
+}
+
+/// 将模式拆分为 40 个位置: 单个字符，或花括号括起的字符类 (如 `{L}`、`{12}`)
+fn pattern_tokens(pattern: &str) -> anyhow::Result<Vec<&str>> {
+    // 正确处理 0x 前缀，只移除一次前缀而不是所有匹配的字符
+    let hex_str = if pattern.starts_with("0x") || pattern.starts_with("0X") {
+        &pattern[2..]
+    } else {
+        pattern
+    };
+
+    let mut tokens = Vec::with_capacity(40);
+    let mut rest = hex_str;
+    while let Some(c) = rest.chars().next() {
+        let len = if c == '{' {
+            rest.find('}')
+                .map(|end| end + 1)
+                .ok_or_else(|| anyhow::anyhow!("Unclosed '{{' in pattern"))?
+        } else {
+            c.len_utf8()
+        };
+        tokens.push(&rest[..len]);
+        rest = &rest[len..];
+    }
+
+    // 验证长度 (必须是40个位置 = 20字节)
+    if tokens.len() != 40 {
+        anyhow::bail!(
+            "Pattern must be exactly 40 hex characters (20 bytes), got {}",
+            tokens.len()
+        );
+    }
+    Ok(tokens)
+}
+
+/// EIP-55 校验和地址 (0x 开头的大小写混合形式)
+pub fn to_checksum_address(address: &[u8; 20]) -> String {
+    use sha3::{Digest, Keccak256};
+
+    let lower = hex::encode(address);
+    let hash = Keccak256::digest(lower.as_bytes());
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0F
+            };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+/// 计算 CREATE 合约地址: keccak256(rlp([deployer, nonce]))[12:]
+pub fn create_contract_address(deployer: &[u8; 20], nonce: u64) -> [u8; 20] {
+    use sha3::{Digest, Keccak256};
+
+    let mut payload = Vec::with_capacity(30);
+    payload.push(0x80 + 20);
+    payload.extend_from_slice(deployer);
+    match nonce {
+        0 => payload.push(0x80),
+        1..=0x7f => payload.push(nonce as u8),
+        _ => {
+            let bytes = nonce.to_be_bytes();
+            let skip = bytes.iter().take_while(|&&b| b == 0).count();
+            payload.push(0x80 + (8 - skip) as u8);
+            payload.extend_from_slice(&bytes[skip..]);
+        }
+    }
+
+    let mut rlp = Vec::with_capacity(31);
+    rlp.push(0xc0 + payload.len() as u8);
+    rlp.extend_from_slice(&payload);
+    let hash = Keccak256::digest(&rlp);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// 计算 CREATE2 合约地址: keccak256(0xff ++ deployer ++ salt ++ init_code_hash)[12:]
+pub fn create2_address(
+    deployer: &[u8; 20],
+    salt: &[u8; 32],
+    init_code_hash: &[u8; 32],
+) -> [u8; 20] {
+    use sha3::{Digest, Keccak256};
+
+    let mut hasher = Keccak256::new();
+    hasher.update([0xff]);
+    hasher.update(deployer);
+    hasher.update(salt);
+    hasher.update(init_code_hash);
+    let hash = hasher.finalize();
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// 解析 32 字节十六进制值 (如 init code 哈希、salt)，可带 0x 前缀
+pub fn parse_hash32(value: &str) -> anyhow::Result<[u8; 32]> {
+    let hex_str = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+    let bytes = hex::decode(hex_str)?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Value must be exactly 32 bytes (64 hex characters)"))
+}
+
+/// 解析 BIP32 派生路径，如 `m/44'/60'/0'/0/0`
+///
+/// 硬化索引可用 `'` 或 `h` 标记，返回的索引已带 `HARDENED` 标志位。
+pub fn parse_derivation_path(path: &str) -> anyhow::Result<Vec<u32>> {
+    let mut parts = path.trim().split('/');
+    if parts.next() != Some("m") {
+        anyhow::bail!("Derivation path must start with 'm', got '{}'", path);
+    }
+    let indices = parts
+        .map(|part| {
+            let (number, hardened) = match part
+                .strip_suffix('\'')
+                .or_else(|| part.strip_suffix('h'))
+                .or_else(|| part.strip_suffix('H'))
+            {
+                Some(number) => (number, true),
+                None => (part, false),
+            };
+            let index: u32 = number
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid derivation path component '{}'", part))?;
+            if index >= HARDENED {
+                anyhow::bail!("Derivation index {} is out of range", index);
+            }
+            Ok(if hardened { index | HARDENED } else { index })
+        })
+        .collect::<anyhow::Result<Vec<u32>>>()?;
+    if indices.len() > MAX_DERIVATION_DEPTH {
+        anyhow::bail!(
+            "Derivation path must have at most {} levels, got {}",
+            MAX_DERIVATION_DEPTH,
+            indices.len()
+        );
+    }
+    Ok(indices)
+}
+
+/// 将派生路径格式化为 `m/44'/60'/0'/0/0` 形式
+pub fn format_derivation_path(path: &[u32]) -> String {
+    let mut text = String::from("m");
+    for &index in path {
+        if index & HARDENED != 0 {
+            text.push_str(&format!("/{}'", index & !HARDENED));
+        } else {
+            text.push_str(&format!("/{}", index));
+        }
+    }
+    text
+}
+
+/// 解析 20 字节以太坊地址 (十六进制，可带 0x 前缀)
+pub fn parse_eth_address(address: &str) -> anyhow::Result<[u8; 20]> {
+    let hex_str = address
+        .strip_prefix("0x")
+        .or_else(|| address.strip_prefix("0X"))
+        .unwrap_or(address);
+    let bytes = hex::decode(hex_str)?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Address must be exactly 20 bytes (40 hex characters)"))
+}
+
+/// 解析地址范围的边界 (按整数解析的十六进制，可带 0x 前缀和 `_` 分隔符)
+///
+/// 不足 40 位时高位补零，例如 `0x1000` 表示整数 4096；
+/// 要表示 "0x00001000..." 这样的地址前缀边界，需写出完整的 40 位。
+pub fn parse_address_bound(value: &str) -> anyhow::Result<[u8; 20]> {
+    let value = value.trim();
+    let hex_str: String = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value)
+        .chars()
+        .filter(|c| *c != '_')
+        .collect();
+    if hex_str.is_empty() || hex_str.len() > 40 {
+        anyhow::bail!(
+            "Address bound must have between 1 and 40 hex digits, got {}",
+            hex_str.len()
+        );
+    }
+    let bytes = hex::decode(format!("{:0>40}", hex_str))?;
+    let mut bound = [0u8; 20];
+    bound.copy_from_slice(&bytes);
+    Ok(bound)
+}
+
+/// 解析地址范围 `LOW..HIGH` (左闭右开)，任一端可省略
+///
+/// 省略下界表示从 0 开始，省略上界表示直到最大地址 (含)。
+pub fn parse_address_range(range: &str) -> anyhow::Result<([u8; 20], Option<[u8; 20]>)> {
+    let (low, high) = range
+        .split_once("..")
+        .ok_or_else(|| anyhow::anyhow!("Range must be written as LOW..HIGH, got '{}'", range))?;
+    let low = if low.trim().is_empty() {
+        [0u8; 20]
+    } else {
+        parse_address_bound(low)?
+    };
+    let high = if high.trim().is_empty() {
+        None
+    } else {
+        Some(parse_address_bound(high)?)
+    };
+    Ok((low, high))
+}
+
+/// 搜索结果 (从 GPU 传回，结果环形缓冲区中的一个槽位)
 /// 注意：必须与 OpenCL 的 search_result_t 结构体完全匹配
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct SearchResult {
-    /// 是否找到 (0/1) - 对应 OpenCL int
+    /// 结果序号 + 1 (0 表示槽位尚未写入) - 对应 OpenCL int
+    /// 内核最后写入该字段，主机据此判断槽位内容是否完整
     pub found: i32,
     /// 找到的候选密钥材料 (32字节) - 对应 OpenCL uchar[32]
     /// 在不同 source_mode 下，可能表示熵或私钥
@@ -122,19 +799,12 @@ pub struct SearchResult {
     pub total_checked_low: u32,
     /// 总共检查的地址数量 - 高32位 - 对应 OpenCL uint
     pub total_checked_high: u32,
-}
-
-impl Default for SearchResult {
-    fn default() -> Self {
-        Self {
-            found: 0,
-            result_seed: [0u8; 32],
-            eth_address: [0u8; 20],
-            found_by_thread: 0,
-            total_checked_low: 0,
-            total_checked_high: 0,
-        }
-    }
+    /// 命中的目标在目标表中的下标 - 对应 OpenCL uint
+    pub target_index: u32,
+    /// 评分模式下的地址分数 - 对应 OpenCL uint
+    pub score: u32,
+    /// 助记词模式命中的地址索引 (派生路径最后一层，含硬化标志) - 对应 OpenCL uint
+    pub address_index: u32,
 }
 
 /// 搜索来源模式
@@ -144,6 +814,12 @@ pub enum SourceMode {
     MnemonicEntropy = 0,
     /// 直接将 32 字节作为私钥遍历
     PrivateKey = 1,
+    /// 分离密钥: 遍历偏移量 k，地址来自用户公钥 P + k*G，GPU 不接触私钥
+    SplitKey = 2,
+    /// 固定部署者: 遍历 nonce (种子低 8 字节)，匹配 CREATE 合约地址
+    DeployerNonce = 3,
+    /// CREATE2: 遍历 32 字节 salt，匹配 CREATE2 合约地址
+    Create2Salt = 4,
 }
 
 impl SourceMode {
@@ -152,16 +828,96 @@ impl SourceMode {
     }
 }
 
-/// 目标链类型 (预留扩展，比如 Bitcoin)
+/// 匹配的地址类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressType {
+    /// 外部账户地址 keccak256(pubkey)[12:]
+    Eoa = 0,
+    /// 账户以 nonce 0 部署的 CREATE 合约地址
+    Create = 1,
+}
+
+/// 评分模式 (持续搜索并报告越来越好的地址，而不是固定阈值)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreMode {
+    /// 不评分，按条件匹配
+    None = 0,
+    /// 前导零字符数
+    LeadingZeros = 1,
+    /// 地址中全部零字符数
+    ZeroNibbles = 2,
+    /// 与目标模式相同的字符数 (目标表第一项的 mask/value)
+    Matching = 3,
+}
+
+impl ScoreMode {
+    pub fn as_u32(self) -> u32 {
+        self as u32
+    }
+
+    /// 可能达到的最高分，达到后继续搜索没有意义
+    pub fn max_score(self, pattern: &PatternConfig) -> u32 {
+        match self {
+            ScoreMode::None => 0,
+            ScoreMode::LeadingZeros | ScoreMode::ZeroNibbles => 40,
+            ScoreMode::Matching => pattern
+                .mask
+                .iter()
+                .map(|m| u32::from(m & 0xF0 != 0) + u32::from(m & 0x0F != 0))
+                .sum(),
+        }
+    }
+
+    /// 主机端计算地址分数，与内核 score_address 一致 (仅供测试)
+    #[cfg(test)]
+    pub(crate) fn score(self, address: &[u8; 20], pattern: &PatternConfig) -> u32 {
+        let nibbles = address.iter().flat_map(|b| [b >> 4, b & 0x0F]);
+        match self {
+            ScoreMode::None => 0,
+            ScoreMode::LeadingZeros => nibbles.take_while(|n| *n == 0).count() as u32,
+            ScoreMode::ZeroNibbles => nibbles.filter(|n| *n == 0).count() as u32,
+            ScoreMode::Matching => {
+                let masks = pattern.mask.iter().flat_map(|b| [b >> 4, b & 0x0F]);
+                let values = pattern.value.iter().flat_map(|b| [b >> 4, b & 0x0F]);
+                nibbles
+                    .zip(masks.zip(values))
+                    .filter(|(n, (m, v))| *m != 0 && n & m == *v)
+                    .count() as u32
+            }
+        }
+    }
+}
+
+/// 目标链类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TargetChain {
+    /// 以太坊: 匹配 Keccak-256 地址
+    #[default]
     Ethereum = 0,
+    /// 比特币 P2PKH 及其分叉币: 匹配压缩公钥的 HASH160
+    Bitcoin = 1,
+    /// 比特币 SegWit P2WPKH (bech32): 同样匹配压缩公钥的 HASH160
+    BitcoinSegwit = 2,
+    /// 比特币 Taproot P2TR (bech32m): 匹配 BIP-341 调整后输出公钥 x 坐标的前 20 字节
+    BitcoinTaproot = 3,
+    /// 波场: 与以太坊相同的 Keccak-256 地址，渲染为 0x41 版本字节的 Base58Check 地址
+    Tron = 4,
+    /// Nostr: 匹配 x-only 公钥 (x 坐标) 的前 20 字节，渲染为 bech32 npub
+    Nostr = 5,
 }
 
 impl TargetChain {
     pub fn as_u32(self) -> u32 {
         self as u32
     }
+
+    /// 是否为 SegWit / Taproot 地址或 Nostr 公钥等 bech32 编码
+    pub fn is_bech32(self) -> bool {
+        matches!(
+            self,
+            TargetChain::BitcoinSegwit | TargetChain::BitcoinTaproot | TargetChain::Nostr
+        )
+    }
 }
 
 impl SearchResult {
@@ -184,6 +940,21 @@ pub enum ConditionType {
     Leading = 0x04,
     /// 前导零个数 (精确匹配)
     LeadingExact = 0x05,
+    /// 地址任意位置零字节个数 (至少)
+    ZeroBytes = 0x06,
+    /// 模式匹配 + 字符类约束 (字母/数字/与其他位置相同)
+    CharClass = 0x07,
+    /// 单词出现在地址任意位置 (参数为单词的半字节数，单词存放在 pattern value 中)
+    Contains = 0x08,
+    /// 地址按 160 位大端整数落在 [下界, 上界) 内
+    /// 下界存放在 pattern mask，上界存放在 pattern value，参数为 1 表示没有上界
+    Range = 0x09,
+    /// 逻辑与: 弹出 n 个结果 (参数为 n)，全部为真时压入真
+    And = 0x10,
+    /// 逻辑或: 弹出 n 个结果 (参数为 n)，任一为真时压入真
+    Or = 0x11,
+    /// 逻辑非: 栈顶结果取反
+    Not = 0x12,
 }
 
 impl ConditionType {
@@ -192,6 +963,87 @@ impl ConditionType {
     pub fn encode(self, param: u64) -> u64 {
         ((self as u64) << 48) | (param & 0xFFFFFFFFFFFF)
     }
+
+    /// 编码带字节数的前缀/后缀条件 (大端序)
+    /// 格式: [类型:16位][字节数:4位][保留:4位][参数:40位]
+    ///
+    /// 40 位参数最多容纳 5 字节；字节数字段的 0 在内核中表示 6 字节，但参数放不下，因此不支持
+    pub fn encode_bytes(self, bytes: &[u8]) -> anyhow::Result<u64> {
+        if bytes.is_empty() || bytes.len() > MAX_CONDITION_BYTES {
+            anyhow::bail!(
+                "Condition bytes must be between 1 and {}, got {}",
+                MAX_CONDITION_BYTES,
+                bytes.len()
+            );
+        }
+        let param = bytes.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        Ok(((self as u64) << 48) | ((bytes.len() as u64) << 44) | param)
+    }
+}
+
+/// 前缀/后缀专用条件最多容纳的字节数 (40 位参数)
+pub const MAX_CONDITION_BYTES: usize = 5;
+
+/// 主机端条件判断，与内核 check_condition_with_pattern 的逻辑一致 (仅供测试)
+#[cfg(test)]
+pub(crate) fn matches_condition(
+    address: &[u8; 20],
+    condition: u64,
+    pattern: &PatternConfig,
+) -> bool {
+    let cond_type = (condition >> 48) & 0xFFFF;
+    let param = condition & 0xFFFFFFFFFFFF;
+    let param_bytes = match ((condition >> 44) & 0x0F) as usize {
+        0 => 6,
+        n => n,
+    };
+    let value = condition & 0xFFFFFFFFFF;
+    let param_byte = |i: usize| ((value >> (8 * (param_bytes - 1 - i))) & 0xFF) as u8;
+    let leading_zeros = address
+        .iter()
+        .flat_map(|b| [b >> 4, b & 0x0F])
+        .take_while(|n| *n == 0)
+        .count() as u64;
+    let matches_mask = address
+        .iter()
+        .zip(pattern.mask.iter().zip(pattern.value.iter()))
+        .all(|(a, (m, v))| a & m == *v);
+
+    match cond_type {
+        t if t == ConditionType::Pattern as u64 => matches_mask,
+        t if t == ConditionType::CharClass as u64 => {
+            matches_mask && pattern.matches_classes(address)
+        }
+        t if t == ConditionType::Contains as u64 => {
+            let len = param as usize;
+            let word: Vec<u8> = pattern
+                .value
+                .iter()
+                .flat_map(|b| [b >> 4, b & 0x0F])
+                .take(len)
+                .collect();
+            let nibbles: Vec<u8> = address.iter().flat_map(|b| [b >> 4, b & 0x0F]).collect();
+            (1..=40).contains(&len) && nibbles.windows(len).any(|w| w == word)
+        }
+        // 大端字节序的字典序即数值大小
+        t if t == ConditionType::Range as u64 => {
+            *address >= pattern.mask && (param & 1 == 1 || *address < pattern.value)
+        }
+        t if t == ConditionType::Prefix as u64 => {
+            param_bytes <= MAX_CONDITION_BYTES
+                && (0..param_bytes).all(|i| address[i] == param_byte(i))
+        }
+        t if t == ConditionType::Suffix as u64 => {
+            param_bytes <= MAX_CONDITION_BYTES
+                && (0..param_bytes).all(|i| address[20 - param_bytes + i] == param_byte(i))
+        }
+        t if t == ConditionType::Leading as u64 => leading_zeros >= param,
+        t if t == ConditionType::LeadingExact as u64 => leading_zeros == param,
+        t if t == ConditionType::ZeroBytes as u64 => {
+            address.iter().filter(|b| **b == 0).count() as u64 >= param
+        }
+        _ => false,
+    }
 }
 
 /// 解析模式匹配条件
@@ -202,35 +1054,33 @@ impl ConditionType {
 /// - `0xXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXdead` - 后缀匹配
 /// - `0xXXXX1234XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX` - 中间匹配
 ///
+/// 字符类用花括号表示，每个占一个位置 (裸字母 D 本身是十六进制值，因此不能直接作为类名):
+/// - `{L}` - 字母 a-f
+/// - `{D}` - 数字 0-9
+/// - `{n}` - 与第 n 个字符相同 (从 0 开始，不含 0x 前缀)，如 `0xX{0}X{2}...` 表示前两个字节是叠字
+///
+/// 出现字符类时返回 CharClass 条件，否则返回 Pattern 条件。
+///
 /// # Example
 /// ```
 /// use rust_profanity::config::parse_pattern_condition;
 /// let (condition, pattern_config) = parse_pattern_condition("0xXXXXXXXXXXXXdeadXXXXXXXXXXXXXXXXXXXXXXXX").unwrap();
 /// ```
 pub fn parse_pattern_condition(pattern: &str) -> anyhow::Result<(u64, PatternConfig)> {
-    // 正确处理 0x 前缀，只移除一次前缀而不是所有匹配的字符
-    let hex_str = if pattern.starts_with("0x") || pattern.starts_with("0X") {
-        &pattern[2..]
-    } else {
-        pattern
-    };
+    let tokens = pattern_tokens(pattern)?;
+    let mut pattern_config = PatternConfig::default();
 
-    // 验证长度 (必须是40个十六进制字符 = 20字节)
-    if hex_str.len() != 40 {
-        anyhow::bail!(
-            "Pattern must be exactly 40 hex characters (20 bytes), got {}",
-            hex_str.len()
-        );
-    }
-
-    let mut mask = [0u8; 20];
-    let mut value = [0u8; 20];
-
-    // 解析每个字符
-    for (i, c) in hex_str.chars().enumerate() {
+    // 解析每个位置
+    for (i, token) in tokens.iter().enumerate() {
         let byte_idx = i / 2;
         let is_high_nibble = i % 2 == 0;
 
+        if let Some(class) = token.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+            pattern_config.classes[i] = parse_nibble_class(class, i)?;
+            continue;
+        }
+
+        let c = token.chars().next().unwrap_or_default();
         match c {
             'X' | 'x' | '*' | '?' => {
                 // 通配符: 不需要匹配这个半字节
@@ -242,29 +1092,127 @@ pub fn parse_pattern_condition(pattern: &str) -> anyhow::Result<(u64, PatternCon
 
                 if is_high_nibble {
                     // 高半字节 (位7-4)
-                    mask[byte_idx] |= 0xF0;
-                    value[byte_idx] |= nibble << 4;
+                    pattern_config.mask[byte_idx] |= 0xF0;
+                    pattern_config.value[byte_idx] |= nibble << 4;
                 } else {
                     // 低半字节 (位3-0)
-                    mask[byte_idx] |= 0x0F;
-                    value[byte_idx] |= nibble;
+                    pattern_config.mask[byte_idx] |= 0x0F;
+                    pattern_config.value[byte_idx] |= nibble;
                 }
             }
             _ => {
                 anyhow::bail!(
-                    "Invalid character '{}' in pattern. Use hex digits (0-9, a-f) or X/*/? for wildcards",
+                    "Invalid character '{}' in pattern. Use hex digits (0-9, a-f), X/*/? for wildcards or {{L}}/{{D}}/{{n}} for character classes",
                     c
                 );
             }
         }
     }
 
-    let pattern_config = PatternConfig { mask, value };
-    let condition = ConditionType::Pattern.encode(0); // Pattern 类型不需要额外参数
+    let condition = if pattern_config.has_classes() {
+        ConditionType::CharClass.encode(0)
+    } else {
+        ConditionType::Pattern.encode(0) // Pattern 类型不需要额外参数
+    };
 
     Ok((condition, pattern_config))
 }
 
+/// 主机端按后缀表达式求值目标表，与内核 evaluate_targets 一致
+///
+/// 返回第一个命中的顶层条件序号，未命中返回 None (仅供测试)
+#[cfg(test)]
+pub(crate) fn evaluate_targets(address: &[u8; 20], targets: &[SearchTarget]) -> Option<usize> {
+    use sha3::{Digest, Keccak256};
+
+    let checksum_hash = Keccak256::digest(hex::encode(address).as_bytes());
+    let mut stack: Vec<bool> = Vec::with_capacity(MAX_CONDITION_DEPTH);
+    let mut root_index = 0;
+    for target in targets {
+        let cond_type = (target.condition >> 48) & 0xFFFF;
+        let operands = (target.condition & 0xFFFFFFFFFFFF) as usize;
+        if cond_type == ConditionType::And as u64 || cond_type == ConditionType::Or as u64 {
+            let popped = stack.split_off(stack.len().saturating_sub(operands));
+            stack.push(if cond_type == ConditionType::And as u64 {
+                popped.iter().all(|b| *b)
+            } else {
+                popped.iter().any(|b| *b)
+            });
+        } else if cond_type == ConditionType::Not as u64 {
+            if let Some(top) = stack.last_mut() {
+                *top = !*top;
+            }
+        } else {
+            let case_ok = target.case_sensitive == 0
+                || (0..20).all(|i| checksum_hash[i] & target.case_mask[i] == target.case_value[i]);
+            stack.push(
+                case_ok && matches_condition(address, target.condition, &target.pattern_config),
+            );
+        }
+
+        if target.root != 0 {
+            if stack.pop().unwrap_or(false) {
+                return Some(root_index);
+            }
+            root_index += 1;
+        }
+    }
+    None
+}
+
+/// 将单词转换为十六进制半字节序列 (hex-speak)
+///
+/// 十六进制字符原样保留，其余字母按 leetspeak 替换为外形相近的数字:
+/// o→0, i/l→1, z→2, s→5, t→7, g→9，例如 `coffee` → `c0ffee`、`toolset` → `70015e7`。
+/// 不区分大小写，无法转换的字符返回错误。
+pub fn parse_hexspeak(word: &str) -> anyhow::Result<Vec<u8>> {
+    word.chars()
+        .map(|c| {
+            let c = c.to_ascii_lowercase();
+            let hex = match c {
+                'o' => '0',
+                'i' | 'l' => '1',
+                'z' => '2',
+                's' => '5',
+                't' => '7',
+                'g' => '9',
+                _ => c,
+            };
+            hex.to_digit(16).map(|n| n as u8).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Character '{}' in word '{}' has no hex-speak equivalent",
+                    c,
+                    word
+                )
+            })
+        })
+        .collect()
+}
+
+/// 解析花括号内的字符类: L (字母)、D (数字) 或十进制位置 (反向引用)
+fn parse_nibble_class(class: &str, position: usize) -> anyhow::Result<u8> {
+    match class {
+        "L" | "l" => Ok(NIBBLE_LETTER),
+        "D" | "d" => Ok(NIBBLE_DIGIT),
+        _ => {
+            let index: usize = class.parse().map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid character class '{{{}}}' in pattern. Use {{L}}, {{D}} or {{n}}",
+                    class
+                )
+            })?;
+            if index >= 40 || index == position {
+                anyhow::bail!(
+                    "Back reference {{{}}} at position {} must point to another position in 0..40",
+                    index,
+                    position
+                );
+            }
+            Ok(NIBBLE_BACKREF | index as u8)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,6 +1224,56 @@ mod tests {
         assert_eq!(condition & 0xFFFFFFFFFFFF, 0x8888);
     }
 
+    #[test]
+    fn test_encode_bytes() {
+        let condition = ConditionType::Prefix
+            .encode_bytes(&[0x12, 0x34, 0x56])
+            .unwrap();
+        assert_eq!(condition >> 48, 0x01);
+        assert_eq!((condition >> 44) & 0x0F, 3);
+        assert_eq!(condition & 0xFFFFFFFFFF, 0x123456);
+        assert!(ConditionType::Suffix.encode_bytes(&[0xAB; 5]).is_ok());
+        assert!(ConditionType::Suffix.encode_bytes(&[0xAB; 6]).is_err());
+        assert!(ConditionType::Prefix.encode_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn test_matches_condition_specialized_vs_pattern() {
+        let mut address = [0x5Au8; 20];
+        address[..3].copy_from_slice(&[0x12, 0x34, 0x56]);
+        address[17..].copy_from_slice(&[0xDE, 0xAD, 0x01]);
+
+        let prefix = ConditionType::Prefix
+            .encode_bytes(&[0x12, 0x34, 0x56])
+            .unwrap();
+        let (pattern_cond, pattern) =
+            parse_pattern_condition("0x123456XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX").unwrap();
+        let none = PatternConfig::default();
+        assert!(matches_condition(&address, prefix, &none));
+        assert!(matches_condition(&address, pattern_cond, &pattern));
+        address[2] = 0x57;
+        assert!(!matches_condition(&address, prefix, &none));
+        assert!(!matches_condition(&address, pattern_cond, &pattern));
+
+        let suffix = ConditionType::Suffix
+            .encode_bytes(&[0xDE, 0xAD, 0x01])
+            .unwrap();
+        assert!(matches_condition(&address, suffix, &none));
+        address[19] = 0x02;
+        assert!(!matches_condition(&address, suffix, &none));
+
+        // 恰好 3 个前导零字符: 0x000f... 满足, 0x0000... 不满足
+        let exact = ConditionType::LeadingExact.encode(3);
+        let at_least = ConditionType::Leading.encode(3);
+        address[0] = 0x00;
+        address[1] = 0x0F;
+        assert!(matches_condition(&address, exact, &none));
+        assert!(matches_condition(&address, at_least, &none));
+        address[1] = 0x00;
+        assert!(!matches_condition(&address, exact, &none));
+        assert!(matches_condition(&address, at_least, &none));
+    }
+
     #[test]
     fn test_parse_prefix() {
         let (condition, _pattern) =
@@ -286,15 +1284,193 @@ mod tests {
     #[test]
     fn test_struct_sizes() {
         // 验证结构体大小与 OpenCL 端匹配
-        // OpenCL: typedef struct { uchar[32]; uint; uint; uint; uchar[4]; ulong; uint; uchar[4]; uchar[20]; uchar[20]; }
+        // OpenCL: typedef struct { uchar[32]; uint; uint; uint; uint; uint; uint; uchar[64]; uchar[64]; uint; uchar[20]; uchar[32]; uint; uint; uint; uchar[116]; uint; uint[10]; uint; uint; }
         let config_size = std::mem::size_of::<SearchConfig>();
         println!("SearchConfig size: {}", config_size);
-        assert!(config_size >= 104, "SearchConfig too small");
+        assert_eq!(config_size, 424, "SearchConfig size mismatch");
+        assert_eq!(std::mem::offset_of!(SearchConfig, target_count), 44);
+        assert_eq!(std::mem::offset_of!(SearchConfig, batch_size), 52);
+        assert_eq!(std::mem::offset_of!(SearchConfig, step_point), 56);
+        assert_eq!(std::mem::offset_of!(SearchConfig, public_key), 120);
+        assert_eq!(std::mem::offset_of!(SearchConfig, address_type), 184);
+        assert_eq!(std::mem::offset_of!(SearchConfig, deployer), 188);
+        assert_eq!(std::mem::offset_of!(SearchConfig, init_code_hash), 208);
+        assert_eq!(std::mem::offset_of!(SearchConfig, max_results), 240);
+        assert_eq!(std::mem::offset_of!(SearchConfig, score_mode), 244);
+        assert_eq!(std::mem::offset_of!(SearchConfig, passphrase_len), 248);
+        assert_eq!(std::mem::offset_of!(SearchConfig, passphrase), 252);
+        assert_eq!(std::mem::offset_of!(SearchConfig, derivation_path_len), 368);
+        assert_eq!(std::mem::offset_of!(SearchConfig, derivation_path), 372);
+        assert_eq!(std::mem::offset_of!(SearchConfig, address_count), 412);
+        assert_eq!(std::mem::offset_of!(SearchConfig, entropy_len), 416);
+
+        // OpenCL: typedef struct { ulong; uchar[20]; uchar[20]; uchar[40]; uchar[20]; uchar[20]; uint; uchar[4]; }
+        assert_eq!(std::mem::size_of::<PatternConfig>(), 80);
+        assert_eq!(std::mem::size_of::<SearchTarget>(), 136);
+        assert_eq!(std::mem::offset_of!(SearchTarget, pattern_config), 8);
+        assert_eq!(std::mem::offset_of!(SearchTarget, case_mask), 88);
+        assert_eq!(std::mem::offset_of!(SearchTarget, case_value), 108);
+        assert_eq!(std::mem::offset_of!(SearchTarget, case_sensitive), 128);
+        assert_eq!(std::mem::offset_of!(SearchTarget, root), 132);
 
-        // OpenCL: typedef struct { int; uchar[32]; uchar[20]; uint; uint; uint; } = 4 + 32 + 20 + 4 + 4 + 4 = 68 (可能有填充)
+        // OpenCL: typedef struct { int; uchar[32]; uchar[20]; uint; uint; uint; uint; uint; uint; } = 4 + 32 + 20 + 4 * 6 = 80
         let result_size = std::mem::size_of::<SearchResult>();
         println!("SearchResult size: {}", result_size);
-        assert!(result_size >= 68, "SearchResult too small");
+        assert!(result_size >= 80, "SearchResult too small");
+        assert_eq!(std::mem::offset_of!(SearchResult, target_index), 68);
+        assert_eq!(std::mem::offset_of!(SearchResult, score), 72);
+        assert_eq!(std::mem::offset_of!(SearchResult, address_index), 76);
+    }
+
+    #[test]
+    fn test_private_key_step_point() {
+        // 1 * G 应该就是基点 G
+        let step = private_key_step_point(1);
+        assert_eq!(
+            hex::encode(step),
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+             483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        );
+
+        let config = SearchConfig::new([1u8; 32], 1024);
+        assert_eq!(config.batch_size, DEFAULT_BATCH_SIZE);
+        assert_eq!(
+            config.step_point,
+            private_key_step_point(1024 * DEFAULT_BATCH_SIZE as u64)
+        );
+        assert_ne!(config.step_point, step);
+
+        assert_eq!(private_key_step_point(0), [0u8; 64]);
+    }
+
+    #[test]
+    fn test_with_batch_size() {
+        let config = SearchConfig::new([1u8; 32], 1024).with_batch_size(4);
+        assert_eq!(config.batch_size, 4);
+        assert_eq!(config.step_point, private_key_step_point(4096));
+
+        // 超出范围的批大小被限制到 1..=MAX_BATCH_SIZE
+        let config = SearchConfig::new([1u8; 32], 1024).with_batch_size(0);
+        assert_eq!(config.batch_size, 1);
+        assert_eq!(config.step_point, private_key_step_point(1024));
+        let config = SearchConfig::new([1u8; 32], 1024).with_batch_size(1000);
+        assert_eq!(config.batch_size, MAX_BATCH_SIZE);
+    }
+
+    #[test]
+    fn test_create_contract_address() {
+        let deployer = parse_eth_address("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0").unwrap();
+        assert_eq!(
+            hex::encode(create_contract_address(&deployer, 0)),
+            "cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"
+        );
+        assert_eq!(
+            hex::encode(create_contract_address(&deployer, 1)),
+            "343c43a37d37dff08ae8c4a11544c718abb4fcf8"
+        );
+        assert_eq!(
+            hex::encode(create_contract_address(&deployer, 2)),
+            "f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"
+        );
+        // 多字节 nonce 与 OpenCL derive_create_address 的结果一致
+        assert_eq!(
+            hex::encode(create_contract_address(&deployer, 0x80)),
+            "08e190dcb7b73f5fcdabb43e102215c83659a76d"
+        );
+        assert_eq!(
+            hex::encode(create_contract_address(&deployer, u64::MAX)),
+            "9bc924993b60399df164c3763a964301d3db95ca"
+        );
+
+        assert!(parse_eth_address("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_create2_address() {
+        // EIP-1014 示例: init_code = 0x00
+        let init_code_hash =
+            parse_hash32("0xbc36789e7a1e281436464229828f817d6612f7b477d66591ff96a9e064bcc98a")
+                .unwrap();
+        let salt = [0u8; 32];
+
+        assert_eq!(
+            hex::encode(create2_address(&[0u8; 20], &salt, &init_code_hash)),
+            "4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"
+        );
+        let deployer = parse_eth_address("0xdeadbeef00000000000000000000000000000000").unwrap();
+        assert_eq!(
+            hex::encode(create2_address(&deployer, &salt, &init_code_hash)),
+            "b928f69bb1d91cd65274e3c79d8986362984fda3"
+        );
+
+        assert!(parse_hash32("0x00").is_err());
+    }
+
+    #[test]
+    fn test_pattern_config_raw_mask() {
+        let mut mask = [0u8; 20];
+        let mut value = [0u8; 20];
+        mask[0] = 0x80;
+        value[0] = 0x80;
+        let pattern = PatternConfig::from_mask_value(mask, value).unwrap();
+        assert_eq!(pattern.mask[0], 0x80);
+
+        // value 的位超出 mask 时永远无法匹配，应拒绝
+        value[1] = 0x01;
+        assert!(PatternConfig::from_mask_value(mask, value).is_err());
+
+        // 低 14 位: 0x2081 -> 字节 18 = 0x20 (掩码 0x3F)，字节 19 = 0x81 (掩码 0xFF)
+        let pattern = PatternConfig::default().with_low_bits(14, 0x2081).unwrap();
+        assert_eq!(pattern.mask[18], 0x3F);
+        assert_eq!(pattern.mask[19], 0xFF);
+        assert_eq!(pattern.value[18], 0x20);
+        assert_eq!(pattern.value[19], 0x81);
+        assert!(pattern.mask[..18].iter().all(|&m| m == 0));
+
+        assert!(PatternConfig::default().with_low_bits(14, 0x4000).is_err());
+    }
+
+    #[test]
+    fn test_checksum_address() {
+        // EIP-55 规范中的示例
+        for expected in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let address = parse_eth_address(expected).unwrap();
+            assert_eq!(to_checksum_address(&address), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_checksum_case() {
+        let address = parse_eth_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        let hash = {
+            use sha3::{Digest, Keccak256};
+            Keccak256::digest(hex::encode(address).as_bytes())
+        };
+        let matches = |case_pattern: &PatternConfig| {
+            (0..20).all(|i| hash[i] & case_pattern.mask[i] == case_pattern.value[i])
+        };
+
+        // 与校验和形式一致的大小写满足，翻转任一字母的大小写则不满足
+        let case_pattern =
+            parse_checksum_case("0x5aAeXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX").unwrap();
+        assert_eq!(case_pattern.mask[0], 0x08);
+        assert_eq!(case_pattern.mask[1], 0x88);
+        assert_eq!(case_pattern.value[1], 0x80);
+        assert!(matches(&case_pattern));
+        assert!(!matches(
+            &parse_checksum_case("0x5AAeXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX").unwrap()
+        ));
+        let suffix = format!("0x{}BeAed", "X".repeat(35));
+        assert!(matches(&parse_checksum_case(&suffix).unwrap()));
+
+        // 纯数字不约束大小写
+        let digits = parse_checksum_case("0x1234XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX").unwrap();
+        assert_eq!(digits.mask, [0u8; 20]);
     }
 
     #[test]
@@ -306,6 +1482,9 @@ mod tests {
             found_by_thread: 0,
             total_checked_low: 0x12345678,
             total_checked_high: 0x9ABCDEF0,
+            target_index: 0,
+            score: 0,
+            address_index: 0,
         };
         assert_eq!(result.total_checked(), 0x9ABCDEF012345678);
     }
@@ -428,4 +1607,243 @@ mod tests {
         assert_eq!(pattern_config.mask[3], 0xFF);
         assert_eq!(pattern_config.value[3], 0x34);
     }
+
+    #[test]
+    fn test_score_mode() {
+        let mut address = [0xFFu8; 20];
+        address[0] = 0x00;
+        address[1] = 0xAA;
+        address[10] = 0xF0;
+        let none = PatternConfig::default();
+        assert_eq!(ScoreMode::LeadingZeros.score(&address, &none), 2);
+        assert_eq!(ScoreMode::ZeroNibbles.score(&address, &none), 3);
+        assert_eq!(ScoreMode::LeadingZeros.max_score(&none), 40);
+
+        // 目标 0x00ab...: 地址 0x00aa... 前三个字符相同，第四个 a != b
+        let (_, target) =
+            parse_pattern_condition("0x00abXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX").unwrap();
+        assert_eq!(ScoreMode::Matching.max_score(&target), 4);
+        assert_eq!(ScoreMode::Matching.score(&address, &target), 3);
+    }
+
+    #[test]
+    fn test_parse_char_classes() {
+        let (condition, pattern) =
+            parse_pattern_condition(&format!("0x{{L}}{{D}}X{{2}}ab{}", "X".repeat(34))).unwrap();
+        assert_eq!(condition >> 48, ConditionType::CharClass as u64);
+        assert_eq!(pattern.classes[0], NIBBLE_LETTER);
+        assert_eq!(pattern.classes[1], NIBBLE_DIGIT);
+        assert_eq!(pattern.classes[2], 0);
+        assert_eq!(pattern.classes[3], NIBBLE_BACKREF | 2);
+        assert_eq!(pattern.mask[2], 0xFF);
+        assert_eq!(pattern.value[2], 0xab);
+
+        // 大小写约束按位置计算，字符类不约束大小写
+        let case = parse_checksum_case(&format!("0x{{L}}{{D}}XXAB{}", "X".repeat(34))).unwrap();
+        assert_eq!(case.mask[0], 0x00);
+        assert_eq!(case.mask[2], 0x88);
+
+        assert!(parse_pattern_condition(&format!("0x{{0}}{}", "X".repeat(39))).is_err());
+        assert!(parse_pattern_condition(&format!("0x{{40}}{}", "X".repeat(39))).is_err());
+        assert!(parse_pattern_condition(&format!("0x{{Q}}{}", "X".repeat(39))).is_err());
+        assert!(parse_pattern_condition(&format!("0x{{L{}", "X".repeat(39))).is_err());
+        assert!(parse_pattern_condition(&format!("0x{{L}}{}", "X".repeat(40))).is_err());
+    }
+
+    #[test]
+    fn test_matches_char_classes() {
+        let condition = ConditionType::CharClass.encode(0);
+        let letters = PatternConfig::all_letters();
+        let digits = PatternConfig::all_digits();
+        assert!(matches_condition(&[0xAB; 20], condition, &letters));
+        assert!(!matches_condition(&[0xA9; 20], condition, &letters));
+        assert!(matches_condition(&[0x09; 20], condition, &digits));
+        assert!(!matches_condition(&[0x0A; 20], condition, &digits));
+
+        // 前 2 个字节为叠字: 0x11aa...
+        let doubles = PatternConfig::leading_doubles(2).unwrap();
+        let mut address = [0x12u8; 20];
+        address[0] = 0x11;
+        address[1] = 0xAA;
+        assert!(matches_condition(&address, condition, &doubles));
+        address[1] = 0xAB;
+        assert!(!matches_condition(&address, condition, &doubles));
+
+        // 首尾 3 个字符镜像: 0x123...321
+        let mirror = PatternConfig::mirror(3).unwrap();
+        let mut address = [0x55u8; 20];
+        address[0] = 0x12;
+        address[1] = 0x30;
+        address[18] = 0x03;
+        address[19] = 0x21;
+        assert!(matches_condition(&address, condition, &mirror));
+        address[19] = 0x22;
+        assert!(!matches_condition(&address, condition, &mirror));
+
+        // Pattern 条件忽略字符类
+        assert!(matches_condition(
+            &[0x00; 20],
+            ConditionType::Pattern.encode(0),
+            &letters
+        ));
+        assert!(PatternConfig::leading_doubles(21).is_err());
+        assert!(PatternConfig::mirror(0).is_err());
+    }
+
+    #[test]
+    fn test_parse_hexspeak() {
+        assert_eq!(parse_hexspeak("dead").unwrap(), vec![0xd, 0xe, 0xa, 0xd]);
+        assert_eq!(
+            parse_hexspeak("Coffee").unwrap(),
+            parse_hexspeak("c0ffee").unwrap()
+        );
+        assert_eq!(
+            parse_hexspeak("toolset").unwrap(),
+            vec![7, 0, 0, 1, 5, 0xe, 7]
+        );
+        assert!(parse_hexspeak("rust").is_err());
+    }
+
+    #[test]
+    fn test_matches_contains() {
+        let word = parse_hexspeak("c0ffee").unwrap();
+        let pattern = PatternConfig::contains(&word).unwrap();
+        assert_eq!(pattern.value[..3], [0xc0, 0xff, 0xee]);
+        assert_eq!(pattern.mask, [0u8; 20]);
+        let condition = ConditionType::Contains.encode(word.len() as u64);
+
+        // 奇数偏移 (跨字节) 也能命中: 0x1c0ffee2...
+        let mut address = [0x11u8; 20];
+        address[7..11].copy_from_slice(&[0x1c, 0x0f, 0xfe, 0xe2]);
+        assert!(matches_condition(&address, condition, &pattern));
+        address[10] = 0xf2;
+        assert!(!matches_condition(&address, condition, &pattern));
+
+        // 末尾命中
+        let mut address = [0x11u8; 20];
+        address[17..].copy_from_slice(&[0xc0, 0xff, 0xee]);
+        assert!(matches_condition(&address, condition, &pattern));
+
+        assert!(PatternConfig::contains(&[]).is_err());
+        assert!(PatternConfig::contains(&[0u8; 41]).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_targets_postfix() {
+        let prefix = |hex: &str| {
+            let (condition, pattern) =
+                parse_pattern_condition(&format!("0x{}{}", hex, "X".repeat(40 - hex.len())))
+                    .unwrap();
+            SearchTarget {
+                root: 0,
+                ..SearchTarget::new_with_pattern(condition, pattern)
+            }
+        };
+        let suffix = |hex: &str| {
+            let (condition, pattern) =
+                parse_pattern_condition(&format!("0x{}{}", "X".repeat(40 - hex.len()), hex))
+                    .unwrap();
+            SearchTarget {
+                root: 0,
+                ..SearchTarget::new_with_pattern(condition, pattern)
+            }
+        };
+        let op = |op: ConditionType, n: u32| SearchTarget {
+            root: 0,
+            ..SearchTarget::operator(op, n).unwrap()
+        };
+
+        // #0: (prefix dead OR prefix beef) AND NOT suffix 00
+        // #1: suffix 11
+        let mut targets = vec![
+            prefix("dead"),
+            prefix("beef"),
+            op(ConditionType::Or, 2),
+            suffix("00"),
+            op(ConditionType::Not, 0),
+            op(ConditionType::And, 2),
+            suffix("11"),
+        ];
+        targets[5].root = 1;
+        targets[6].root = 1;
+
+        let mut address = [0x55u8; 20];
+        address[..2].copy_from_slice(&[0xbe, 0xef]);
+        assert_eq!(evaluate_targets(&address, &targets), Some(0));
+        address[19] = 0x00;
+        assert_eq!(evaluate_targets(&address, &targets), None);
+        address[19] = 0x11;
+        assert_eq!(evaluate_targets(&address, &targets), Some(0));
+        address[0] = 0x12;
+        assert_eq!(evaluate_targets(&address, &targets), Some(1));
+
+        assert!(SearchTarget::operator(ConditionType::And, 0).is_err());
+        assert!(SearchTarget::operator(ConditionType::Or, 32).is_err());
+        assert!(SearchTarget::operator(ConditionType::Prefix, 1).is_err());
+    }
+
+    #[test]
+    fn test_address_range() {
+        let (low, high) =
+            parse_address_range("..0x0000_1000_0000_0000_0000_0000_0000_0000_0000_0000").unwrap();
+        assert_eq!(low, [0u8; 20]);
+        let high = high.unwrap();
+        assert_eq!(high[..2], [0x00, 0x00]);
+        assert_eq!(high[2], 0x10);
+
+        let (condition, pattern) = PatternConfig::range(low, Some(high)).unwrap();
+        assert_eq!(condition >> 48, ConditionType::Range as u64);
+        let mut address = [0xFFu8; 20];
+        address[..3].copy_from_slice(&[0x00, 0x00, 0x0F]);
+        assert!(matches_condition(&address, condition, &pattern));
+        // 上界不包含在范围内
+        assert!(!matches_condition(&high, condition, &pattern));
+        assert!(matches_condition(&low, condition, &pattern));
+
+        // 没有上界: 大于等于竞争对手的地址
+        let competitor = parse_address_bound("0x8000000000000000000000000000000000000000").unwrap();
+        let (condition, pattern) = PatternConfig::range(competitor, None).unwrap();
+        assert_eq!(condition & 0xFFFFFFFFFFFF, 1);
+        assert!(matches_condition(&[0xFF; 20], condition, &pattern));
+        assert!(matches_condition(&competitor, condition, &pattern));
+        assert!(!matches_condition(&[0x7F; 20], condition, &pattern));
+
+        // 短数字按整数解析 (高位补零)
+        assert_eq!(parse_address_bound("0x1000").unwrap()[18..], [0x10, 0x00]);
+        assert!(PatternConfig::range([1; 20], Some([1; 20])).is_err());
+        assert!(parse_address_range("0x10").is_err());
+        assert!(parse_address_bound(&"f".repeat(41)).is_err());
+    }
+
+    #[test]
+    fn test_parse_derivation_path() {
+        let path = parse_derivation_path(DEFAULT_DERIVATION_PATH).unwrap();
+        assert_eq!(path, vec![44 | HARDENED, 60 | HARDENED, HARDENED, 0, 0]);
+        let config = SearchConfig::new([0; 32], 1);
+        assert_eq!(
+            config.derivation_path[..config.derivation_path_len as usize],
+            path[..]
+        );
+
+        // Ledger Live: m/44'/60'/N'/0/0，也接受 h 作为硬化标记
+        let ledger = parse_derivation_path("m/44h/60h/3h/0/0").unwrap();
+        assert_eq!(ledger[2], 3 | HARDENED);
+        assert_eq!(format_derivation_path(&ledger), "m/44'/60'/3'/0/0");
+        assert_eq!(parse_derivation_path("m").unwrap(), Vec::<u32>::new());
+
+        assert!(parse_derivation_path("44'/60'/0'/0/0").is_err());
+        assert!(parse_derivation_path("m/44'/x").is_err());
+        assert!(parse_derivation_path("m/2147483648").is_err());
+        assert!(parse_derivation_path(&format!("m{}", "/0".repeat(11))).is_err());
+        assert!(config.with_derivation_path(&[0; 11]).is_err());
+    }
+
+    #[test]
+    fn test_with_word_count() {
+        let config = SearchConfig::new([0; 32], 1);
+        assert_eq!(config.entropy_len, 32);
+        assert_eq!(config.with_word_count(12).unwrap().entropy_len, 16);
+        assert_eq!(config.with_word_count(18).unwrap().entropy_len, 24);
+        assert!(config.with_word_count(16).is_err());
+    }
 }
@@ -0,0 +1,251 @@
+//! 组合条件表达式解析
+//!
+//! 语法 (关键字不区分大小写):
+//! - `expr   := term (("OR" | "|") term)*`
+//! - `term   := factor (("AND" | "&") factor)*`
+//! - `factor := ("NOT" | "!") factor | "(" expr ")" | atom`
+//! - `atom   := 名称[:值]`，名称与命令行参数相同，如 `prefix:0000`、`leading-zeros:4`、`letters`
+//!
+//! 例如 `(prefix:dead OR prefix:beef) AND NOT suffix:00`。
+
+use anyhow::bail;
+
+use crate::api::SearchCondition;
+use crate::config::parse_address_range;
+use crate::hooks::parse_hook_flags;
+
+/// 解析组合条件表达式
+pub fn parse_condition_expr(expr: &str) -> anyhow::Result<SearchCondition> {
+    let tokens = tokenize(expr);
+    let mut parser = Parser { tokens, pos: 0 };
+    let condition = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        bail!("Unexpected '{}' in condition expression", token);
+    }
+    Ok(condition)
+}
+
+/// 拆分为括号、运算符和原子条件 (`&&` / `||` 视为 `&` / `|`)
+fn tokenize(expr: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = expr.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() || matches!(c, '(' | ')' | '&' | '|' | '!') {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            if matches!(c, '&' | '|') && chars.peek() == Some(&c) {
+                chars.next();
+            }
+            if !c.is_whitespace() {
+                tokens.push(c.to_string());
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+struct Parser {
+    tokens: Vec<String>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    /// 当前记号是否为指定运算符 (符号或关键字)，是则消费
+    fn eat(&mut self, symbol: &str, keyword: &str) -> bool {
+        match self.peek() {
+            Some(token) if token == symbol || token.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_or(&mut self) -> anyhow::Result<SearchCondition> {
+        let mut children = vec![self.parse_and()?];
+        while self.eat("|", "or") {
+            children.push(self.parse_and()?);
+        }
+        Ok(if children.len() == 1 {
+            children.remove(0)
+        } else {
+            SearchCondition::Or(children)
+        })
+    }
+
+    fn parse_and(&mut self) -> anyhow::Result<SearchCondition> {
+        let mut children = vec![self.parse_factor()?];
+        while self.eat("&", "and") {
+            children.push(self.parse_factor()?);
+        }
+        Ok(if children.len() == 1 {
+            children.remove(0)
+        } else {
+            SearchCondition::And(children)
+        })
+    }
+
+    fn parse_factor(&mut self) -> anyhow::Result<SearchCondition> {
+        if self.eat("!", "not") {
+            return Ok(!self.parse_factor()?);
+        }
+        if self.eat("(", "(") {
+            let condition = self.parse_or()?;
+            if !self.eat(")", ")") {
+                bail!("Missing ')' in condition expression");
+            }
+            return Ok(condition);
+        }
+        match self.peek() {
+            Some(token) if token != ")" && token != "&" && token != "|" => {
+                let token = token.to_string();
+                self.pos += 1;
+                parse_atom(&token)
+            }
+            Some(token) => bail!("Unexpected '{}' in condition expression", token),
+            None => bail!("Condition expression ended unexpectedly"),
+        }
+    }
+}
+
+/// 解析单个条件，如 `prefix:0000`、`leading-zeros:4`、`letters`
+fn parse_atom(token: &str) -> anyhow::Result<SearchCondition> {
+    let (name, value) = match token.split_once(':') {
+        Some((name, value)) => (name, Some(value)),
+        None => (token, None),
+    };
+    let name = name.to_ascii_lowercase();
+    let text = || {
+        value
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                anyhow::anyhow!("Condition '{}' requires a value ({}:<value>)", name, name)
+            })
+    };
+    let number = || -> anyhow::Result<u32> {
+        text()?
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Condition '{}' requires a number", name))
+    };
+
+    let condition = match name.as_str() {
+        "prefix" => SearchCondition::Prefix(text()?),
+        "suffix" => SearchCondition::Suffix(text()?),
+        "pattern" => SearchCondition::Pattern(text()?),
+        "contains" => SearchCondition::Contains(text()?),
+        "base58-prefix" => SearchCondition::Base58Prefix(text()?),
+        "bech32-prefix" => SearchCondition::Bech32Prefix(text()?),
+        "leading-zeros" => SearchCondition::LeadingZeros(number()?),
+        "leading-zeros-exact" => SearchCondition::LeadingZerosExact(number()?),
+        "zero-bytes" => SearchCondition::ZeroBytes(number()?),
+        "leading-doubles" => SearchCondition::LeadingDoubles(number()?),
+        "mirror" => SearchCondition::Mirror(number()?),
+        "hook-flags" => SearchCondition::HookFlags(parse_hook_flags(&text()?)?),
+        "range" => {
+            let (low, high) = parse_address_range(&text()?)?;
+            SearchCondition::Range { low, high }
+        }
+        "letters" => SearchCondition::AllLetters,
+        "numbers" => SearchCondition::AllDigits,
+        _ => bail!("Unknown condition '{}' in condition expression", name),
+    };
+    if matches!(
+        condition,
+        SearchCondition::AllLetters | SearchCondition::AllDigits
+    ) && value.is_some()
+    {
+        bail!("Condition '{}' does not take a value", name);
+    }
+    Ok(condition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_and() {
+        let condition = parse_condition_expr("prefix:0000 AND suffix:beef").unwrap();
+        match condition {
+            SearchCondition::And(children) => {
+                assert_eq!(children.len(), 2);
+                assert!(matches!(&children[0], SearchCondition::Prefix(p) if p == "0000"));
+                assert!(matches!(&children[1], SearchCondition::Suffix(s) if s == "beef"));
+            }
+            other => panic!("unexpected condition {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_precedence_and_not() {
+        // AND 优先级高于 OR，NOT 只作用于紧随的条件
+        let condition =
+            parse_condition_expr("(prefix:dead | prefix:beef) && !suffix:00 || letters").unwrap();
+        let SearchCondition::Or(alternatives) = condition else {
+            panic!("expected Or");
+        };
+        assert!(matches!(alternatives[1], SearchCondition::AllLetters));
+        let SearchCondition::And(children) = &alternatives[0] else {
+            panic!("expected And");
+        };
+        assert!(matches!(&children[0], SearchCondition::Or(c) if c.len() == 2));
+        assert!(matches!(
+            &children[1],
+            SearchCondition::Not(inner) if matches!(inner.as_ref(), SearchCondition::Suffix(s) if s == "00")
+        ));
+    }
+
+    #[test]
+    fn test_parse_atoms() {
+        assert!(matches!(
+            parse_condition_expr("leading-zeros:4").unwrap(),
+            SearchCondition::LeadingZeros(4)
+        ));
+        assert!(matches!(
+            parse_condition_expr("range:0x1000..").unwrap(),
+            SearchCondition::Range { high: None, .. }
+        ));
+        assert!(matches!(
+            parse_condition_expr("not not mirror:3").unwrap(),
+            SearchCondition::Not(_)
+        ));
+        assert!(matches!(
+            parse_condition_expr("pattern:0x{L}X{1}deadXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX")
+                .unwrap(),
+            SearchCondition::Pattern(_)
+        ));
+        // Base58 区分大小写，值保持原样
+        assert!(matches!(
+            parse_condition_expr("BASE58-PREFIX:1Love").unwrap(),
+            SearchCondition::Base58Prefix(p) if p == "1Love"
+        ));
+        assert!(matches!(
+            parse_condition_expr("bech32-prefix:bc1qxyz").unwrap(),
+            SearchCondition::Bech32Prefix(p) if p == "bc1qxyz"
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_condition_expr("").is_err());
+        assert!(parse_condition_expr("prefix").is_err());
+        assert!(parse_condition_expr("leading-zeros:many").is_err());
+        assert!(parse_condition_expr("(prefix:00").is_err());
+        assert!(parse_condition_expr("prefix:00 suffix:11").is_err());
+        assert!(parse_condition_expr("prefix:00 AND").is_err());
+        assert!(parse_condition_expr("letters:1").is_err());
+        assert!(parse_condition_expr("colour:red").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! Uniswap v4 hook 地址权限标志
+//!
+//! v4 的 PoolManager 根据 hook 合约地址的最低 14 位决定调用哪些回调，
+//! 因此部署 hook 前需要挖出一个 CREATE2 salt，使地址的低 14 位恰好等于所需权限。
+
+use crate::config::PatternConfig;
+
+pub const BEFORE_INITIALIZE: u16 = 1 << 13;
+pub const AFTER_INITIALIZE: u16 = 1 << 12;
+pub const BEFORE_ADD_LIQUIDITY: u16 = 1 << 11;
+pub const AFTER_ADD_LIQUIDITY: u16 = 1 << 10;
+pub const BEFORE_REMOVE_LIQUIDITY: u16 = 1 << 9;
+pub const AFTER_REMOVE_LIQUIDITY: u16 = 1 << 8;
+pub const BEFORE_SWAP: u16 = 1 << 7;
+pub const AFTER_SWAP: u16 = 1 << 6;
+pub const BEFORE_DONATE: u16 = 1 << 5;
+pub const AFTER_DONATE: u16 = 1 << 4;
+pub const BEFORE_SWAP_RETURNS_DELTA: u16 = 1 << 3;
+pub const AFTER_SWAP_RETURNS_DELTA: u16 = 1 << 2;
+pub const AFTER_ADD_LIQUIDITY_RETURNS_DELTA: u16 = 1 << 1;
+pub const AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA: u16 = 1 << 0;
+
+/// 全部权限位 (地址最低 14 位)
+pub const ALL_HOOK_MASK: u16 = (1 << 14) - 1;
+
+/// 权限标志名称 (命令行使用)
+pub const HOOK_FLAG_NAMES: [(&str, u16); 14] = [
+    ("before-initialize", BEFORE_INITIALIZE),
+    ("after-initialize", AFTER_INITIALIZE),
+    ("before-add-liquidity", BEFORE_ADD_LIQUIDITY),
+    ("after-add-liquidity", AFTER_ADD_LIQUIDITY),
+    ("before-remove-liquidity", BEFORE_REMOVE_LIQUIDITY),
+    ("after-remove-liquidity", AFTER_REMOVE_LIQUIDITY),
+    ("before-swap", BEFORE_SWAP),
+    ("after-swap", AFTER_SWAP),
+    ("before-donate", BEFORE_DONATE),
+    ("after-donate", AFTER_DONATE),
+    ("before-swap-returns-delta", BEFORE_SWAP_RETURNS_DELTA),
+    ("after-swap-returns-delta", AFTER_SWAP_RETURNS_DELTA),
+    (
+        "after-add-liquidity-returns-delta",
+        AFTER_ADD_LIQUIDITY_RETURNS_DELTA,
+    ),
+    (
+        "after-remove-liquidity-returns-delta",
+        AFTER_REMOVE_LIQUIDITY_RETURNS_DELTA,
+    ),
+];
+
+/// 解析权限标志
+///
+/// 支持逗号分隔的名称 (如 `before-swap,after-swap`) 或十六进制数值 (如 `0x00c0`)。
+pub fn parse_hook_flags(flags: &str) -> anyhow::Result<u16> {
+    let flags = flags.trim();
+    if let Some(hex) = flags
+        .strip_prefix("0x")
+        .or_else(|| flags.strip_prefix("0X"))
+    {
+        let value = u16::from_str_radix(hex, 16)?;
+        if value & !ALL_HOOK_MASK != 0 {
+            anyhow::bail!("Hook flags must fit in the low 14 bits, got 0x{:x}", value);
+        }
+        return Ok(value);
+    }
+
+    let mut value = 0u16;
+    for name in flags.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let flag = HOOK_FLAG_NAMES
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, flag)| *flag)
+            .ok_or_else(|| anyhow::anyhow!("Unknown hook flag '{}'", name))?;
+        value |= flag;
+    }
+    Ok(value)
+}
+
+/// 生成 hook 地址的匹配模式: 低 14 位必须与 flags 完全一致 (未选中的权限位必须为 0)
+pub fn hook_pattern(flags: u16) -> anyhow::Result<PatternConfig> {
+    if flags & !ALL_HOOK_MASK != 0 {
+        anyhow::bail!("Hook flags must fit in the low 14 bits, got 0x{:x}", flags);
+    }
+    PatternConfig::default().with_low_bits(14, flags as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hook_flags() {
+        assert_eq!(
+            parse_hook_flags("before-swap,after-swap").unwrap(),
+            BEFORE_SWAP | AFTER_SWAP
+        );
+        assert_eq!(parse_hook_flags("0x2000").unwrap(), BEFORE_INITIALIZE);
+        assert_eq!(parse_hook_flags("").unwrap(), 0);
+        assert!(parse_hook_flags("before-everything").is_err());
+        assert!(parse_hook_flags("0x4000").is_err());
+    }
+
+    #[test]
+    fn test_hook_pattern() {
+        let pattern = hook_pattern(BEFORE_INITIALIZE | AFTER_SWAP).unwrap();
+        assert_eq!(pattern.mask[18], 0x3F);
+        assert_eq!(pattern.mask[19], 0xFF);
+        assert_eq!(pattern.value[18], 0x20);
+        assert_eq!(pattern.value[19], 0x40);
+
+        // 地址 ...2040 满足，...2041 多出一个权限位不满足
+        let mut address = [0xABu8; 20];
+        address[18] = 0xE0;
+        address[19] = 0x40;
+        let matches = |address: &[u8; 20]| {
+            address
+                .iter()
+                .zip(pattern.mask.iter().zip(pattern.value.iter()))
+                .all(|(a, (m, v))| a & m == *v)
+        };
+        assert!(matches(&address));
+        address[19] = 0x41;
+        assert!(!matches(&address));
+    }
+}
@@ -2,19 +2,24 @@
 //!
 //! 提供统一的内核源代码加载功能，避免在 main.rs 和测试代码中重复。
 
+use std::fmt::Write;
+
+use crate::mnemonic::Language;
+
 /// 加载完整版内核源代码 (包含完整加密实现)
 ///
 /// 按正确的依赖顺序合并所有内核文件:
 /// 1. SHA-512 (PBKDF2 依赖)
 /// 2. PBKDF2 (BIP39 依赖)
 /// 3. SHA-256 (BIP39 校验和计算依赖)
-/// 4. Keccak-256 (以太坊地址生成)
-/// 5. secp256k1 (椭圆曲线运算)
-/// 6. 条件匹配
-/// 7. BIP39 词表
-/// 8. BIP39 熵处理
-/// 9. 主搜索内核
-/// 10. BIP39 助记词处理
+/// 4. RIPEMD-160 (比特币 HASH160)
+/// 5. Keccak-256 (以太坊地址生成)
+/// 6. secp256k1 (椭圆曲线运算)
+/// 7. 条件匹配
+/// 8. BIP39 词表 (英文，其他语言见 `load_kernel_source_for_language`)
+/// 9. BIP39 熵处理
+/// 10. 主搜索内核
+/// 11. BIP39 助记词处理
 ///
 /// # Example
 /// ```
@@ -23,6 +28,13 @@
 /// let kernel_source = load_kernel_source().expect("Failed to load kernel source");
 /// ```
 pub fn load_kernel_source() -> anyhow::Result<String> {
+    load_kernel_source_for_language(Language::English)
+}
+
+/// 加载使用指定语言 BIP39 词表的完整内核源代码
+///
+/// 英文词表使用预生成的 `wordlist.cl`，其他语言在加载时由 `wordlist_kernel_source` 生成
+pub fn load_kernel_source_for_language(language: Language) -> anyhow::Result<String> {
     let mut source = String::new();
 
     // 1. SHA-512 (PBKDF2 依赖)
@@ -37,27 +49,35 @@ pub fn load_kernel_source() -> anyhow::Result<String> {
     source.push_str(include_str!("../kernels/crypto/sha256.cl"));
     source.push('\n');
 
-    // 4. Keccak-256 (以太坊地址生成)
+    // 4. RIPEMD-160 (比特币 HASH160)
+    source.push_str(include_str!("../kernels/crypto/ripemd160.cl"));
+    source.push('\n');
+
+    // 5. Keccak-256 (以太坊地址生成)
     source.push_str(include_str!("../kernels/crypto/keccak.cl"));
     source.push('\n');
 
-    // 5. secp256k1 (椭圆曲线运算)
+    // 6. secp256k1 (椭圆曲线运算)
     source.push_str(include_str!("../kernels/crypto/secp256k1.cl"));
     source.push('\n');
 
-    // 6. 条件匹配
+    // 7. 条件匹配
     source.push_str(include_str!("../kernels/utils/condition.cl"));
     source.push('\n');
 
-    // 7. BIP39 词表 (entropy.cl 和 mnemonic.cl 依赖)
-    source.push_str(include_str!("../kernels/bip39/wordlist.cl"));
+    // 8. BIP39 词表 (entropy.cl 和 mnemonic.cl 依赖)
+    if language == Language::English {
+        source.push_str(include_str!("../kernels/bip39/wordlist.cl"));
+    } else {
+        source.push_str(&wordlist_kernel_source(language));
+    }
     source.push('\n');
 
-    // 8. BIP39 熵处理 (entropy_to_mnemonic 等，依赖 sha256 和 wordlist)
+    // 9. BIP39 熵处理 (entropy_to_mnemonic 等，依赖 sha256 和 wordlist)
     source.push_str(include_str!("../kernels/bip39/entropy.cl"));
     source.push('\n');
 
-    // 9. 主搜索内核 (包含 local_mnemonic_t 定义，必须在 mnemonic.cl 之前)
+    // 10. 主搜索内核 (包含 local_mnemonic_t 定义，必须在 mnemonic.cl 之前)
     let search_kernel = include_str!("../kernels/search.cl");
     for line in search_kernel.lines() {
         if !line.trim_start().starts_with("#include") {
@@ -67,13 +87,71 @@ pub fn load_kernel_source() -> anyhow::Result<String> {
     }
     source.push('\n');
 
-    // 10. BIP39 助记词处理 (依赖 local_mnemonic_t 和 wordlist.cl)
+    // 11. BIP39 助记词处理 (依赖 local_mnemonic_t 和 wordlist.cl)
     source.push_str(include_str!("../kernels/bip39/mnemonic.cl"));
     source.push('\n');
 
     Ok(source)
 }
 
+/// 生成 BIP39 词表的 OpenCL 源代码 (紧凑存储，格式与 `kernels/bip39/wordlist.cl` 相同)
+///
+/// 单词为 NFKD 规范化后的 UTF-8 字节，按顺序拼接后每 8 字节打包为一个大端序 ulong
+pub fn wordlist_kernel_source(language: Language) -> String {
+    let words = language.word_list();
+    let mut offsets = Vec::with_capacity(words.len());
+    let mut chars = Vec::new();
+    for word in words {
+        offsets.push(chars.len());
+        chars.extend_from_slice(word.as_bytes());
+    }
+    let total_chars = chars.len();
+    let max_word_len = words.iter().map(|w| w.len()).max().unwrap_or(0);
+    chars.resize(total_chars.div_ceil(8) * 8, 0);
+    let data: Vec<u64> = chars
+        .chunks(8)
+        .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+        .collect();
+
+    let mut source = String::new();
+    let _ = writeln!(source, "// BIP39 {}词表 (紧凑存储)", language.name());
+    source.push_str("// 自动生成，请勿手动修改\n\n");
+    let _ = writeln!(source, "#define BIP39_WORD_COUNT {}", words.len());
+    let _ = writeln!(source, "#define BIP39_TOTAL_CHARS {}", total_chars);
+    let _ = writeln!(source, "#define BIP39_ULONG_COUNT {}", data.len());
+    let _ = writeln!(source, "#define BIP39_MAX_WORD_LEN {}", max_word_len);
+
+    let _ = writeln!(source, "\n// 每个单词的长度 ({} bytes)", words.len());
+    source.push_str("__constant uchar WORD_LENGTHS[BIP39_WORD_COUNT] = {\n");
+    let lengths: Vec<String> = words.iter().map(|w| format!("{:2}", w.len())).collect();
+    push_table_rows(&mut source, &lengths, 16);
+
+    let _ = writeln!(
+        source,
+        "\n// 每个单词在字符数据中的偏移量 ({} bytes)",
+        words.len() * 2
+    );
+    source.push_str("__constant ushort WORD_OFFSETS[BIP39_WORD_COUNT] = {\n");
+    let offsets: Vec<String> = offsets.iter().map(|o| format!("{:5}", o)).collect();
+    push_table_rows(&mut source, &offsets, 8);
+
+    let _ = writeln!(source, "\n// 单词字符数据 ({} bytes)", data.len() * 8);
+    let _ = writeln!(source, "__constant ulong WORD_DATA[{}] = {{", data.len());
+    let data: Vec<String> = data.iter().map(|d| format!("0x{:016X}ULL", d)).collect();
+    push_table_rows(&mut source, &data, 4);
+    source
+}
+
+/// 按每行 per_row 项输出数组初始化列表，并以 `};` 结束
+fn push_table_rows(source: &mut String, items: &[String], per_row: usize) {
+    let rows: Vec<String> = items
+        .chunks(per_row)
+        .map(|row| format!("    {}", row.join(", ")))
+        .collect();
+    source.push_str(&rows.join(",\n"));
+    source.push_str("\n};\n");
+}
+
 /// 加载指定阶段的内核源代码 (用于测试和调试)
 ///
 /// # Arguments
@@ -81,6 +159,7 @@ pub fn load_kernel_source() -> anyhow::Result<String> {
 ///   - "sha512" - SHA-512 哈希
 ///   - "pbkdf2" - PBKDF2 密钥派生
 ///   - "sha256" - SHA-256 哈希
+///   - "ripemd160" - RIPEMD-160 哈希
 ///   - "keccak" - Keccak-256 哈希
 ///   - "secp256k1" - 椭圆曲线运算
 ///   - "condition" - 条件匹配
@@ -109,6 +188,9 @@ pub fn load_kernel_stages(stages: &[&str]) -> anyhow::Result<String> {
             "sha256" => {
                 source.push_str(include_str!("../kernels/crypto/sha256.cl"));
             }
+            "ripemd160" => {
+                source.push_str(include_str!("../kernels/crypto/ripemd160.cl"));
+            }
             "keccak" => {
                 source.push_str(include_str!("../kernels/crypto/keccak.cl"));
             }
@@ -157,9 +239,26 @@ mod tests {
         // 验证包含关键函数定义
         assert!(source.contains("search_kernel"));
         assert!(source.contains("keccak256"));
+        assert!(source.contains("ripemd160"));
         assert!(source.contains("pbkdf2_hmac_sha512"));
     }
 
+    #[test]
+    fn test_wordlist_kernel_source() {
+        // 生成的英文词表与预生成的 wordlist.cl 完全一致
+        assert_eq!(
+            wordlist_kernel_source(Language::English),
+            include_str!("../kernels/bip39/wordlist.cl")
+        );
+
+        let japanese = wordlist_kernel_source(Language::Japanese);
+        assert!(japanese.starts_with("// BIP39 日文词表"));
+        assert!(japanese.contains("#define BIP39_MAX_WORD_LEN 27"));
+        let korean = load_kernel_source_for_language(Language::Korean).unwrap();
+        assert!(korean.contains("#define BIP39_TOTAL_CHARS 35784"));
+        assert!(!korean.contains("BIP39 英文词表"));
+    }
+
     #[test]
     fn test_load_kernel_stages() {
         let source = load_kernel_stages(&["sha512", "pbkdf2"]).unwrap();
@@ -4,16 +4,30 @@
 //! 使用 OpenCL 在 GPU 上并行搜索符合条件的以太坊地址。
 
 pub mod api;
+pub mod bech32;
+pub mod bitcoin;
 pub mod config;
+pub mod expr;
+pub mod hooks;
 pub mod kernel_loader;
 pub mod mnemonic;
+pub mod nostr;
 pub mod opencl;
+pub mod split_key;
+pub mod tron;
 
-pub use api::{SearchCondition, SearchRequest, SearchResponse, search};
+pub use api::{
+    ConditionEncoding, SearchCondition, SearchMatch, SearchRequest, SearchResponse, search,
+};
 pub use config::{
-    ConditionType, PatternConfig, SearchConfig, SearchResult, SourceMode, TargetChain,
+    AddressType, ConditionType, DEFAULT_DERIVATION_PATH, MAX_DERIVATION_DEPTH,
+    MAX_PASSPHRASE_BYTES, PatternConfig, ScoreMode, SearchConfig, SearchResult, SearchTarget,
+    SourceMode, TargetChain, create_contract_address, create2_address, format_derivation_path,
+    parse_address_range, parse_derivation_path, parse_eth_address, parse_hash32,
     parse_pattern_condition,
 };
-pub use kernel_loader::load_kernel_source;
-pub use mnemonic::Mnemonic;
+pub use expr::parse_condition_expr;
+pub use kernel_loader::{load_kernel_source, load_kernel_source_for_language};
+pub use mnemonic::{Language, Mnemonic};
 pub use opencl::{OpenCLContext, SearchKernel};
+pub use split_key::{combine_split_key, eth_address_from_private_key, parse_public_key};
@@ -5,16 +5,47 @@
 //!   cargo run -- --suffix dead --threads 2048
 //!   cargo run -- --leading-zeros 4 --threads 4096
 
-use clap::Parser;
+use clap::{ArgGroup, Parser, ValueEnum};
 use log::info;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
-use rust_profanity::{Mnemonic, SearchCondition, SearchRequest, SourceMode, search};
+use rust_profanity::bitcoin::{
+    BITCOIN_HRP, BITCOIN_P2PKH_VERSION, DOGECOIN_P2PKH_VERSION, LITECOIN_HRP,
+    LITECOIN_P2PKH_VERSION, hash160_from_private_key, p2pkh_address, p2tr_address, p2wpkh_address,
+    private_key_to_wif, public_key_from_private_key, taproot_output_key,
+};
+use rust_profanity::hooks::parse_hook_flags;
+use rust_profanity::nostr::{npub, nsec, x_only_public_key};
+use rust_profanity::tron::tron_address;
+use rust_profanity::{
+    AddressType, ConditionEncoding, Language, SearchCondition, SearchMatch, SearchRequest,
+    SourceMode, TargetChain, combine_split_key, eth_address_from_private_key, parse_address_range,
+    parse_condition_expr, parse_eth_address, parse_hash32, parse_public_key, search,
+};
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+enum ConditionEncodingArg {
+    Pattern,
+    Specialized,
+}
+
+impl From<ConditionEncodingArg> for ConditionEncoding {
+    fn from(value: ConditionEncodingArg) -> Self {
+        match value {
+            ConditionEncodingArg::Pattern => ConditionEncoding::Pattern,
+            ConditionEncodingArg::Specialized => ConditionEncoding::Specialized,
+        }
+    }
+}
 
 #[derive(clap::ValueEnum, Debug, Clone, Copy)]
 enum SourceModeArg {
     Mnemonic,
     PrivateKey,
+    SplitKey,
+    DeployerNonce,
+    Create2,
 }
 
 impl From<SourceModeArg> for SourceMode {
@@ -22,6 +53,128 @@ impl From<SourceModeArg> for SourceMode {
         match value {
             SourceModeArg::Mnemonic => SourceMode::MnemonicEntropy,
             SourceModeArg::PrivateKey => SourceMode::PrivateKey,
+            SourceModeArg::SplitKey => SourceMode::SplitKey,
+            SourceModeArg::DeployerNonce => SourceMode::DeployerNonce,
+            SourceModeArg::Create2 => SourceMode::Create2Salt,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+enum LanguageArg {
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Japanese,
+    Korean,
+    Spanish,
+    French,
+    Italian,
+    Czech,
+    Portuguese,
+}
+
+impl From<LanguageArg> for Language {
+    fn from(value: LanguageArg) -> Self {
+        match value {
+            LanguageArg::English => Language::English,
+            LanguageArg::ChineseSimplified => Language::ChineseSimplified,
+            LanguageArg::ChineseTraditional => Language::ChineseTraditional,
+            LanguageArg::Japanese => Language::Japanese,
+            LanguageArg::Korean => Language::Korean,
+            LanguageArg::Spanish => Language::Spanish,
+            LanguageArg::French => Language::French,
+            LanguageArg::Italian => Language::Italian,
+            LanguageArg::Czech => Language::Czech,
+            LanguageArg::Portuguese => Language::Portuguese,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+enum ChainArg {
+    Ethereum,
+    Bitcoin,
+    Litecoin,
+    Dogecoin,
+    Tron,
+    Nostr,
+}
+
+impl ChainArg {
+    fn target_chain(self, format: AddressFormatArg) -> anyhow::Result<TargetChain> {
+        Ok(match (self, format) {
+            (ChainArg::Ethereum, AddressFormatArg::Legacy) => TargetChain::Ethereum,
+            (ChainArg::Tron, AddressFormatArg::Legacy) => TargetChain::Tron,
+            (ChainArg::Nostr, AddressFormatArg::Legacy) => TargetChain::Nostr,
+            (_, AddressFormatArg::Legacy) => TargetChain::Bitcoin,
+            (ChainArg::Ethereum | ChainArg::Dogecoin | ChainArg::Tron | ChainArg::Nostr, _) => {
+                anyhow::bail!("{}不支持 SegWit / Taproot 地址", self.name())
+            }
+            (_, AddressFormatArg::Segwit) => TargetChain::BitcoinSegwit,
+            (_, AddressFormatArg::Taproot) => TargetChain::BitcoinTaproot,
+        })
+    }
+
+    /// bech32 地址的人类可读部分 (不支持 SegWit 的链为 None)
+    fn bech32_hrp(self) -> Option<&'static str> {
+        match self {
+            ChainArg::Bitcoin => Some(BITCOIN_HRP),
+            ChainArg::Litecoin => Some(LITECOIN_HRP),
+            ChainArg::Ethereum | ChainArg::Dogecoin | ChainArg::Tron | ChainArg::Nostr => None,
+        }
+    }
+
+    /// 比特币系的 P2PKH 版本字节 (以太坊、波场、Nostr 为 None)
+    fn address_version(self) -> Option<u8> {
+        match self {
+            ChainArg::Ethereum | ChainArg::Tron | ChainArg::Nostr => None,
+            ChainArg::Bitcoin => Some(BITCOIN_P2PKH_VERSION),
+            ChainArg::Litecoin => Some(LITECOIN_P2PKH_VERSION),
+            ChainArg::Dogecoin => Some(DOGECOIN_P2PKH_VERSION),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ChainArg::Ethereum => "以太坊",
+            ChainArg::Bitcoin => "比特币",
+            ChainArg::Litecoin => "莱特币",
+            ChainArg::Dogecoin => "狗狗币",
+            ChainArg::Tron => "波场",
+            ChainArg::Nostr => "Nostr",
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum AddressFormatArg {
+    Legacy,
+    Segwit,
+    Taproot,
+}
+
+impl AddressFormatArg {
+    fn name(self) -> &'static str {
+        match self {
+            AddressFormatArg::Legacy => "P2PKH",
+            AddressFormatArg::Segwit => "SegWit P2WPKH",
+            AddressFormatArg::Taproot => "Taproot P2TR",
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+enum AddressTypeArg {
+    Eoa,
+    Create,
+}
+
+impl From<AddressTypeArg> for AddressType {
+    fn from(value: AddressTypeArg) -> Self {
+        match value {
+            AddressTypeArg::Eoa => AddressType::Eoa,
+            AddressTypeArg::Create => AddressType::Create,
         }
     }
 }
@@ -31,6 +184,7 @@ impl From<SourceModeArg> for SourceMode {
 #[command(name = "rust-profanity")]
 #[command(about = "GPU以太坊靓号地址搜索系统")]
 #[command(version = "0.1.0")]
+#[command(group(ArgGroup::new("condition").multiple(true)))]
 struct Args {
     /// 前缀匹配 (十六进制，如 8888)
     #[arg(long, group = "condition")]
@@ -44,11 +198,95 @@ struct Args {
     #[arg(long, group = "condition")]
     leading_zeros: Option<u32>,
 
+    /// 前导零个数 (精确，第 n+1 个字符必须非零)
+    #[arg(long, group = "condition")]
+    leading_zeros_exact: Option<u32>,
+
+    /// 零字节个数 (至少，任意位置)，零字节越多 calldata gas 越省
+    #[arg(long, group = "condition")]
+    zero_bytes: Option<u32>,
+
+    /// 地址全部由字母 a-f 组成
+    #[arg(long, group = "condition")]
+    letters: bool,
+
+    /// 地址全部由数字 0-9 组成
+    #[arg(long, group = "condition")]
+    numbers: bool,
+
+    /// 前 n 个字节的两个字符相同 (如 0x00aa11...)
+    #[arg(long, group = "condition")]
+    leading_doubles: Option<u32>,
+
+    /// 首尾 n 个字符互为镜像 (如 n=3: 0x123...321)
+    #[arg(long, group = "condition")]
+    mirror: Option<u32>,
+
     /// 模式匹配 (完整地址模式，如 0xXXXXXXXXXXXXdeadXXXXXXXXXXXXXXXXXXXXXXXX)
-    /// X/*/? 表示通配符，其他字符表示需要匹配的值
+    /// X/*/? 表示通配符，{L}/{D} 表示字母/数字，{n} 表示与第 n 个字符相同，其他字符表示需要匹配的值
     #[arg(long, group = "condition")]
     pattern: Option<String>,
 
+    /// Uniswap v4 hook 权限标志 (逗号分隔的名称如 before-swap,after-swap，或十六进制如 0x00c0)
+    /// 自动使用 create2 模式挖 salt，需配合 --deployer 和 --init-code-hash
+    #[arg(long, group = "condition")]
+    hook_flags: Option<String>,
+
+    /// 地址数值范围 LOW..HIGH (左闭右开，任一端可省略)，地址按 160 位大端整数比较
+    /// 边界按整数解析，不足 40 位时高位补零，如 ..0x0000100000000000000000000000000000000000
+    #[arg(long, group = "condition")]
+    range: Option<String>,
+
+    /// Base58Check 地址前缀 (比特币 / 莱特币 / 狗狗币 / 波场，需配合 --chain)，如 1Love、LTC、TRX
+    /// 区分大小写，不能包含 0、O、I、l
+    #[arg(long, group = "condition")]
+    base58_prefix: Option<String>,
+
+    /// bech32 地址前缀 (需配合 --address-format segwit / taproot 或 --chain nostr)，如 bc1qxyz、npub1xyz
+    /// 不能包含 1、b、i、o，hrp1q / hrp1p / npub1 之后最多 32 个字符
+    #[arg(long, group = "condition")]
+    bech32_prefix: Option<String>,
+
+    /// 组合条件表达式，支持 AND / OR / NOT 和括号，条件名称与参数名相同
+    /// 如 "(prefix:dead OR prefix:beef) AND NOT suffix:00"
+    #[arg(long, group = "condition")]
+    expr: Option<String>,
+
+    /// 单词出现在地址任意位置 (逗号分隔多个单词，任一命中即可)
+    /// 非十六进制字母按 leetspeak 转换: o→0, i/l→1, z→2, s→5, t→7, g→9，如 coffee → c0ffee
+    #[arg(long, group = "condition_list", conflicts_with = "condition")]
+    contains: Option<String>,
+
+    /// 单词字典文件 (每行一个单词，空行和 # 开头的行被忽略)，任一单词出现在地址任意位置即命中
+    /// 每个单词占目标表中的一项并单独扫描，最多 128 个单词
+    #[arg(long, group = "condition_list", conflicts_with = "condition")]
+    dictionary: Option<PathBuf>,
+
+    /// 多目标模式文件 (每行一个完整地址模式，空行和 # 开头的行被忽略)
+    /// 任一模式命中即停止，输出会注明命中的是第几个模式
+    #[arg(long, group = "condition_list", conflicts_with = "condition")]
+    pattern_file: Option<PathBuf>,
+
+    /// 评分模式: 持续搜索前导零最多的地址，每次刷新最高分都会输出
+    #[arg(long, group = "condition")]
+    score_leading_zeros: bool,
+
+    /// 评分模式: 持续搜索零字符总数最多的地址
+    #[arg(long, group = "condition")]
+    score_zeros: bool,
+
+    /// 评分模式: 持续搜索与目标模式相同字符最多的地址 (格式同 --pattern)
+    #[arg(long, group = "condition")]
+    score_matching: Option<String>,
+
+    /// 条件编码方式: pattern(统一模式匹配) / specialized(前缀/后缀/前导零使用专用编码，更快)
+    #[arg(long, value_enum, default_value = "pattern")]
+    condition_encoding: ConditionEncodingArg,
+
+    /// 按 EIP-55 校验和大小写匹配条件中的字母 (如 --prefix DeAd)
+    #[arg(long, default_value_t = false)]
+    case_sensitive: bool,
+
     /// GPU 线程数
     #[arg(short, long, default_value = "1024")]
     threads: u32,
@@ -65,57 +303,520 @@ struct Args {
     #[arg(long, default_value = "0")]
     timeout: u64,
 
-    /// 地址搜索来源模式: mnemonic(助记词) / private-key(直接私钥)
-    #[arg(long, value_enum, default_value = "mnemonic")]
-    source_mode: SourceModeArg,
+    /// 收集多少个结果后停止 (0 表示不限，持续搜索直到超时)
+    #[arg(long, default_value = "1")]
+    max_results: u32,
+
+    /// 地址搜索来源模式: mnemonic(助记词) / private-key(直接私钥) / split-key(分离密钥) /
+    /// deployer-nonce(固定部署者遍历 nonce) / create2(遍历 CREATE2 salt)
+    /// 默认为 mnemonic，给出 --hook-flags 时默认为 create2
+    #[arg(long, value_enum)]
+    source_mode: Option<SourceModeArg>,
 
     /// 启用多 GPU 并行 (自动使用全部可用 GPU)
     #[arg(long, default_value_t = false)]
     multi_gpu: bool,
+
+    /// 私钥模式每批点数 (批量求逆，1-16)
+    #[arg(long, default_value = "8")]
+    batch_size: u32,
+
+    /// 分离密钥模式的用户公钥 (十六进制，压缩或未压缩格式)
+    #[arg(long)]
+    public_key: Option<String>,
+
+    /// 目标链: ethereum / bitcoin / litecoin / dogecoin / tron / nostr (比特币系为 P2PKH 地址，
+    /// 波场为 T 地址，Nostr 为 npub 公钥，派生路径默认按币种选择)
+    #[arg(long, value_enum, default_value = "ethereum")]
+    chain: ChainArg,
+
+    /// 比特币系地址格式: legacy(P2PKH) / segwit(P2WPKH, bc1q) / taproot(P2TR, bc1p)
+    /// segwit 默认派生路径为 BIP84，taproot 为 BIP86
+    #[arg(long, value_enum, default_value = "legacy")]
+    address_format: AddressFormatArg,
+
+    /// 匹配的地址类型: eoa(账户地址) / create(该账户 nonce 0 部署的合约地址)
+    #[arg(long, value_enum, default_value = "eoa")]
+    address_type: AddressTypeArg,
+
+    /// 部署者地址 (deployer-nonce / create2 模式)
+    #[arg(long)]
+    deployer: Option<String>,
+
+    /// CREATE2 的 keccak256(init_code) (十六进制，create2 模式)
+    #[arg(long)]
+    init_code_hash: Option<String>,
+
+    /// 助记词模式的 BIP39 密码 (第 25 个词) 所在的文件，`-` 表示从标准输入读取
+    /// 密码不会出现在命令行、shell 历史和日志中，恢复钱包时必须输入相同的密码
+    #[arg(long)]
+    passphrase_file: Option<PathBuf>,
+
+    /// 助记词模式的 BIP32 派生路径，如 Ledger Live 的 m/44'/60'/1'/0/0 (默认 m/44'/60'/0'/0/0)
+    #[arg(long)]
+    derivation_path: Option<String>,
+
+    /// 助记词模式每个助记词检查的地址个数 (派生路径最后一层依次递增，分摊 PBKDF2 开销)
+    #[arg(long, default_value = "1")]
+    address_count: u32,
+
+    /// 助记词模式的单词数: 12 / 15 / 18 / 21 / 24 (对应 128-256 位熵)
+    #[arg(long, default_value = "24")]
+    words: usize,
+
+    /// 助记词模式的 BIP39 词表语言: english / chinese-simplified / chinese-traditional /
+    /// japanese / korean / spanish / french / italian / czech / portuguese
+    #[arg(long, value_enum, default_value = "english")]
+    language: LanguageArg,
+
+    /// 合并分离密钥: 存放用户私钥 (十六进制) 的文件，`-` 表示从标准输入读取，
+    /// 私钥不会出现在命令行、shell 历史和进程列表中 (离线使用，需配合 --combine-offset)
+    #[arg(long, requires = "combine_offset")]
+    combine_secret_file: Option<PathBuf>,
+
+    /// 合并分离密钥: GPU 找到的偏移量 k (十六进制)
+    #[arg(long, requires = "combine_secret_file")]
+    combine_offset: Option<String>,
 }
 
-/// 解析搜索条件
+/// 解析搜索条件 (同时给出多个条件参数时要求全部满足)
 fn parse_condition(args: &Args) -> anyhow::Result<SearchCondition> {
+    let mut conditions = Vec::new();
     if let Some(prefix) = &args.prefix {
         info!("搜索条件: 前缀匹配 {}", prefix);
-        Ok(SearchCondition::Prefix(prefix.clone()))
-    } else if let Some(suffix) = &args.suffix {
+        conditions.push(SearchCondition::Prefix(prefix.clone()));
+    }
+    if let Some(suffix) = &args.suffix {
         info!("搜索条件: 后缀匹配 {}", suffix);
-        Ok(SearchCondition::Suffix(suffix.clone()))
-    } else if let Some(zeros) = args.leading_zeros {
+        conditions.push(SearchCondition::Suffix(suffix.clone()));
+    }
+    if let Some(zeros) = args.leading_zeros {
         info!("搜索条件: 前导零至少 {} 个", zeros);
-        Ok(SearchCondition::LeadingZeros(zeros))
-    } else if let Some(pattern) = &args.pattern {
+        conditions.push(SearchCondition::LeadingZeros(zeros));
+    }
+    if let Some(zeros) = args.leading_zeros_exact {
+        info!("搜索条件: 前导零恰好 {} 个", zeros);
+        conditions.push(SearchCondition::LeadingZerosExact(zeros));
+    }
+    if let Some(count) = args.zero_bytes {
+        info!("搜索条件: 零字节至少 {} 个", count);
+        conditions.push(SearchCondition::ZeroBytes(count));
+    }
+    if args.letters {
+        info!("搜索条件: 全字母地址");
+        conditions.push(SearchCondition::AllLetters);
+    }
+    if args.numbers {
+        info!("搜索条件: 全数字地址");
+        conditions.push(SearchCondition::AllDigits);
+    }
+    if let Some(count) = args.leading_doubles {
+        info!("搜索条件: 前 {} 个字节为叠字", count);
+        conditions.push(SearchCondition::LeadingDoubles(count));
+    }
+    if let Some(count) = args.mirror {
+        info!("搜索条件: 首尾 {} 个字符镜像", count);
+        conditions.push(SearchCondition::Mirror(count));
+    }
+    if let Some(pattern) = &args.pattern {
         info!("搜索条件: 模式匹配 {}", pattern);
-        Ok(SearchCondition::Pattern(pattern.clone()))
+        conditions.push(SearchCondition::Pattern(pattern.clone()));
+    }
+    if let Some(flags) = &args.hook_flags {
+        let flags = parse_hook_flags(flags)?;
+        info!("搜索条件: Uniswap v4 hook 权限标志 0x{:04x}", flags);
+        conditions.push(SearchCondition::HookFlags(flags));
+    }
+    if let Some(range) = &args.range {
+        let (low, high) = parse_address_range(range)?;
+        info!("搜索条件: 地址数值范围 {}", range);
+        conditions.push(SearchCondition::Range { low, high });
+    }
+    if let Some(prefix) = &args.base58_prefix {
+        info!("搜索条件: Base58 地址前缀 {}", prefix);
+        conditions.push(SearchCondition::Base58Prefix(prefix.clone()));
+    }
+    if let Some(prefix) = &args.bech32_prefix {
+        info!("搜索条件: bech32 地址前缀 {}", prefix);
+        conditions.push(SearchCondition::Bech32Prefix(prefix.clone()));
+    }
+    if let Some(expr) = &args.expr {
+        info!("搜索条件: 表达式 {}", expr);
+        conditions.push(parse_condition_expr(expr)?);
+    }
+    if args.score_leading_zeros {
+        info!("搜索条件: 评分模式 (前导零最多)");
+        conditions.push(SearchCondition::ScoreLeadingZeros);
+    }
+    if args.score_zeros {
+        info!("搜索条件: 评分模式 (零字符最多)");
+        conditions.push(SearchCondition::ScoreZeroNibbles);
+    }
+    if let Some(pattern) = &args.score_matching {
+        info!("搜索条件: 评分模式 (与 {} 相同字符最多)", pattern);
+        conditions.push(SearchCondition::ScoreMatching(pattern.clone()));
+    }
+
+    match conditions.len() {
+        0 => anyhow::bail!(
+            "请指定搜索条件: --prefix, --suffix, --leading-zeros, --leading-zeros-exact, --zero-bytes, --letters, --numbers, --leading-doubles, --mirror, --pattern, --hook-flags, --range, --base58-prefix, --bech32-prefix, --expr, --contains, --dictionary, --pattern-file 或 --score-*"
+        ),
+        1 => Ok(conditions.remove(0)),
+        n => {
+            info!("搜索条件: 以上 {} 个条件需同时满足", n);
+            Ok(SearchCondition::And(conditions))
+        }
+    }
+}
+
+/// 解析多目标模式列表 (每行一个模式，忽略空行和注释)
+fn parse_pattern_list(content: &str) -> anyhow::Result<Vec<SearchCondition>> {
+    let conditions: Vec<SearchCondition> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| SearchCondition::Pattern(line.to_string()))
+        .collect();
+    if conditions.is_empty() {
+        anyhow::bail!("模式文件中没有任何模式");
+    }
+    Ok(conditions)
+}
+
+/// 解析单词列表 (逗号或换行分隔，忽略空项和 # 注释行)
+fn parse_word_list(content: &str) -> anyhow::Result<Vec<SearchCondition>> {
+    let conditions: Vec<SearchCondition> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+        .map(|word| SearchCondition::Contains(word.to_string()))
+        .collect();
+    if conditions.is_empty() {
+        anyhow::bail!("没有任何单词");
+    }
+    Ok(conditions)
+}
+
+/// 解析全部搜索条件: --pattern-file / --dictionary / --contains 提供多个目标，其余参数提供单个目标
+fn parse_conditions(args: &Args) -> anyhow::Result<Vec<SearchCondition>> {
+    if let Some(path) = &args.pattern_file {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("读取模式文件 {} 失败: {}", path.display(), e))?;
+        let conditions = parse_pattern_list(&content)?;
+        info!(
+            "搜索条件: 从 {} 读取 {} 个模式",
+            path.display(),
+            conditions.len()
+        );
+        Ok(conditions)
+    } else if let Some(path) = &args.dictionary {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("读取字典文件 {} 失败: {}", path.display(), e))?;
+        let conditions = parse_word_list(&content)?;
+        info!(
+            "搜索条件: 从 {} 读取 {} 个单词 (任意位置)",
+            path.display(),
+            conditions.len()
+        );
+        Ok(conditions)
+    } else if let Some(words) = &args.contains {
+        info!("搜索条件: 地址任意位置包含 {}", words);
+        parse_word_list(words)
     } else {
-        anyhow::bail!("请指定搜索条件: --prefix, --suffix, --leading-zeros 或 --pattern")
+        Ok(vec![parse_condition(args)?])
     }
 }
 
+/// 从文件读取敏感参数 (`-` 表示标准输入)，只取第一行，保留行内空白
+fn read_secret(path: &Path) -> anyhow::Result<String> {
+    let mut content = String::new();
+    if path == Path::new("-") {
+        std::io::stdin()
+            .read_line(&mut content)
+            .map_err(|e| anyhow::anyhow!("读取标准输入失败: {}", e))?;
+    } else {
+        content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("读取文件 {} 失败: {}", path.display(), e))?;
+    }
+    let line = content.lines().next().unwrap_or_default();
+    Ok(line.to_string())
+}
+
+/// 读取 BIP39 密码，文件为空或只有空白时报错 (否则搜索结果与不带密码相同，却提示已启用密码)
+fn read_passphrase(path: &Path) -> anyhow::Result<String> {
+    let passphrase = read_secret(path)?;
+    if passphrase.trim().is_empty() {
+        anyhow::bail!("BIP39 密码为空，请检查 --passphrase-file 的内容");
+    }
+    Ok(passphrase)
+}
+
+/// 离线合并分离密钥，输出最终私钥和地址
+fn combine_keys(
+    secret: &str,
+    offset: &str,
+    chain: ChainArg,
+    format: AddressFormatArg,
+) -> anyhow::Result<()> {
+    let private_key = combine_split_key(&parse_hash32(secret)?, &parse_hash32(offset)?)?;
+    if matches!(chain, ChainArg::Nostr) {
+        let x_only = x_only_public_key(&public_key_from_private_key(&private_key)?);
+        println!("Nostr 公钥: {}", npub(&x_only));
+        println!("私钥: 0x{}", hex::encode(private_key));
+        println!("Nostr 私钥: {}", nsec(&private_key));
+        return Ok(());
+    }
+    if matches!(chain, ChainArg::Tron) {
+        let address = eth_address_from_private_key(&private_key)?;
+        println!("{}地址: {}", chain.name(), tron_address(&address));
+        println!("私钥: 0x{}", hex::encode(private_key));
+        return Ok(());
+    }
+    if let Some(version) = chain.address_version() {
+        let hrp = chain.bech32_hrp().unwrap_or(BITCOIN_HRP);
+        let address = match chain.target_chain(format)? {
+            TargetChain::BitcoinSegwit => {
+                p2wpkh_address(hrp, &hash160_from_private_key(&private_key)?)
+            }
+            TargetChain::BitcoinTaproot => {
+                let output_key = taproot_output_key(&public_key_from_private_key(&private_key)?)?;
+                p2tr_address(hrp, &output_key)
+            }
+            _ => p2pkh_address(version, &hash160_from_private_key(&private_key)?),
+        };
+        println!("{}地址: {}", chain.name(), address);
+        println!("私钥: 0x{}", hex::encode(private_key));
+        println!("WIF 私钥: {}", private_key_to_wif(&private_key, version));
+        return Ok(());
+    }
+    let address = eth_address_from_private_key(&private_key)?;
+    println!("以太坊地址: 0x{}", hex::encode(address));
+    println!("私钥: 0x{}", hex::encode(private_key));
+    Ok(())
+}
+
+/// 打印单个命中结果
+fn print_match(
+    found: &SearchMatch,
+    args: &Args,
+    passphrase: Option<&str>,
+    address_type: AddressType,
+    conditions: &[SearchCondition],
+) {
+    let is_contract = matches!(
+        found.source_mode,
+        SourceMode::DeployerNonce | SourceMode::Create2Salt
+    ) || address_type == AddressType::Create;
+    if is_contract {
+        println!("合约地址: {}", found.address);
+    } else if matches!(args.chain, ChainArg::Nostr) {
+        println!("Nostr 公钥: {}", found.address);
+    } else {
+        println!("{}地址: {}", args.chain.name(), found.address);
+    }
+
+    match found.source_mode {
+        SourceMode::MnemonicEntropy => {
+            let mnemonic = found.mnemonic().expect("从熵生成助记词失败");
+            println!("助记词: {}", mnemonic);
+            if mnemonic.language != Language::English {
+                println!("助记词语言: {}", mnemonic.language.name());
+            }
+            if let Some(path) = &found.derivation_path {
+                println!("派生路径: {}", path);
+            }
+            if let (Some(index), true) = (found.address_index, args.address_count > 1) {
+                println!("地址索引: {}", index);
+            }
+            if passphrase.is_some() {
+                println!("BIP39 密码: 已启用 (导入钱包时需同时输入 --passphrase-file 中的密码)");
+            }
+            if let (ChainArg::Nostr, Some(path)) = (args.chain, &found.derivation_path) {
+                match mnemonic.derive_private_key(passphrase.unwrap_or(""), path) {
+                    Ok(private_key) => println!("Nostr 私钥: {}", nsec(&private_key)),
+                    Err(e) => println!("Nostr 私钥: 派生失败 ({})", e),
+                }
+            }
+        }
+        SourceMode::PrivateKey => {
+            println!("私钥: 0x{}", hex::encode(found.result_seed));
+            if let Some(version) = args.chain.address_version() {
+                println!(
+                    "WIF 私钥: {}",
+                    private_key_to_wif(&found.result_seed, version)
+                );
+            }
+            if matches!(args.chain, ChainArg::Nostr) {
+                println!("Nostr 私钥: {}", nsec(&found.result_seed));
+            }
+        }
+        SourceMode::SplitKey => {
+            println!("偏移量 k: 0x{}", hex::encode(found.result_seed));
+            let mut chain = match args.chain {
+                ChainArg::Ethereum => String::new(),
+                chain => format!(
+                    " --chain {}",
+                    chain
+                        .to_possible_value()
+                        .expect("no skipped variants")
+                        .get_name()
+                ),
+            };
+            if args.address_format != AddressFormatArg::Legacy {
+                chain.push_str(&format!(
+                    " --address-format {}",
+                    args.address_format
+                        .to_possible_value()
+                        .expect("no skipped variants")
+                        .get_name()
+                ));
+            }
+            println!(
+                "最终私钥 = 你的私钥 + k (mod n)，可离线执行 --combine-secret-file <私钥文件> --combine-offset 0x{}{}",
+                hex::encode(found.result_seed),
+                chain
+            );
+        }
+        SourceMode::DeployerNonce => {
+            if let Some(nonce) = found.nonce() {
+                println!("部署者: {}", args.deployer.as_deref().unwrap_or_default());
+                println!("部署 nonce: {}", nonce);
+            }
+        }
+        SourceMode::Create2Salt => {
+            if let Some(salt) = found.salt() {
+                println!("部署者: {}", args.deployer.as_deref().unwrap_or_default());
+                println!("Salt: 0x{}", hex::encode(salt));
+            }
+        }
+    }
+    if address_type == AddressType::Create
+        && !matches!(
+            found.source_mode,
+            SourceMode::DeployerNonce | SourceMode::Create2Salt
+        )
+    {
+        println!("部署 nonce: 0 (需用该账户的第一笔交易部署合约)");
+    }
+
+    if conditions.len() > 1 {
+        match conditions.get(found.target_index) {
+            Some(SearchCondition::Pattern(pattern)) => {
+                println!("命中模式: #{} {}", found.target_index + 1, pattern);
+            }
+            Some(SearchCondition::Contains(word)) => {
+                println!("命中单词: #{} {}", found.target_index + 1, word);
+            }
+            _ => {}
+        }
+    }
+
+    if found.score > 0 {
+        println!("分数: {}", found.score);
+    }
+    println!("找到线程: {}", found.found_by_thread);
+    println!("找到设备: {}", found.found_device);
+}
+
+/// 确定来源模式: 未指定时 --hook-flags 隐含 create2，否则为助记词模式
+///
+/// 只在某种模式下生效的参数与其他模式同时出现时报错，而不是静默忽略
+fn resolve_source_mode(args: &Args) -> anyhow::Result<SourceMode> {
+    // hook 权限标志只能通过 CREATE2 salt 挖掘满足，显式指定其他来源模式时报错
+    let source_mode: SourceMode = match (args.source_mode, args.hook_flags.is_some()) {
+        (Some(mode), true) if SourceMode::from(mode) != SourceMode::Create2Salt => {
+            anyhow::bail!(
+                "--hook-flags 只能用于 create2 来源模式，与 --source-mode {} 冲突",
+                mode.to_possible_value()
+                    .expect("no skipped variants")
+                    .get_name()
+            );
+        }
+        (Some(mode), _) => mode.into(),
+        (None, true) => SourceMode::Create2Salt,
+        (None, false) => SourceMode::MnemonicEntropy,
+    };
+    if args.public_key.is_some() && source_mode != SourceMode::SplitKey {
+        anyhow::bail!("--public-key 只能用于分离密钥模式，请指定 --source-mode split-key");
+    }
+    Ok(source_mode)
+}
+
 /// 主函数
 fn main() -> anyhow::Result<()> {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
 
     let args = Args::parse();
+    if let (Some(secret_file), Some(offset)) = (&args.combine_secret_file, &args.combine_offset) {
+        let secret = read_secret(secret_file)?;
+        return combine_keys(secret.trim(), offset, args.chain, args.address_format);
+    }
+
     info!("启动 GPU以太坊靓号地址搜索系统");
     info!("参数: {:?}", args);
+    let passphrase = args
+        .passphrase_file
+        .as_deref()
+        .map(read_passphrase)
+        .transpose()?;
 
-    let source_mode: SourceMode = args.source_mode.into();
+    let source_mode = resolve_source_mode(&args)?;
     match source_mode {
         SourceMode::MnemonicEntropy => {
             info!("来源模式: 助记词熵派生");
             info!("搜索空间: {} 个线程从随机熵开始并行遍历", args.threads);
+            if passphrase.is_some() {
+                info!("已启用 BIP39 密码");
+            }
+            if let Some(path) = &args.derivation_path {
+                info!("派生路径: {}", path);
+            }
+            if args.address_count > 1 {
+                info!("每个助记词检查 {} 个地址索引", args.address_count);
+            }
+            if args.words != 24 {
+                info!("助记词单词数: {}", args.words);
+            }
+            let language = Language::from(args.language);
+            if language != Language::English {
+                info!("助记词语言: {}", language.name());
+            }
         }
         SourceMode::PrivateKey => {
             info!("来源模式: 直接私钥遍历");
             info!("搜索空间: {} 个线程从随机私钥开始并行遍历", args.threads);
         }
+        SourceMode::SplitKey => {
+            info!("来源模式: 分离密钥 (GPU 只搜索偏移量，不接触私钥)");
+            info!("搜索空间: {} 个线程从随机偏移量开始并行遍历", args.threads);
+        }
+        SourceMode::DeployerNonce => {
+            info!("来源模式: 固定部署者遍历 nonce (CREATE 合约地址)");
+            info!("搜索空间: {} 个线程从 nonce 0 开始并行遍历", args.threads);
+        }
+        SourceMode::Create2Salt => {
+            info!("来源模式: CREATE2 salt 遍历");
+            info!("搜索空间: {} 个线程从随机 salt 开始并行遍历", args.threads);
+        }
+    }
+
+    let target_chain = args.chain.target_chain(args.address_format)?;
+    match target_chain {
+        TargetChain::Ethereum => {}
+        TargetChain::Tron | TargetChain::Nostr => info!("目标链: {}", args.chain.name()),
+        _ => info!(
+            "目标链: {} ({} 地址)",
+            args.chain.name(),
+            args.address_format.name()
+        ),
     }
 
-    let condition = parse_condition(&args)?;
+    let conditions = parse_conditions(&args)?;
 
-    let mut request = SearchRequest::new(condition);
+    let mut request = SearchRequest::with_conditions(conditions.clone());
     request.threads = args.threads;
     request.work_group_size = args.work_group_size;
     request.poll_interval = Duration::from_millis(args.poll_interval);
@@ -126,6 +827,37 @@ fn main() -> anyhow::Result<()> {
     };
     request.source_mode = source_mode;
     request.multi_gpu = args.multi_gpu;
+    request.case_sensitive = args.case_sensitive;
+    request.max_results = args.max_results;
+    request.condition_encoding = args.condition_encoding.into();
+    request.batch_size = args.batch_size;
+    request.public_key = args
+        .public_key
+        .as_deref()
+        .map(parse_public_key)
+        .transpose()?;
+    request.address_type = args.address_type.into();
+    request.deployer = args
+        .deployer
+        .as_deref()
+        .map(parse_eth_address)
+        .transpose()?;
+    request.init_code_hash = args
+        .init_code_hash
+        .as_deref()
+        .map(parse_hash32)
+        .transpose()?;
+    request.passphrase = passphrase.clone();
+    request.derivation_path = args.derivation_path.clone();
+    request.address_count = args.address_count;
+    request.word_count = args.words;
+    request.language = args.language.into();
+    request.target_chain = target_chain;
+    if target_chain == TargetChain::Bitcoin {
+        request.address_version = args.chain.address_version();
+    } else if target_chain.is_bech32() {
+        request.bech32_hrp = args.chain.bech32_hrp().map(String::from);
+    }
 
     let response = search(request)?;
 
@@ -135,30 +867,28 @@ fn main() -> anyhow::Result<()> {
     if response.found {
         println!("✓ 找到符合条件的地址!");
         println!("========================================");
-        println!(
-            "以太坊地址: 0x{}",
-            response.eth_address_hex().unwrap_or_default()
-        );
-
-        match response.source_mode {
-            SourceMode::MnemonicEntropy => {
-                if let Some(seed) = response.result_seed {
-                    let mnemonic = Mnemonic::from_entropy(&seed).expect("从熵生成助记词失败");
-                    println!("助记词: {}", mnemonic);
-                }
-            }
-            SourceMode::PrivateKey => {
-                if let Some(seed) = response.result_seed {
-                    println!("私钥: 0x{}", hex::encode(seed));
-                }
+        // 评分模式只输出最高分结果 (更早的提升已在搜索过程中记录到日志)
+        let shown = if response.score.is_some() {
+            &response.results[response.results.len() - 1..]
+        } else {
+            &response.results[..]
+        };
+        let multiple = shown.len() > 1;
+        for (idx, found) in shown.iter().enumerate() {
+            if multiple {
+                println!("---------- 结果 #{} ----------", idx + 1);
             }
+            print_match(
+                found,
+                &args,
+                passphrase.as_deref(),
+                response.address_type,
+                &conditions,
+            );
         }
-
-        if let Some(found_by_thread) = response.found_by_thread {
-            println!("找到线程: {}", found_by_thread);
-        }
-        if let Some(device_name) = response.found_device {
-            println!("找到设备: {}", device_name);
+        if multiple {
+            println!("========================================");
+            println!("共找到 {} 个结果", shown.len());
         }
     } else if response.timed_out {
         println!("✗ 搜索超时 ({} 秒) - 强制终止", args.timeout);
@@ -187,13 +917,47 @@ mod tests {
             prefix: Some("8888".to_string()),
             suffix: None,
             leading_zeros: None,
+            leading_zeros_exact: None,
+            zero_bytes: None,
+            letters: false,
+            numbers: false,
+            leading_doubles: None,
+            mirror: None,
             pattern: None,
+            hook_flags: None,
+            range: None,
+            base58_prefix: None,
+            bech32_prefix: None,
+            expr: None,
+            contains: None,
+            dictionary: None,
+            pattern_file: None,
+            score_leading_zeros: false,
+            score_zeros: false,
+            score_matching: None,
+            condition_encoding: ConditionEncodingArg::Pattern,
+            case_sensitive: false,
             threads: 1024,
             work_group_size: 256,
             poll_interval: 100,
             timeout: 0,
-            source_mode: SourceModeArg::Mnemonic,
+            max_results: 1,
+            source_mode: None,
             multi_gpu: false,
+            batch_size: 8,
+            public_key: None,
+            combine_secret_file: None,
+            combine_offset: None,
+            chain: ChainArg::Ethereum,
+            address_format: AddressFormatArg::Legacy,
+            address_type: AddressTypeArg::Eoa,
+            deployer: None,
+            init_code_hash: None,
+            passphrase_file: None,
+            derivation_path: None,
+            address_count: 1,
+            words: 24,
+            language: LanguageArg::English,
         };
 
         let condition = parse_condition(&args).unwrap();
@@ -207,13 +971,47 @@ mod tests {
             prefix: None,
             suffix: None,
             leading_zeros: None,
+            leading_zeros_exact: None,
+            zero_bytes: None,
+            letters: false,
+            numbers: false,
+            leading_doubles: None,
+            mirror: None,
             pattern: None,
+            hook_flags: None,
+            range: None,
+            base58_prefix: None,
+            bech32_prefix: None,
+            expr: None,
+            contains: None,
+            dictionary: None,
+            pattern_file: None,
+            score_leading_zeros: false,
+            score_zeros: false,
+            score_matching: None,
+            condition_encoding: ConditionEncodingArg::Pattern,
+            case_sensitive: false,
             threads: 1024,
             work_group_size: 128,
             poll_interval: 250,
             timeout: 0,
-            source_mode: SourceModeArg::Mnemonic,
+            max_results: 1,
+            source_mode: None,
             multi_gpu: false,
+            batch_size: 8,
+            public_key: None,
+            combine_secret_file: None,
+            combine_offset: None,
+            chain: ChainArg::Ethereum,
+            address_format: AddressFormatArg::Legacy,
+            address_type: AddressTypeArg::Eoa,
+            deployer: None,
+            init_code_hash: None,
+            passphrase_file: None,
+            derivation_path: None,
+            address_count: 1,
+            words: 24,
+            language: LanguageArg::English,
         };
 
         let result = parse_condition(&args);
@@ -234,13 +1032,47 @@ mod tests {
             prefix: None,
             suffix: Some("dead".to_string()),
             leading_zeros: None,
+            leading_zeros_exact: None,
+            zero_bytes: None,
+            letters: false,
+            numbers: false,
+            leading_doubles: None,
+            mirror: None,
             pattern: None,
+            hook_flags: None,
+            range: None,
+            base58_prefix: None,
+            bech32_prefix: None,
+            expr: None,
+            contains: None,
+            dictionary: None,
+            pattern_file: None,
+            score_leading_zeros: false,
+            score_zeros: false,
+            score_matching: None,
+            condition_encoding: ConditionEncodingArg::Pattern,
+            case_sensitive: false,
             threads: 1,
             work_group_size: 1,
             poll_interval: 1,
             timeout: 0,
-            source_mode: SourceModeArg::Mnemonic,
+            max_results: 1,
+            source_mode: None,
             multi_gpu: false,
+            batch_size: 8,
+            public_key: None,
+            combine_secret_file: None,
+            combine_offset: None,
+            chain: ChainArg::Ethereum,
+            address_format: AddressFormatArg::Legacy,
+            address_type: AddressTypeArg::Eoa,
+            deployer: None,
+            init_code_hash: None,
+            passphrase_file: None,
+            derivation_path: None,
+            address_count: 1,
+            words: 24,
+            language: LanguageArg::English,
         };
         assert!(matches!(
             parse_condition(&suffix).unwrap(),
@@ -251,13 +1083,47 @@ mod tests {
             prefix: None,
             suffix: None,
             leading_zeros: Some(4),
+            leading_zeros_exact: None,
+            zero_bytes: None,
+            letters: false,
+            numbers: false,
+            leading_doubles: None,
+            mirror: None,
             pattern: None,
+            hook_flags: None,
+            range: None,
+            base58_prefix: None,
+            bech32_prefix: None,
+            expr: None,
+            contains: None,
+            dictionary: None,
+            pattern_file: None,
+            score_leading_zeros: false,
+            score_zeros: false,
+            score_matching: None,
+            condition_encoding: ConditionEncodingArg::Pattern,
+            case_sensitive: false,
             threads: 1,
             work_group_size: 1,
             poll_interval: 1,
             timeout: 0,
-            source_mode: SourceModeArg::Mnemonic,
+            max_results: 1,
+            source_mode: None,
             multi_gpu: false,
+            batch_size: 8,
+            public_key: None,
+            combine_secret_file: None,
+            combine_offset: None,
+            chain: ChainArg::Ethereum,
+            address_format: AddressFormatArg::Legacy,
+            address_type: AddressTypeArg::Eoa,
+            deployer: None,
+            init_code_hash: None,
+            passphrase_file: None,
+            derivation_path: None,
+            address_count: 1,
+            words: 24,
+            language: LanguageArg::English,
         };
         assert!(matches!(
             parse_condition(&leading).unwrap(),
@@ -268,13 +1134,47 @@ mod tests {
             prefix: None,
             suffix: None,
             leading_zeros: None,
+            leading_zeros_exact: None,
+            zero_bytes: None,
+            letters: false,
+            numbers: false,
+            leading_doubles: None,
+            mirror: None,
             pattern: Some("0xXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXdead".to_string()),
+            hook_flags: None,
+            range: None,
+            base58_prefix: None,
+            bech32_prefix: None,
+            expr: None,
+            contains: None,
+            dictionary: None,
+            pattern_file: None,
+            score_leading_zeros: false,
+            score_zeros: false,
+            score_matching: None,
+            condition_encoding: ConditionEncodingArg::Pattern,
+            case_sensitive: false,
             threads: 1,
             work_group_size: 1,
             poll_interval: 1,
             timeout: 0,
-            source_mode: SourceModeArg::Mnemonic,
+            max_results: 1,
+            source_mode: None,
             multi_gpu: false,
+            batch_size: 8,
+            public_key: None,
+            combine_secret_file: None,
+            combine_offset: None,
+            chain: ChainArg::Ethereum,
+            address_format: AddressFormatArg::Legacy,
+            address_type: AddressTypeArg::Eoa,
+            deployer: None,
+            init_code_hash: None,
+            passphrase_file: None,
+            derivation_path: None,
+            address_count: 1,
+            words: 24,
+            language: LanguageArg::English,
         };
         assert!(matches!(
             parse_condition(&pattern).unwrap(),
@@ -282,8 +1182,212 @@ mod tests {
         ));
     }
 
+    /// 测试: 验证比特币系目标链和 Base58 前缀参数
+    #[test]
+    fn test_parse_chain_args() {
+        let args = Args::try_parse_from([
+            "rust-profanity",
+            "--chain",
+            "litecoin",
+            "--base58-prefix",
+            "LTC",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.chain.target_chain(args.address_format).unwrap(),
+            TargetChain::Bitcoin
+        );
+        assert_eq!(args.chain.address_version(), Some(LITECOIN_P2PKH_VERSION));
+        assert!(matches!(
+            parse_condition(&args).unwrap(),
+            SearchCondition::Base58Prefix(p) if p == "LTC"
+        ));
+
+        let args = Args::try_parse_from(["rust-profanity", "--prefix", "dead"]).unwrap();
+        assert_eq!(
+            args.chain.target_chain(args.address_format).unwrap(),
+            TargetChain::Ethereum
+        );
+        assert!(args.chain.address_version().is_none());
+
+        let args = Args::try_parse_from([
+            "rust-profanity",
+            "--chain",
+            "litecoin",
+            "--address-format",
+            "taproot",
+            "--bech32-prefix",
+            "ltc1pxyz",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.chain.target_chain(args.address_format).unwrap(),
+            TargetChain::BitcoinTaproot
+        );
+        assert_eq!(args.chain.bech32_hrp(), Some(LITECOIN_HRP));
+        assert!(matches!(
+            parse_condition(&args).unwrap(),
+            SearchCondition::Bech32Prefix(p) if p == "ltc1pxyz"
+        ));
+
+        let args = Args::try_parse_from([
+            "rust-profanity",
+            "--chain",
+            "tron",
+            "--base58-prefix",
+            "TRX",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.chain.target_chain(args.address_format).unwrap(),
+            TargetChain::Tron
+        );
+        assert!(args.chain.address_version().is_none());
+
+        let args = Args::try_parse_from([
+            "rust-profanity",
+            "--chain",
+            "nostr",
+            "--bech32-prefix",
+            "npub1xyz",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.chain.target_chain(args.address_format).unwrap(),
+            TargetChain::Nostr
+        );
+        assert!(args.chain.bech32_hrp().is_none());
+
+        // 狗狗币、以太坊、波场和 Nostr 没有 SegWit 地址
+        for chain in [
+            ChainArg::Dogecoin,
+            ChainArg::Ethereum,
+            ChainArg::Tron,
+            ChainArg::Nostr,
+        ] {
+            assert!(chain.target_chain(AddressFormatArg::Segwit).is_err());
+        }
+    }
+
+    /// 测试: 只在某种来源模式下生效的参数与其他模式同时出现时报错
+    #[test]
+    fn test_resolve_source_mode() {
+        let resolve = |argv: &[&str]| {
+            let args =
+                Args::try_parse_from(["rust-profanity", "--prefix", "00"].iter().chain(argv))
+                    .unwrap();
+            resolve_source_mode(&args)
+        };
+
+        assert_eq!(resolve(&[]).unwrap(), SourceMode::MnemonicEntropy);
+        assert_eq!(
+            resolve(&["--hook-flags", "before-swap"]).unwrap(),
+            SourceMode::Create2Salt
+        );
+        assert!(
+            resolve(&[
+                "--source-mode",
+                "private-key",
+                "--hook-flags",
+                "before-swap"
+            ])
+            .is_err()
+        );
+
+        assert_eq!(
+            resolve(&["--source-mode", "split-key", "--public-key", "04"]).unwrap(),
+            SourceMode::SplitKey
+        );
+        let err = resolve(&["--public-key", "04"]).unwrap_err();
+        assert!(err.to_string().contains("--public-key"));
+        assert!(resolve(&["--source-mode", "private-key", "--public-key", "04"]).is_err());
+    }
+
+    /// 测试: 空的密码文件被拒绝，而不是当作已启用的空密码
+    #[test]
+    fn test_read_passphrase_rejects_empty() {
+        let path = std::env::temp_dir().join(format!("passphrase-{}.txt", std::process::id()));
+        for (content, expected) in [
+            // 只取第一行，保留行内空白
+            (" TREZOR\nsecond line\n", Some(" TREZOR")),
+            ("", None),
+            ("  \n", None),
+        ] {
+            std::fs::write(&path, content).unwrap();
+            let result = read_passphrase(&path);
+            match expected {
+                Some(passphrase) => assert_eq!(result.unwrap(), passphrase),
+                None => assert!(result.is_err()),
+            }
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn test_pattern_parser_still_available() {
         assert!(parse_pattern_condition("0xXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXdead").is_ok());
     }
+
+    /// 测试: 验证多目标模式文件的解析
+    #[test]
+    fn test_parse_pattern_list() {
+        let content = "# vanity targets\n0x8888XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX\n\n  0xXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXdead  \n";
+        let conditions = parse_pattern_list(content).unwrap();
+        assert_eq!(conditions.len(), 2);
+        assert!(matches!(
+            &conditions[1],
+            SearchCondition::Pattern(p) if p == "0xXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXdead"
+        ));
+        assert!(parse_pattern_list("# only comments\n\n").is_err());
+    }
+
+    /// 测试: 验证单词列表的解析
+    #[test]
+    fn test_parse_word_list() {
+        let conditions = parse_word_list("# brand words\ndead, beef\n\ncoffee\n").unwrap();
+        assert_eq!(conditions.len(), 3);
+        assert!(matches!(
+            &conditions[2],
+            SearchCondition::Contains(w) if w == "coffee"
+        ));
+        assert!(parse_word_list(" , \n# none").is_err());
+    }
+
+    /// 测试: 多个条件参数组合为 And，多目标参数不能与其他条件同时使用
+    #[test]
+    fn test_condition_flags_combine_with_and() {
+        let args =
+            Args::try_parse_from(["rust-profanity", "--prefix", "00", "--suffix", "11"]).unwrap();
+        assert!(matches!(
+            parse_condition(&args).unwrap(),
+            SearchCondition::And(children) if children.len() == 2
+        ));
+
+        let args = Args::try_parse_from([
+            "rust-profanity",
+            "--expr",
+            "prefix:dead OR prefix:beef",
+            "--letters",
+        ])
+        .unwrap();
+        assert!(matches!(
+            parse_condition(&args).unwrap(),
+            SearchCondition::And(children) if matches!(children[1], SearchCondition::Or(_))
+        ));
+
+        assert!(
+            Args::try_parse_from(["rust-profanity", "--contains", "dead", "--prefix", "00"])
+                .is_err()
+        );
+        assert!(
+            Args::try_parse_from([
+                "rust-profanity",
+                "--contains",
+                "dead",
+                "--pattern-file",
+                "targets.txt"
+            ])
+            .is_err()
+        );
+    }
 }
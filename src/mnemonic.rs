@@ -3,57 +3,178 @@
 use rand::RngCore;
 use rand::rngs::OsRng;
 use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
 
-// 引入完整的 BIP39 单词表
+// 引入完整的 BIP39 英文单词表 (其他语言的单词表来自 bip39 crate，均已 NFKD 规范化)
 include!("wordlist.rs");
 
+/// BIP39 单词表语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Japanese,
+    Korean,
+    Spanish,
+    French,
+    Italian,
+    Czech,
+    Portuguese,
+}
+
+impl Language {
+    /// 全部支持的语言 (`Mnemonic::from_string` 按此顺序识别)
+    pub const ALL: [Language; 10] = [
+        Language::English,
+        Language::ChineseSimplified,
+        Language::ChineseTraditional,
+        Language::Japanese,
+        Language::Korean,
+        Language::Spanish,
+        Language::French,
+        Language::Italian,
+        Language::Czech,
+        Language::Portuguese,
+    ];
+
+    /// 2048 个单词 (NFKD 规范化后的 UTF-8)
+    pub fn word_list(self) -> &'static [&'static str] {
+        let language = match self {
+            Language::English => return BIP39_WORDLIST,
+            Language::ChineseSimplified => bip39::Language::SimplifiedChinese,
+            Language::ChineseTraditional => bip39::Language::TraditionalChinese,
+            Language::Japanese => bip39::Language::Japanese,
+            Language::Korean => bip39::Language::Korean,
+            Language::Spanish => bip39::Language::Spanish,
+            Language::French => bip39::Language::French,
+            Language::Italian => bip39::Language::Italian,
+            Language::Czech => bip39::Language::Czech,
+            Language::Portuguese => bip39::Language::Portuguese,
+        };
+        language.word_list()
+    }
+
+    /// 语言名称
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::English => "英文",
+            Language::ChineseSimplified => "简体中文",
+            Language::ChineseTraditional => "繁体中文",
+            Language::Japanese => "日文",
+            Language::Korean => "韩文",
+            Language::Spanish => "西班牙文",
+            Language::French => "法文",
+            Language::Italian => "意大利文",
+            Language::Czech => "捷克文",
+            Language::Portuguese => "葡萄牙文",
+        }
+    }
+
+    /// 助记词的单词分隔符: 日文使用全角空格 (U+3000)，其他语言使用空格
+    ///
+    /// 计算种子时助记词按 NFKD 规范化，全角空格会变为普通空格
+    pub fn separator(self) -> &'static str {
+        match self {
+            Language::Japanese => "\u{3000}",
+            _ => " ",
+        }
+    }
+
+    /// 查找单词的索引 (输入先做 NFKD 规范化)
+    pub fn find_word(self, word: &str) -> Option<u16> {
+        let word: String = word.nfkd().collect();
+        self.word_list()
+            .iter()
+            .position(|&w| w == word)
+            .map(|idx| idx as u16)
+    }
+}
+
+/// 支持的助记词单词数 (对应 128/160/192/224/256 位熵)
+pub const SUPPORTED_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// 单词数对应的熵字节数 (12 词 16 字节 ... 24 词 32 字节)
+///
+/// # Errors
+/// 单词数不在 `SUPPORTED_WORD_COUNTS` 中时返回错误
+pub fn entropy_len_for_words(word_count: usize) -> anyhow::Result<usize> {
+    if !SUPPORTED_WORD_COUNTS.contains(&word_count) {
+        anyhow::bail!(
+            "Mnemonic must have 12, 15, 18, 21 or 24 words, got {}",
+            word_count
+        );
+    }
+    Ok(word_count / 3 * 4)
+}
+
 /// BIP39 助记词
 #[derive(Debug, Clone)]
 pub struct Mnemonic {
-    /// 24个单词的索引 (每个索引 0-2047)
-    pub words: [u16; 24],
+    /// 单词索引 (12/15/18/21/24 个，每个索引 0-2047)
+    pub words: Vec<u16>,
+    /// 单词表语言
+    pub language: Language,
 }
 
 impl Mnemonic {
-    /// 生成随机助记词
+    /// 生成随机助记词 (24 个单词)
     pub fn generate_random() -> anyhow::Result<Self> {
-        let mut entropy = [0u8; 32];
+        Self::generate_random_with_words(24)
+    }
+
+    /// 生成指定单词数的随机助记词
+    pub fn generate_random_with_words(word_count: usize) -> anyhow::Result<Self> {
+        let mut entropy = vec![0u8; entropy_len_for_words(word_count)?];
         OsRng.fill_bytes(&mut entropy);
 
         Self::from_entropy(&entropy)
     }
 
-    /// 从熵生成助记词 (符合 BIP39 标准)
-    pub fn from_entropy(entropy: &[u8; 32]) -> anyhow::Result<Self> {
-        // 计算校验和: SHA256 的前 8 位 (256/32 = 8)
-        let hash = Sha256::digest(entropy);
-        let checksum_bits = hash[0]; // 取前8位
+    /// 从熵生成英文助记词 (符合 BIP39 标准)
+    ///
+    /// # Errors
+    /// 熵长度不是 16/20/24/28/32 字节时返回错误
+    pub fn from_entropy(entropy: &[u8]) -> anyhow::Result<Self> {
+        Self::from_entropy_in(Language::English, entropy)
+    }
+
+    /// 从熵生成指定语言的助记词
+    ///
+    /// # Errors
+    /// 熵长度不是 16/20/24/28/32 字节时返回错误
+    pub fn from_entropy_in(language: Language, entropy: &[u8]) -> anyhow::Result<Self> {
+        if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+            anyhow::bail!(
+                "Entropy must be 16, 20, 24, 28 or 32 bytes, got {}",
+                entropy.len()
+            );
+        }
 
-        // 组合: 256位熵 + 8位校验和 = 264位
-        // 将数据视为大端序的位流
-        let mut all_bits = [0u8; 33];
-        all_bits[..32].copy_from_slice(entropy);
-        all_bits[32] = checksum_bits;
+        // 组合: ENT 位熵 + ENT/32 位校验和 (SHA256 的前几位)
+        // 将数据视为大端序的位流，校验和位于最后一个字节的高位
+        let mut all_bits = entropy.to_vec();
+        all_bits.push(Self::checksum(entropy));
 
-        // 提取24个11位索引
-        let words = Self::extract_word_indices(&all_bits);
+        // 提取 (ENT + CS) / 11 个 11 位索引
+        let word_count = entropy.len() * 3 / 4;
+        let words = (0..word_count)
+            .map(|i| Self::read_11bits(&all_bits, i * 11))
+            .collect();
 
-        Ok(Self { words })
+        Ok(Self { words, language })
     }
 
-    /// 从位流中提取24个11位单词索引
-    fn extract_word_indices(all_bits: &[u8; 33]) -> [u16; 24] {
-        let mut words = [0u16; 24];
-        for (i, word) in words.iter_mut().enumerate() {
-            let bit_offset = i * 11;
-            *word = Self::read_11bits(all_bits, bit_offset);
-        }
-        words
+    /// 熵的校验和字节: SHA256 的前 ENT/32 位，低位清零
+    fn checksum(entropy: &[u8]) -> u8 {
+        let checksum_len = entropy.len() * 8 / 32;
+        Sha256::digest(entropy)[0] & (0xFF00u16 >> checksum_len) as u8
     }
 
     /// 从字节数组中读取11位值 (大端序位流)
     #[inline]
-    fn read_11bits(data: &[u8; 33], bit_offset: usize) -> u16 {
+    fn read_11bits(data: &[u8], bit_offset: usize) -> u16 {
         let mut idx: u16 = 0;
         for j in 0..11 {
             let bit_pos = bit_offset + j;
@@ -69,7 +190,7 @@ impl Mnemonic {
 
     /// 将11位值写入字节数组 (大端序位流)
     #[inline]
-    fn write_11bits(data: &mut [u8; 33], bit_offset: usize, value: u16) {
+    fn write_11bits(data: &mut [u8], bit_offset: usize, value: u16) {
         for j in 0..11 {
             let bit_pos = bit_offset + j;
             let byte_idx = bit_pos / 8;
@@ -81,13 +202,17 @@ impl Mnemonic {
         }
     }
 
-    /// 转换为 BIP39 种子
+    /// 转换为 BIP39 种子 (助记词和密码按 BIP39 要求做 NFKD 规范化)
     ///
     /// # Panics
     /// 如果助记词包含无效的单词索引，会 panic
     pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
-        let mnemonic_str = self.as_phrase().expect("Invalid mnemonic word index");
-        let salt = format!("mnemonic{}", passphrase);
+        let mnemonic_str: String = self
+            .as_phrase()
+            .expect("Invalid mnemonic word index")
+            .nfkd()
+            .collect();
+        let salt: String = "mnemonic".chars().chain(passphrase.nfkd()).collect();
 
         use pbkdf2::pbkdf2_hmac;
         use sha2::Sha512;
@@ -98,49 +223,79 @@ impl Mnemonic {
         seed
     }
 
+    /// 按 BIP32 派生路径 (如 `m/44'/60'/0'/0/0`) 推导私钥 (主机端，用于重建命中结果的密钥)
+    pub fn derive_private_key(&self, passphrase: &str, path: &str) -> anyhow::Result<[u8; 32]> {
+        let path: bip32::DerivationPath = path
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid derivation path '{}': {}", path, e))?;
+        let xprv = bip32::XPrv::derive_from_path(self.to_seed(passphrase), &path)
+            .map_err(|e| anyhow::anyhow!("BIP32 derivation failed: {}", e))?;
+        Ok(xprv.private_key().to_bytes().into())
+    }
+
     /// 转换为字符串
     ///
     /// # Errors
     /// 如果单词索引超出有效范围 (0-2047)，返回错误
     pub fn as_phrase(&self) -> anyhow::Result<String> {
-        let mut words = Vec::with_capacity(24);
+        let wordlist = self.language.word_list();
+        let mut words = Vec::with_capacity(self.words.len());
         for (i, &idx) in self.words.iter().enumerate() {
-            if (idx as usize) < BIP39_WORDLIST.len() {
-                words.push(BIP39_WORDLIST[idx as usize]);
+            if (idx as usize) < wordlist.len() {
+                words.push(wordlist[idx as usize]);
             } else {
                 anyhow::bail!(
                     "Invalid word index {} at position {} (max: {})",
                     idx,
                     i,
-                    BIP39_WORDLIST.len() - 1
+                    wordlist.len() - 1
                 );
             }
         }
-        Ok(words.join(" "))
+        Ok(words.join(self.language.separator()))
     }
 
-    /// 从字符串解析
+    /// 从字符串解析 (12/15/18/21/24 个单词)，自动识别语言
+    ///
+    /// 简体 / 繁体中文共用大量汉字，优先选择校验和有效的语言，其次按 `Language::ALL` 的顺序
     pub fn from_string(s: &str) -> anyhow::Result<Self> {
-        let word_strs: Vec<&str> = s.split_whitespace().collect();
-
-        if word_strs.len() != 24 {
-            anyhow::bail!("Expected 24 words, got {}", word_strs.len());
+        let candidates: Vec<Self> = Language::ALL
+            .into_iter()
+            .filter_map(|language| Self::from_string_in(language, s).ok())
+            .collect();
+        match candidates.iter().find(|m| m.validate_checksum()) {
+            Some(mnemonic) => Ok(mnemonic.clone()),
+            None => match candidates.into_iter().next() {
+                Some(mnemonic) => Ok(mnemonic),
+                None => Self::from_string_in(Language::English, s),
+            },
         }
+    }
+
+    /// 按指定语言的单词表解析 (单词先做 NFKD 规范化，支持日文全角空格分隔)
+    pub fn from_string_in(language: Language, s: &str) -> anyhow::Result<Self> {
+        let word_strs: Vec<&str> = s.split_whitespace().collect();
+        entropy_len_for_words(word_strs.len())?;
 
-        let mut words = [0u16; 24];
-        for (i, word) in word_strs.iter().enumerate() {
-            match BIP39_WORDLIST.iter().position(|&w| w == *word) {
-                Some(idx) => words[i] = idx as u16,
+        let mut words = Vec::with_capacity(word_strs.len());
+        for word in &word_strs {
+            match language.find_word(word) {
+                Some(idx) => words.push(idx),
                 None => anyhow::bail!("Unknown word: {}", word),
             }
         }
 
-        Ok(Self { words })
+        Ok(Self { words, language })
+    }
+
+    /// 单词数
+    pub fn word_count(&self) -> usize {
+        self.words.len()
     }
 
-    /// 从单词索引重建位流
-    fn rebuild_bitstream(&self) -> [u8; 33] {
-        let mut all_bits = [0u8; 33];
+    /// 从单词索引重建位流 (熵 + 校验和字节)
+    fn rebuild_bitstream(&self) -> Vec<u8> {
+        let mut all_bits = vec![0u8; self.words.len() * 4 / 3 + 1];
         for (i, &word_idx) in self.words.iter().enumerate() {
             let bit_offset = i * 11;
             Self::write_11bits(&mut all_bits, bit_offset, word_idx);
@@ -150,33 +305,17 @@ impl Mnemonic {
 
     /// 验证助记词校验和 (BIP39 标准验证)
     pub fn validate_checksum(&self) -> bool {
-        let all_bits = self.rebuild_bitstream();
-
-        // 提取熵和校验和
-        let entropy = &all_bits[..32];
-        let checksum = all_bits[32];
-
-        // 计算期望的校验和
-        let hash = Sha256::digest(entropy);
-        let expected_checksum = hash[0];
-
-        checksum == expected_checksum
+        self.to_entropy().1
     }
 
-    /// 从助记词重建熵 (256位)
+    /// 从助记词重建熵 (128-256 位)
     /// 返回熵和校验和是否有效的布尔值
-    pub fn to_entropy(&self) -> ([u8; 32], bool) {
-        let all_bits = self.rebuild_bitstream();
-
-        // 提取熵
-        let mut entropy = [0u8; 32];
-        entropy.copy_from_slice(&all_bits[..32]);
-        let checksum = all_bits[32];
+    pub fn to_entropy(&self) -> (Vec<u8>, bool) {
+        let mut entropy = self.rebuild_bitstream();
+        let checksum = entropy.pop().unwrap_or_default();
 
         // 验证校验和
-        let hash = Sha256::digest(entropy);
-        let expected_checksum = hash[0];
-        let valid = checksum == expected_checksum;
+        let valid = checksum == Self::checksum(&entropy);
 
         (entropy, valid)
     }
@@ -218,6 +357,28 @@ mod tests {
         assert_eq!(seed.len(), 64);
     }
 
+    #[test]
+    fn test_mnemonic_to_seed_with_passphrase() {
+        // Trezor 测试向量: 全零熵 + 密码 "TREZOR"
+        let mnemonic = Mnemonic::from_entropy(&[0u8; 32]).unwrap();
+        assert_eq!(
+            hex::encode(mnemonic.to_seed("TREZOR")),
+            "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8"
+        );
+
+        // 非 ASCII 密码需先做 NFKD 规范化，结果与 bip39 crate 一致
+        let phrase = mnemonic.to_string();
+        let reference = bip39::Mnemonic::parse(&phrase).unwrap();
+        for passphrase in ["ｐａｓｓｗｏｒｄ", "Ünïcödé", "密码"] {
+            assert_eq!(
+                mnemonic.to_seed(passphrase),
+                reference.to_seed(passphrase),
+                "passphrase {:?}",
+                passphrase
+            );
+        }
+    }
+
     /// 测试 BIP39 标准测试向量
     /// 来自: https://github.com/trezor/python-mnemonic/blob/master/vectors.json
     #[test]
@@ -250,6 +411,72 @@ mod tests {
         assert!(mnemonic2.validate_checksum(), "Vector 2 checksum failed");
     }
 
+    /// 测试 12/15/18/21 词助记词 (128-224 位熵)，与 Trezor 测试向量及 bip39 crate 一致
+    #[test]
+    fn test_shorter_mnemonics() {
+        let mnemonic = Mnemonic::from_entropy(&[0u8; 16]).unwrap();
+        assert_eq!(
+            mnemonic.to_string(),
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+        let mnemonic = Mnemonic::from_entropy(&[0x7f; 24]).unwrap();
+        assert_eq!(
+            mnemonic.to_string(),
+            "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal will"
+        );
+
+        for word_count in SUPPORTED_WORD_COUNTS {
+            let entropy = vec![0xa5u8; entropy_len_for_words(word_count).unwrap()];
+            let mnemonic = Mnemonic::from_entropy(&entropy).unwrap();
+            assert_eq!(mnemonic.word_count(), word_count);
+            assert_eq!(mnemonic.to_entropy(), (entropy.clone(), true));
+
+            let reference = bip39::Mnemonic::from_entropy(&entropy).unwrap();
+            assert_eq!(mnemonic.to_string(), reference.to_string());
+            assert_eq!(mnemonic.to_seed("TREZOR"), reference.to_seed("TREZOR"));
+        }
+
+        assert!(Mnemonic::from_entropy(&[0u8; 17]).is_err());
+        assert!(entropy_len_for_words(13).is_err());
+    }
+
+    /// 非英文词表与 bip39 crate 一致 (单词、NFKD 规范化后的种子)
+    #[test]
+    fn test_other_languages() {
+        let entropy = [0x5au8; 16];
+        for language in Language::ALL {
+            let reference_language = match language {
+                Language::English => bip39::Language::English,
+                Language::ChineseSimplified => bip39::Language::SimplifiedChinese,
+                Language::ChineseTraditional => bip39::Language::TraditionalChinese,
+                Language::Japanese => bip39::Language::Japanese,
+                Language::Korean => bip39::Language::Korean,
+                Language::Spanish => bip39::Language::Spanish,
+                Language::French => bip39::Language::French,
+                Language::Italian => bip39::Language::Italian,
+                Language::Czech => bip39::Language::Czech,
+                Language::Portuguese => bip39::Language::Portuguese,
+            };
+            let mnemonic = Mnemonic::from_entropy_in(language, &entropy).unwrap();
+            let reference = bip39::Mnemonic::from_entropy_in(reference_language, &entropy).unwrap();
+            let words: Vec<&str> = reference.words().collect();
+            assert_eq!(mnemonic.to_string(), words.join(language.separator()));
+            assert_eq!(mnemonic.to_seed("TREZOR"), reference.to_seed("TREZOR"));
+
+            let parsed = Mnemonic::from_string_in(language, &mnemonic.to_string()).unwrap();
+            assert_eq!(parsed.words, mnemonic.words);
+        }
+
+        // 日文助记词用全角空格分隔，自动识别语言
+        let mnemonic = Mnemonic::from_entropy_in(Language::Japanese, &[0u8; 16]).unwrap();
+        let phrase = mnemonic.to_string();
+        assert_eq!(phrase.split('\u{3000}').count(), 12);
+        let parsed = Mnemonic::from_string(&phrase).unwrap();
+        assert_eq!(parsed.language, Language::Japanese);
+        assert!(parsed.validate_checksum());
+        assert_eq!(Language::Japanese.find_word("あいこくしん"), Some(0));
+    }
+
     #[test]
     fn test_roundtrip() {
         // 生成 -> 字符串 -> 解析 -> 验证
@@ -259,5 +486,14 @@ mod tests {
 
         assert_eq!(original.words, parsed.words);
         assert!(parsed.validate_checksum());
+
+        let original = Mnemonic::generate_random_with_words(12).unwrap();
+        let parsed = Mnemonic::from_string(&original.to_string()).unwrap();
+        assert_eq!(parsed.word_count(), 12);
+        assert_eq!(original.words, parsed.words);
+        assert!(parsed.validate_checksum());
+
+        // 单词数不受支持
+        assert!(Mnemonic::from_string("abandon abandon abandon").is_err());
     }
 }
@@ -0,0 +1,83 @@
+//! Nostr 密钥 (NIP-19 / NIP-06)
+//!
+//! Nostr 公钥是 32 字节的 x-only secp256k1 公钥 (即公钥点的 x 坐标)，显示为 bech32 的 `npub1...`，
+//! 私钥显示为 `nsec1...`。GPU 跳过 Keccak，直接用 x 坐标的前 20 字节匹配 `npub1` 之后的前缀字符，
+//! 每个字符固定 5 位，前缀换算为位掩码交给内核已有的模式匹配。
+
+use secp256k1::PublicKey;
+
+use crate::bech32::{self, Variant};
+
+/// 公钥的 bech32 人类可读部分
+pub const NPUB_HRP: &str = "npub";
+/// 私钥的 bech32 人类可读部分
+pub const NSEC_HRP: &str = "nsec";
+
+/// NIP-06 默认派生路径 (SLIP-44 币种 1237)
+pub const DEFAULT_NOSTR_DERIVATION_PATH: &str = "m/44'/1237'/0'/0/0";
+
+/// 公钥的 x-only 形式 (x 坐标，大端序)
+pub fn x_only_public_key(public_key: &PublicKey) -> [u8; 32] {
+    public_key.x_only_public_key().0.serialize()
+}
+
+/// x-only 公钥的 npub 编码
+pub fn npub(x_only: &[u8; 32]) -> String {
+    bech32::encode(NPUB_HRP, &bech32::to_base32(x_only), Variant::Bech32)
+}
+
+/// 私钥的 nsec 编码
+pub fn nsec(private_key: &[u8; 32]) -> String {
+    bech32::encode(NSEC_HRP, &bech32::to_base32(private_key), Variant::Bech32)
+}
+
+/// 将 npub 前缀 (如 npub1xyz) 换算为 x 坐标前 20 字节上的位掩码和期望值
+///
+/// # Errors
+/// 不以 `npub1` 开头、大小写混用、超过 32 个数据字符或含有非 bech32 字符时返回错误
+pub fn npub_prefix_mask(prefix: &str) -> anyhow::Result<([u8; 20], [u8; 20])> {
+    bech32::address_prefix_mask(prefix, "npub1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nip19_vectors() {
+        let x_only: [u8; 32] =
+            hex::decode("7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            npub(&x_only),
+            "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg"
+        );
+        let private_key: [u8; 32] =
+            hex::decode("67dea2ed018072d675f5415ecfaed7d2597555e202d85b3d65ea4e58d2d92ffa")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(
+            nsec(&private_key),
+            "nsec1vl029mgpspedva04g90vltkh6fvh240zqtv9k0t9af8935ke9laqsnlfe5"
+        );
+    }
+
+    #[test]
+    fn test_npub_prefix_mask() {
+        let x_only = [0xa5u8; 32];
+        let encoded = npub(&x_only);
+        let (mask, value) = npub_prefix_mask(&encoded[..20]).unwrap();
+        for i in 0..20 {
+            assert_eq!(x_only[i] & mask[i], value[i]);
+        }
+        // 15 个数据字符固定前 75 位
+        assert_eq!(&mask[..9], &[0xff; 9]);
+        assert_eq!(mask[9], 0xe0);
+
+        assert!(npub_prefix_mask("nsec1xyz").is_err());
+        assert!(npub_prefix_mask("npub1bad").is_err());
+    }
+}
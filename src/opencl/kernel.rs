@@ -2,10 +2,10 @@
 
 use log::{debug, info};
 use ocl::enums::{ProgramBuildInfo, ProgramBuildInfoResult};
-use ocl::{Buffer, Event, Kernel, Program, SpatialDims};
+use ocl::{Buffer, Event, Kernel, Program, Queue, SpatialDims};
 
 use super::context::OpenCLContext;
-use crate::config::{SearchConfig, SearchResult};
+use crate::config::{MAX_TARGETS, RESULT_RING_CAPACITY, SearchConfig, SearchResult, SearchTarget};
 
 /// 搜索内核封装
 pub struct SearchKernel {
@@ -16,8 +16,17 @@ pub struct SearchKernel {
     kernel: Kernel,
     /// 配置缓冲区
     config_buffer: Buffer<u8>,
-    /// 结果缓冲区
-    result_buffer: Buffer<u8>,
+    /// 目标表缓冲区
+    targets_buffer: Buffer<u8>,
+    /// 结果环形缓冲区 (RESULT_RING_CAPACITY 个槽位)
+    results_buffer: Buffer<u8>,
+    /// 已领取结果槽位数 (原子计数器)
+    result_count_buffer: Buffer<u32>,
+    /// 评分模式下的全局最高分
+    best_score_buffer: Buffer<u32>,
+    /// 监视队列: 内核运行期间读取标志/结果、请求停止
+    /// (主队列是顺序队列，排在内核之后的命令要等内核结束才会执行)
+    monitor_queue: Queue,
     /// 全局标志缓冲区
     flag_buffer: Buffer<i32>,
     /// 每线程最终检查次数缓冲区
@@ -88,12 +97,32 @@ impl SearchKernel {
             .len(std::mem::size_of::<SearchConfig>())
             .build()?;
 
-        let result_buffer = Buffer::<u8>::builder()
+        let targets_buffer = Buffer::<u8>::builder()
             .queue(ctx.queue.clone())
-            .flags(ocl::flags::MEM_WRITE_ONLY)
-            .len(std::mem::size_of::<SearchResult>())
+            .flags(ocl::flags::MEM_READ_ONLY)
+            .len(MAX_TARGETS * std::mem::size_of::<SearchTarget>())
+            .build()?;
+
+        let results_buffer = Buffer::<u8>::builder()
+            .queue(ctx.queue.clone())
+            .flags(ocl::flags::MEM_READ_WRITE)
+            .len(RESULT_RING_CAPACITY * std::mem::size_of::<SearchResult>())
+            .build()?;
+
+        let result_count_buffer = Buffer::<u32>::builder()
+            .queue(ctx.queue.clone())
+            .flags(ocl::flags::MEM_READ_WRITE)
+            .len(1)
             .build()?;
 
+        let best_score_buffer = Buffer::<u32>::builder()
+            .queue(ctx.queue.clone())
+            .flags(ocl::flags::MEM_READ_WRITE)
+            .len(1)
+            .build()?;
+
+        let monitor_queue = Queue::new(&ctx.context, ctx.device, None)?;
+
         let flag_buffer = Buffer::<i32>::builder()
             .queue(ctx.queue.clone())
             .flags(ocl::flags::MEM_READ_WRITE)
@@ -117,7 +146,10 @@ impl SearchKernel {
             .queue(ctx.queue.clone())
             .global_work_size(SpatialDims::One(1)) // 临时值，会在 launch 中更新
             .arg(&config_buffer)
-            .arg(&result_buffer)
+            .arg(&targets_buffer)
+            .arg(&results_buffer)
+            .arg(&result_count_buffer)
+            .arg(&best_score_buffer)
             .arg(&flag_buffer)
             .arg(&thread_checked_buffer)
             .build()
@@ -137,7 +169,11 @@ impl SearchKernel {
             program,
             kernel,
             config_buffer,
-            result_buffer,
+            targets_buffer,
+            results_buffer,
+            result_count_buffer,
+            best_score_buffer,
+            monitor_queue,
             flag_buffer,
             thread_checked_buffer,
             thread_checked_len,
@@ -161,6 +197,35 @@ impl SearchKernel {
         Ok(())
     }
 
+    /// 上传目标表
+    ///
+    /// 目标数量需同时通过 `SearchConfig::with_target_count` 告知内核。
+    pub fn set_targets(&self, targets: &[SearchTarget]) -> anyhow::Result<()> {
+        if targets.is_empty() || targets.len() > MAX_TARGETS {
+            anyhow::bail!(
+                "Target count must be between 1 and {}, got {}",
+                MAX_TARGETS,
+                targets.len()
+            );
+        }
+
+        let targets_bytes = unsafe {
+            std::slice::from_raw_parts(
+                targets.as_ptr() as *const u8,
+                std::mem::size_of_val(targets),
+            )
+        };
+
+        self.targets_buffer
+            .cmd()
+            .offset(0)
+            .write(targets_bytes)
+            .enq()?;
+        debug!("{} search target(s) uploaded to GPU", targets.len());
+
+        Ok(())
+    }
+
     /// 启动内核
     ///
     /// # Arguments
@@ -180,6 +245,12 @@ impl SearchKernel {
         let zero_counts = vec![0u64; self.thread_checked_len];
         self.thread_checked_buffer.write(&zero_counts).enq()?;
 
+        // 清空结果环形缓冲区和槽位计数器 (槽位序号为 0 表示未写入)
+        let zero_results = vec![0u8; RESULT_RING_CAPACITY * std::mem::size_of::<SearchResult>()];
+        self.results_buffer.write(&zero_results).enq()?;
+        self.result_count_buffer.write(&vec![0u32]).enq()?;
+        self.best_score_buffer.write(&vec![0u32]).enq()?;
+
         unsafe {
             self.kernel.cmd().global_work_size(gws).enq()?;
         }
@@ -192,7 +263,12 @@ impl SearchKernel {
     /// 将全局 found 标志置为 1，内核会在下一次检查该标志时尽快退出。
     pub fn request_stop(&self) -> anyhow::Result<()> {
         let stop_flag = vec![1i32];
-        self.flag_buffer.write(&stop_flag).enq()?;
+        self.flag_buffer
+            .cmd()
+            .queue(&self.monitor_queue)
+            .write(&stop_flag)
+            .enq()?;
+        self.monitor_queue.finish()?;
         Ok(())
     }
 
@@ -214,6 +290,7 @@ impl SearchKernel {
             unsafe {
                 self.flag_buffer
                     .cmd()
+                    .queue(&self.monitor_queue)
                     .read(&mut self.flag_read_buf)
                     .block(false)
                     .enew(&mut evt)
@@ -223,25 +300,66 @@ impl SearchKernel {
             return Ok(None);
         }
 
-        if let Some(ref evt) = self.flag_read_event {
-            if evt.is_complete()? {
-                let found = self.flag_read_buf[0] != 0;
-                self.flag_read_event = None;
-                return Ok(Some(found));
-            }
+        if let Some(ref evt) = self.flag_read_event
+            && evt.is_complete()?
+        {
+            let found = self.flag_read_buf[0] != 0;
+            self.flag_read_event = None;
+            return Ok(Some(found));
         }
 
         Ok(None)
     }
 
-    /// 读取搜索结果
-    pub fn read_result(&self) -> anyhow::Result<SearchResult> {
-        let mut result_bytes = vec![0u8; std::mem::size_of::<SearchResult>()];
-        self.result_buffer.read(&mut result_bytes).enq()?;
+    /// 读取已领取的结果槽位数 (可能超过 max_results，超出部分内核不会写入)
+    pub fn read_result_count(&self) -> anyhow::Result<u32> {
+        let mut count = vec![0u32];
+        self.result_count_buffer
+            .cmd()
+            .queue(&self.monitor_queue)
+            .read(&mut count)
+            .enq()?;
+        Ok(count[0])
+    }
 
-        let result = unsafe { std::ptr::read(result_bytes.as_ptr() as *const SearchResult) };
+    /// 读取序号 [start, end) 范围内已写完的结果
+    ///
+    /// 按序号顺序返回，遇到尚未写完的槽位即停止，调用方下次从
+    /// `start + 返回数量` 继续读取。范围不能超过一圈 (RESULT_RING_CAPACITY)。
+    pub fn read_results(&self, start: u32, end: u32) -> anyhow::Result<Vec<SearchResult>> {
+        if end <= start {
+            return Ok(Vec::new());
+        }
+        if end - start > RESULT_RING_CAPACITY as u32 {
+            anyhow::bail!(
+                "Result range {}..{} exceeds ring capacity {}",
+                start,
+                end,
+                RESULT_RING_CAPACITY
+            );
+        }
+
+        let result_size = std::mem::size_of::<SearchResult>();
+        let mut ring_bytes = vec![0u8; RESULT_RING_CAPACITY * result_size];
+        self.results_buffer
+            .cmd()
+            .queue(&self.monitor_queue)
+            .read(&mut ring_bytes)
+            .enq()?;
+
+        let mut results = Vec::new();
+        for seq in start..end {
+            let offset = (seq as usize % RESULT_RING_CAPACITY) * result_size;
+            let result = unsafe {
+                std::ptr::read_unaligned(ring_bytes[offset..].as_ptr() as *const SearchResult)
+            };
+            if result.found as u32 != seq.wrapping_add(1) {
+                break;
+            }
+            results.push(result);
+        }
 
-        Ok(result)
+        Ok(results)
     }
 
     /// 等待内核完成
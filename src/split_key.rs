@@ -0,0 +1,99 @@
+//! 分离密钥 (split-key) 模式的主机端辅助函数
+//!
+//! 用户只把公钥 P 交给不可信的 GPU 机器，GPU 搜索偏移量 k 使 P + k·G 的地址满足条件，并只返回 k。
+//! 用户在本地离线把自己的私钥 s 与 k 合并为最终私钥 (s + k) mod n，私钥始终不离开本机。
+
+use secp256k1::{PublicKey, SECP256K1, Scalar, SecretKey};
+
+/// 解析分离密钥模式的用户公钥
+///
+/// 支持压缩 (33 字节, 02/03 开头) 和未压缩 (65 字节, 04 开头) 格式，可带 0x 前缀。
+/// 返回去掉 0x04 前缀后的 64 字节 (x||y, 大端序)。
+pub fn parse_public_key(public_key: &str) -> anyhow::Result<[u8; 64]> {
+    let hex_str = public_key
+        .strip_prefix("0x")
+        .or_else(|| public_key.strip_prefix("0X"))
+        .unwrap_or(public_key);
+    let bytes = hex::decode(hex_str)?;
+    let public_key = PublicKey::from_slice(&bytes)
+        .map_err(|e| anyhow::anyhow!("Invalid secp256k1 public key: {}", e))?;
+
+    let mut result = [0u8; 64];
+    result.copy_from_slice(&public_key.serialize_uncompressed()[1..]);
+    Ok(result)
+}
+
+/// 合并分离密钥: 最终私钥 = (secret + offset) mod n
+///
+/// secret 为公钥 P 对应的私钥 (只在用户本地使用)，offset 为 GPU 找到的偏移量 k。
+/// 最终私钥对应的地址即为 P + k*G 的地址。
+pub fn combine_split_key(secret: &[u8; 32], offset: &[u8; 32]) -> anyhow::Result<[u8; 32]> {
+    let secret_key =
+        SecretKey::from_slice(secret).map_err(|e| anyhow::anyhow!("Invalid secret key: {}", e))?;
+    let tweak = Scalar::from_be_bytes(*offset)
+        .map_err(|_| anyhow::anyhow!("Offset is out of range for secp256k1"))?;
+    let combined = secret_key
+        .add_tweak(&tweak)
+        .map_err(|e| anyhow::anyhow!("Combined key is invalid: {}", e))?;
+    Ok(combined.secret_bytes())
+}
+
+/// 由私钥计算以太坊地址 (主机端，用于校验和显示)
+pub fn eth_address_from_private_key(private_key: &[u8; 32]) -> anyhow::Result<[u8; 20]> {
+    use sha3::{Digest, Keccak256};
+
+    let secret_key = SecretKey::from_slice(private_key)
+        .map_err(|e| anyhow::anyhow!("Invalid secret key: {}", e))?;
+    let public_key = PublicKey::from_secret_key(SECP256K1, &secret_key);
+    let hash = Keccak256::digest(&public_key.serialize_uncompressed()[1..]);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    Ok(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_key_combine() {
+        // 用户私钥 s 与 GPU 偏移量 k 合并后，其公钥应等于 P + k*G
+        let mut secret = [0u8; 32];
+        secret[31] = 7;
+        let mut offset = [0u8; 32];
+        offset[31] = 5;
+
+        let combined = combine_split_key(&secret, &offset).unwrap();
+        let mut expected = [0u8; 32];
+        expected[31] = 12;
+        assert_eq!(combined, expected);
+
+        let public_key = SecretKey::from_slice(&secret)
+            .unwrap()
+            .public_key(SECP256K1);
+        let parsed = parse_public_key(&hex::encode(public_key.serialize())).unwrap();
+        assert_eq!(parsed[..], public_key.serialize_uncompressed()[1..]);
+        let parsed_uncompressed = parse_public_key(&format!(
+            "0x{}",
+            hex::encode(public_key.serialize_uncompressed())
+        ))
+        .unwrap();
+        assert_eq!(parsed, parsed_uncompressed);
+
+        let offset_point = SecretKey::from_slice(&offset)
+            .unwrap()
+            .public_key(SECP256K1);
+        let sum = public_key.combine(&offset_point).unwrap();
+        let combined_point = SecretKey::from_slice(&combined)
+            .unwrap()
+            .public_key(SECP256K1);
+        assert_eq!(sum, combined_point);
+
+        assert_eq!(
+            eth_address_from_private_key(&expected).unwrap(),
+            eth_address_from_private_key(&combined).unwrap()
+        );
+        assert!(parse_public_key("0x1234").is_err());
+    }
+}
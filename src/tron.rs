@@ -0,0 +1,44 @@
+//! 波场 (Tron) 地址
+//!
+//! 波场与以太坊使用相同的 secp256k1 + Keccak-256 派生，地址同样是公钥哈希的后 20 字节，
+//! 只是渲染为 `Base58Check(0x41 || 地址)` (以 T 开头)。GPU 沿用以太坊的地址计算，
+//! 地址前缀按 0x41 版本字节换算为 20 字节地址上的数值范围，见 `bitcoin::base58_prefix_ranges`。
+
+use crate::bitcoin::p2pkh_address;
+
+/// 波场地址的版本字节
+pub const TRON_ADDRESS_VERSION: u8 = 0x41;
+
+/// 波场默认派生路径 (SLIP-44 币种 195)
+pub const DEFAULT_TRON_DERIVATION_PATH: &str = "m/44'/195'/0'/0/0";
+
+/// 20 字节地址对应的波场 T 地址
+pub fn tron_address(address: &[u8; 20]) -> String {
+    p2pkh_address(TRON_ADDRESS_VERSION, address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitcoin::base58_prefix_ranges;
+    use crate::split_key::eth_address_from_private_key;
+
+    #[test]
+    fn test_tron_address() {
+        let mut private_key = [0u8; 32];
+        private_key[31] = 1;
+        let address = eth_address_from_private_key(&private_key).unwrap();
+        assert_eq!(tron_address(&address), "TMVQGm1qAQYVdetCeGRRkTWYYrLXuHK2HC");
+    }
+
+    #[test]
+    fn test_tron_prefix_ranges() {
+        let ranges = base58_prefix_ranges("TRX", TRON_ADDRESS_VERSION).unwrap();
+        assert!(!ranges.is_empty());
+        for (low, _) in ranges {
+            assert!(tron_address(&low).starts_with("TRX"));
+        }
+        // 波场地址都以 T 开头
+        assert!(base58_prefix_ranges("1", TRON_ADDRESS_VERSION).is_err());
+    }
+}
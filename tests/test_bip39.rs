@@ -292,10 +292,24 @@ mod tests {
             ("sha512", vec!["sha512"]),
             ("sha512+pbkdf2", vec!["sha512", "pbkdf2"]),
             ("...+sha256", vec!["sha512", "pbkdf2", "sha256"]),
-            ("...+keccak", vec!["sha512", "pbkdf2", "sha256", "keccak"]),
+            (
+                "...+ripemd160",
+                vec!["sha512", "pbkdf2", "sha256", "ripemd160"],
+            ),
+            (
+                "...+keccak",
+                vec!["sha512", "pbkdf2", "sha256", "ripemd160", "keccak"],
+            ),
             (
                 "...+secp256k1",
-                vec!["sha512", "pbkdf2", "sha256", "keccak", "secp256k1"],
+                vec![
+                    "sha512",
+                    "pbkdf2",
+                    "sha256",
+                    "ripemd160",
+                    "keccak",
+                    "secp256k1",
+                ],
             ),
         ];
 
@@ -331,7 +345,7 @@ fn test_entropy_to_mnemonic_roundtrip() {
         let (recovered_entropy, valid) = mnemonic.to_entropy();
 
         assert!(valid, "校验和应该有效");
-        assert_eq!(entropy, recovered_entropy, "熵应该匹配");
+        assert_eq!(recovered_entropy, entropy, "熵应该匹配");
     }
 }
 
@@ -352,7 +366,7 @@ fn test_gpu_address_matches_rust() {
 
     // 1. 生成种子 (BIP39)
     let seed = mnemonic.to_seed("");
-    println!("种子: {}", hex::encode(&seed));
+    println!("种子: {}", hex::encode(seed));
 
     // 2. 生成主密钥 (BIP32)
     let mut mac = Hmac::<Sha512>::new_from_slice(b"Bitcoin seed").unwrap();
@@ -423,7 +437,7 @@ fn test_gpu_address_matches_rust() {
             "路径 {} ({}): 派生后私钥 = {}",
             i,
             path_names[i],
-            hex::encode(&child_private)
+            hex::encode(child_private)
         );
     }
 
@@ -436,7 +450,7 @@ fn test_gpu_address_matches_rust() {
     let public_key = PublicKey::from_secret_key(&secp, &secret_key);
 
     let uncompressed = public_key.serialize_uncompressed();
-    println!("未压缩公钥 (65字节): {}", hex::encode(&uncompressed));
+    println!("未压缩公钥 (65字节): {}", hex::encode(uncompressed));
     println!("公钥X坐标 (32字节): {}", hex::encode(&uncompressed[1..33]));
     println!("公钥Y坐标 (32字节): {}", hex::encode(&uncompressed[33..65]));
 
@@ -444,7 +458,7 @@ fn test_gpu_address_matches_rust() {
     let mut hasher = Keccak256::new();
     hasher.update(&uncompressed[1..]); // 只哈希64字节 (X + Y)
     let hash = hasher.finalize();
-    println!("Keccak-256哈希 (32字节): {}", hex::encode(&hash));
+    println!("Keccak-256哈希 (32字节): {}", hex::encode(hash));
 
     // 取后20字节作为地址
     let address = &hash[12..];
@@ -487,7 +501,7 @@ fn test_detailed_address_generation() {
     let (entropy, valid) = mnemonic.to_entropy();
 
     println!("1. 助记词校验和: {}", valid);
-    println!("   熵 (32字节): {}", hex::encode(&entropy));
+    println!("   熵 (32字节): {}", hex::encode(entropy));
 
     // 2. 生成种子 (BIP39)
     let mnemonic_bytes = mnemonic_str.as_bytes();
@@ -496,7 +510,7 @@ fn test_detailed_address_generation() {
 
     let mut seed = [0u8; 64];
     pbkdf2_hmac::<Sha512>(mnemonic_bytes, b"mnemonic", 2048, &mut seed);
-    println!("   种子 (64字节): {}", hex::encode(&seed));
+    println!("   种子 (64字节): {}", hex::encode(seed));
 
     // 3. 生成主密钥 (BIP32)
     println!("\n3. BIP32 主密钥生成:");
@@ -516,7 +530,7 @@ fn test_detailed_address_generation() {
     // 使用bip32库
     let bip32_mnemonic = bip39::Mnemonic::parse_in(bip39::Language::English, mnemonic_str).unwrap();
     let bip32_seed = bip32_mnemonic.to_seed("");
-    let xprv = bip32::XPrv::new(&bip32_seed).unwrap();
+    let xprv = bip32::XPrv::new(bip32_seed).unwrap();
 
     // 派生路径 m/44'/60'/0'/0/0
     let child_xprv = xprv
@@ -532,7 +546,7 @@ fn test_detailed_address_generation() {
         .unwrap();
 
     let final_private_key = child_xprv.private_key().to_bytes();
-    println!("   最终私钥 (32字节): {}", hex::encode(&final_private_key));
+    println!("   最终私钥 (32字节): {}", hex::encode(final_private_key));
 
     // 5. 生成公钥
     println!("\n5. 公钥生成 (secp256k1):");
@@ -541,7 +555,7 @@ fn test_detailed_address_generation() {
     let public_key = PublicKey::from_secret_key(&secp, &secret_key);
 
     let uncompressed = public_key.serialize_uncompressed();
-    println!("   未压缩公钥 (65字节): {}", hex::encode(&uncompressed));
+    println!("   未压缩公钥 (65字节): {}", hex::encode(uncompressed));
     println!("   X坐标 (32字节): {}", hex::encode(&uncompressed[1..33]));
     println!("   Y坐标 (32字节): {}", hex::encode(&uncompressed[33..65]));
 
@@ -551,7 +565,7 @@ fn test_detailed_address_generation() {
     hasher.update(&uncompressed[1..]); // 跳过0x04前缀
     let hash = hasher.finalize();
 
-    println!("   Keccak哈希 (32字节): {}", hex::encode(&hash));
+    println!("   Keccak哈希 (32字节): {}", hex::encode(hash));
 
     let address = &hash[12..];
     println!("   以太坊地址 (20字节): 0x{}", hex::encode(address));
@@ -566,7 +580,7 @@ fn test_detailed_address_generation() {
 #[test]
 fn test_opencl_address_matches_rust() {
     use ocl::{Buffer, MemFlags, ProQue};
-    use rust_profanity::{load_kernel_source, mnemonic::Mnemonic};
+    use rust_profanity::{SearchConfig, load_kernel_source, mnemonic::Mnemonic};
 
     // 使用与Rust测试相同的助记词
     let mnemonic_str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
@@ -586,6 +600,7 @@ fn test_opencl_address_matches_rust() {
 // 测试内核: 从熵生成地址
 __kernel void test_address_from_entropy(
     __constant uchar* entropy,
+    __constant search_config_t* config,
     __global uchar* address_out
 ) {
     uchar address[20];
@@ -594,7 +609,8 @@ __kernel void test_address_from_entropy(
     for (int i = 0; i < 32; i++) {
         local_entropy[i] = entropy[i];
     }
-    derive_address_from_entropy(local_entropy, address);
+    derive_address_from_entropy(local_entropy, 32, config->passphrase, config->passphrase_len,
+                                config->derivation_path, config->derivation_path_len, address);
     
     for (int i = 0; i < 20; i++) {
         address_out[i] = address[i];
@@ -626,6 +642,9 @@ __kernel void smoke_kernel(__global uchar* out) {
         .build()
         .expect("创建熵缓冲区失败");
 
+    // 输入缓冲区: 默认搜索配置 (无密码，派生路径 m/44'/60'/0'/0/0)
+    let config_buffer = config_buffer(&proque, &SearchConfig::new([0; 32], 1));
+
     // 输出缓冲区: 地址 (20字节)
     let address_buffer = Buffer::<u8>::builder()
         .queue(proque.queue().clone())
@@ -654,6 +673,7 @@ __kernel void smoke_kernel(__global uchar* out) {
     let kernel = match proque
         .kernel_builder("test_address_from_entropy")
         .arg(&entropy_buffer)
+        .arg(&config_buffer)
         .arg(&address_buffer)
         .build()
     {
@@ -709,6 +729,710 @@ __kernel void smoke_kernel(__global uchar* out) {
     println!("✓ OpenCL与Rust地址生成一致!");
 }
 
+/// 将搜索配置上传为 `__constant search_config_t*` 内核参数
+fn config_buffer(proque: &ProQue, config: &rust_profanity::SearchConfig) -> Buffer<u8> {
+    let bytes = unsafe {
+        std::slice::from_raw_parts(
+            config as *const _ as *const u8,
+            std::mem::size_of::<rust_profanity::SearchConfig>(),
+        )
+    };
+    Buffer::<u8>::builder()
+        .queue(proque.queue().clone())
+        .flags(MemFlags::READ_ONLY)
+        .len(bytes.len())
+        .copy_host_slice(bytes)
+        .build()
+        .expect("创建配置缓冲区失败")
+}
+
+/// 测试带 BIP39 密码和自定义派生路径时 OpenCL 生成的地址与 bip39/bip32 crate 一致
+#[test]
+fn test_opencl_address_with_passphrase_and_path_matches_rust() {
+    use rust_profanity::{SearchConfig, load_kernel_source, parse_derivation_path};
+
+    let mut source = load_kernel_source().expect("加载内核源代码失败");
+    source.push_str(
+        r#"
+__kernel void test_address_with_config(
+    __constant uchar* entropy,
+    __constant search_config_t* config,
+    __global uchar* address_out
+) {
+    uchar local_entropy[32];
+    for (int i = 0; i < 32; i++) {
+        local_entropy[i] = entropy[i];
+    }
+    uchar address[20];
+    derive_address_from_entropy(local_entropy, 32, config->passphrase, config->passphrase_len,
+                                config->derivation_path, config->derivation_path_len, address);
+    for (int i = 0; i < 20; i++) {
+        address_out[i] = address[i];
+    }
+}
+"#,
+    );
+
+    let proque = match ProQue::builder().src(&source).dims(1).build() {
+        Ok(p) => p,
+        Err(e) => {
+            println!("OpenCL 不可用，跳过测试: {}", e);
+            return;
+        }
+    };
+
+    let entropy = [0x5au8; 32];
+    let phrase = rust_profanity::mnemonic::Mnemonic::from_entropy(&entropy)
+        .unwrap()
+        .to_string();
+    let reference = bip39::Mnemonic::parse_in(Language::English, &phrase).unwrap();
+
+    // 包含需要 NFKD 规范化的全角字符、最大长度密码和 Ledger Live 风格的账户路径
+    let long_passphrase = "x".repeat(rust_profanity::MAX_PASSPHRASE_BYTES);
+    let cases = [
+        ("TREZOR", "m/44'/60'/0'/0/0"),
+        ("ｐａｓｓ 密码", "m/44'/60'/0'/0/0"),
+        (long_passphrase.as_str(), "m/44'/60'/0'/0/0"),
+        ("", "m/44'/60'/7'/0/0"),
+        ("TREZOR", "m/44'/60'/0'/0/12"),
+        ("", "m/0/1'/2/3'/4/5'/6/7'/8/9"),
+    ];
+    for (passphrase, path) in cases {
+        let config = SearchConfig::new([0; 32], 1)
+            .with_passphrase(passphrase)
+            .unwrap()
+            .with_derivation_path(&parse_derivation_path(path).unwrap())
+            .unwrap();
+
+        let entropy_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(32)
+            .copy_host_slice(&entropy)
+            .build()
+            .unwrap();
+        let config_buffer = config_buffer(&proque, &config);
+        let address_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(20)
+            .build()
+            .unwrap();
+        let kernel = proque
+            .kernel_builder("test_address_with_config")
+            .arg(&entropy_buffer)
+            .arg(&config_buffer)
+            .arg(&address_buffer)
+            .build()
+            .unwrap();
+        unsafe {
+            kernel.enq().unwrap();
+        }
+        let mut cl_address = vec![0u8; 20];
+        address_buffer.read(&mut cl_address).enq().unwrap();
+
+        // bip39 + bip32 参考实现
+        let seed = reference.to_seed(passphrase);
+        let xprv = bip32::XPrv::derive_from_path(seed, &path.parse().unwrap()).unwrap();
+        let secret_key = secp256k1::SecretKey::from_slice(&xprv.private_key().to_bytes()).unwrap();
+        let public_key =
+            secp256k1::PublicKey::from_secret_key(&secp256k1::Secp256k1::new(), &secret_key);
+        let hash =
+            <sha3::Keccak256 as sha3::Digest>::digest(&public_key.serialize_uncompressed()[1..]);
+
+        assert_eq!(
+            hex::encode(&cl_address),
+            hex::encode(&hash[12..]),
+            "passphrase {:?}, path {}",
+            passphrase,
+            path
+        );
+    }
+}
+
+/// 测试 12/15/18/21/24 词助记词时 OpenCL 生成的地址与 bip39/bip32 crate 一致
+#[test]
+fn test_opencl_word_counts_match_rust() {
+    use rust_profanity::mnemonic::{SUPPORTED_WORD_COUNTS, entropy_len_for_words};
+    use rust_profanity::{SearchConfig, load_kernel_source};
+
+    let mut source = load_kernel_source().expect("加载内核源代码失败");
+    source.push_str(
+        r#"
+// 熵位于种子末尾 entropy_len 字节 (与 search_kernel 一致)
+__kernel void test_address_from_seed(
+    __constant uchar* seed,
+    __constant search_config_t* config,
+    __global uchar* address_out
+) {
+    uchar local_seed[32];
+    for (int i = 0; i < 32; i++) {
+        local_seed[i] = seed[i];
+    }
+    uchar address[20];
+    derive_address_from_entropy(local_seed + (32 - config->entropy_len), config->entropy_len,
+                                config->passphrase, config->passphrase_len,
+                                config->derivation_path, config->derivation_path_len, address);
+    for (int i = 0; i < 20; i++) {
+        address_out[i] = address[i];
+    }
+}
+"#,
+    );
+
+    let proque = match ProQue::builder().src(&source).dims(1).build() {
+        Ok(p) => p,
+        Err(e) => {
+            println!("OpenCL 不可用，跳过测试: {}", e);
+            return;
+        }
+    };
+
+    for word_count in SUPPORTED_WORD_COUNTS {
+        let entropy_len = entropy_len_for_words(word_count).unwrap();
+        let mut seed = [0u8; 32];
+        for (i, byte) in seed[32 - entropy_len..].iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(37).wrapping_add(11);
+        }
+        let config = SearchConfig::new(seed, 1)
+            .with_word_count(word_count)
+            .unwrap();
+
+        let seed_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(32)
+            .copy_host_slice(&seed)
+            .build()
+            .unwrap();
+        let config_buffer = config_buffer(&proque, &config);
+        let address_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(20)
+            .build()
+            .unwrap();
+        let kernel = proque
+            .kernel_builder("test_address_from_seed")
+            .arg(&seed_buffer)
+            .arg(&config_buffer)
+            .arg(&address_buffer)
+            .build()
+            .unwrap();
+        unsafe {
+            kernel.enq().unwrap();
+        }
+        let mut cl_address = vec![0u8; 20];
+        address_buffer.read(&mut cl_address).enq().unwrap();
+
+        // bip39 + bip32 参考实现
+        let reference = bip39::Mnemonic::from_entropy(&seed[32 - entropy_len..]).unwrap();
+        assert_eq!(reference.word_count(), word_count);
+        let xprv = bip32::XPrv::derive_from_path(
+            reference.to_seed(""),
+            &"m/44'/60'/0'/0/0".parse().unwrap(),
+        )
+        .unwrap();
+        let secret_key = secp256k1::SecretKey::from_slice(&xprv.private_key().to_bytes()).unwrap();
+        let public_key =
+            secp256k1::PublicKey::from_secret_key(&secp256k1::Secp256k1::new(), &secret_key);
+        let hash =
+            <sha3::Keccak256 as sha3::Digest>::digest(&public_key.serialize_uncompressed()[1..]);
+
+        assert_eq!(
+            hex::encode(&cl_address),
+            hex::encode(&hash[12..]),
+            "{} words",
+            word_count
+        );
+    }
+}
+
+/// 测试非英文词表: 多字节 UTF-8 单词、超过 128 字节的助记词字符串 (HMAC 密钥先做哈希)
+#[test]
+fn test_opencl_languages_match_rust() {
+    use rust_profanity::{SearchConfig, load_kernel_source_for_language};
+
+    let languages = [
+        (rust_profanity::Language::Japanese, Language::Japanese),
+        (rust_profanity::Language::Korean, Language::Korean),
+        (
+            rust_profanity::Language::ChineseSimplified,
+            Language::SimplifiedChinese,
+        ),
+        (rust_profanity::Language::Spanish, Language::Spanish),
+    ];
+    for (language, reference_language) in languages {
+        let mut source = load_kernel_source_for_language(language).expect("加载内核源代码失败");
+        source.push_str(
+            r#"
+__kernel void test_address_from_seed(
+    __constant uchar* seed,
+    __constant search_config_t* config,
+    __global uchar* address_out
+) {
+    uchar local_seed[32];
+    for (int i = 0; i < 32; i++) {
+        local_seed[i] = seed[i];
+    }
+    uchar address[20];
+    derive_address_from_entropy(local_seed, 32, config->passphrase, config->passphrase_len,
+                                config->derivation_path, config->derivation_path_len, address);
+    for (int i = 0; i < 20; i++) {
+        address_out[i] = address[i];
+    }
+}
+"#,
+        );
+
+        let proque = match ProQue::builder().src(&source).dims(1).build() {
+            Ok(p) => p,
+            Err(e) => {
+                println!("OpenCL 不可用，跳过测试: {}", e);
+                return;
+            }
+        };
+
+        let mut seed = [0u8; 32];
+        for (i, byte) in seed.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_mul(73).wrapping_add(5);
+        }
+        let config = SearchConfig::new(seed, 1)
+            .with_passphrase("TREZOR")
+            .unwrap();
+
+        let seed_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(32)
+            .copy_host_slice(&seed)
+            .build()
+            .unwrap();
+        let config_buffer = config_buffer(&proque, &config);
+        let address_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(20)
+            .build()
+            .unwrap();
+        let kernel = proque
+            .kernel_builder("test_address_from_seed")
+            .arg(&seed_buffer)
+            .arg(&config_buffer)
+            .arg(&address_buffer)
+            .build()
+            .unwrap();
+        unsafe {
+            kernel.enq().unwrap();
+        }
+        let mut cl_address = vec![0u8; 20];
+        address_buffer.read(&mut cl_address).enq().unwrap();
+
+        let reference = Mnemonic::from_entropy_in(reference_language, &seed).unwrap();
+        let xprv = bip32::XPrv::derive_from_path(
+            reference.to_seed("TREZOR"),
+            &"m/44'/60'/0'/0/0".parse().unwrap(),
+        )
+        .unwrap();
+        let secret_key = secp256k1::SecretKey::from_slice(&xprv.private_key().to_bytes()).unwrap();
+        let public_key =
+            secp256k1::PublicKey::from_secret_key(&secp256k1::Secp256k1::new(), &secret_key);
+        let hash =
+            <sha3::Keccak256 as sha3::Digest>::digest(&public_key.serialize_uncompressed()[1..]);
+
+        assert_eq!(
+            hex::encode(&cl_address),
+            hex::encode(&hash[12..]),
+            "{}",
+            language.name()
+        );
+    }
+}
+
+/// 测试账户级密钥只派生一次、依次检查多个地址索引时与 bip32 crate 一致
+#[test]
+fn test_opencl_account_key_address_indices_match_rust() {
+    use rust_profanity::{SearchConfig, load_kernel_source, parse_derivation_path};
+
+    const ADDRESS_COUNT: usize = 4;
+
+    let mut source = load_kernel_source().expect("加载内核源代码失败");
+    source.push_str(
+        r#"
+__kernel void test_address_indices(
+    __constant uchar* entropy,
+    __constant search_config_t* config,
+    __global uchar* address_out
+) {
+    uchar local_entropy[32];
+    for (int i = 0; i < 32; i++) {
+        local_entropy[i] = entropy[i];
+    }
+    uint path_len = config->derivation_path_len;
+    uchar account_key[64];
+    derive_account_key_from_entropy(local_entropy, 32, config->passphrase, config->passphrase_len,
+                                    config->derivation_path, path_len, account_key);
+    uint first_index = config->derivation_path[path_len - 1];
+    for (uint n = 0; n < config->address_count; n++) {
+        uchar address[20];
+        derive_address_from_account_key(account_key, path_len, first_index + n, address);
+        for (int i = 0; i < 20; i++) {
+            address_out[n * 20 + i] = address[i];
+        }
+    }
+}
+"#,
+    );
+
+    let proque = match ProQue::builder().src(&source).dims(1).build() {
+        Ok(p) => p,
+        Err(e) => {
+            println!("OpenCL 不可用，跳过测试: {}", e);
+            return;
+        }
+    };
+
+    let entropy = [0xa5u8; 32];
+    let phrase = rust_profanity::mnemonic::Mnemonic::from_entropy(&entropy)
+        .unwrap()
+        .to_string();
+    let seed = bip39::Mnemonic::parse_in(Language::English, &phrase)
+        .unwrap()
+        .to_seed("");
+
+    // 普通地址索引和 Ledger Live 风格的硬化账户层
+    for (path, expected_paths) in [
+        (
+            "m/44'/60'/0'/0/5",
+            [
+                "m/44'/60'/0'/0/5",
+                "m/44'/60'/0'/0/6",
+                "m/44'/60'/0'/0/7",
+                "m/44'/60'/0'/0/8",
+            ],
+        ),
+        (
+            "m/44'/60'/0'",
+            [
+                "m/44'/60'/0'",
+                "m/44'/60'/1'",
+                "m/44'/60'/2'",
+                "m/44'/60'/3'",
+            ],
+        ),
+    ] {
+        let config = SearchConfig::new([0; 32], 1)
+            .with_derivation_path(&parse_derivation_path(path).unwrap())
+            .unwrap()
+            .with_address_count(ADDRESS_COUNT as u32);
+
+        let entropy_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(32)
+            .copy_host_slice(&entropy)
+            .build()
+            .unwrap();
+        let config_buffer = config_buffer(&proque, &config);
+        let address_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(20 * ADDRESS_COUNT)
+            .build()
+            .unwrap();
+        let kernel = proque
+            .kernel_builder("test_address_indices")
+            .arg(&entropy_buffer)
+            .arg(&config_buffer)
+            .arg(&address_buffer)
+            .build()
+            .unwrap();
+        unsafe {
+            kernel.enq().unwrap();
+        }
+        let mut cl_addresses = vec![0u8; 20 * ADDRESS_COUNT];
+        address_buffer.read(&mut cl_addresses).enq().unwrap();
+
+        for (n, expected_path) in expected_paths.iter().enumerate() {
+            let xprv =
+                bip32::XPrv::derive_from_path(seed, &expected_path.parse().unwrap()).unwrap();
+            let secret_key =
+                secp256k1::SecretKey::from_slice(&xprv.private_key().to_bytes()).unwrap();
+            let public_key =
+                secp256k1::PublicKey::from_secret_key(&secp256k1::Secp256k1::new(), &secret_key);
+            let hash = <sha3::Keccak256 as sha3::Digest>::digest(
+                &public_key.serialize_uncompressed()[1..],
+            );
+            assert_eq!(
+                hex::encode(&cl_addresses[n * 20..(n + 1) * 20]),
+                hex::encode(&hash[12..]),
+                "path {}",
+                expected_path
+            );
+        }
+    }
+}
+
+/// 测试内核的比特币 HASH160 (压缩公钥 SHA-256 + RIPEMD-160) 与主机实现一致
+#[test]
+fn test_opencl_bitcoin_hash160_matches_rust() {
+    use rust_profanity::bitcoin::{BITCOIN_P2PKH_VERSION, hash160_from_private_key, p2pkh_address};
+    use rust_profanity::load_kernel_source;
+
+    const KEY_COUNT: usize = 4;
+
+    let mut source = load_kernel_source().expect("加载内核源代码失败");
+    source.push_str(
+        r#"
+__kernel void test_hash160(
+    __constant uchar* private_keys,
+    __global uchar* hash_out
+) {
+    for (uint n = 0; n < 4; n++) {
+        uchar private_key[32];
+        for (int i = 0; i < 32; i++) {
+            private_key[i] = private_keys[n * 32 + i];
+        }
+        uchar hash160[20];
+        derive_hash160_from_private_key(private_key, hash160);
+        for (int i = 0; i < 20; i++) {
+            hash_out[n * 20 + i] = hash160[i];
+        }
+    }
+}
+"#,
+    );
+
+    let proque = match ProQue::builder().src(&source).dims(1).build() {
+        Ok(p) => p,
+        Err(e) => {
+            println!("OpenCL 不可用，跳过测试: {}", e);
+            return;
+        }
+    };
+
+    // 覆盖 y 为奇数和偶数的公钥
+    let mut private_keys = [[0u8; 32]; KEY_COUNT];
+    private_keys[0][31] = 1;
+    private_keys[1][31] = 3;
+    private_keys[2] = [0xa5; 32];
+    private_keys[3] = [0x5a; 32];
+
+    let key_buffer = Buffer::<u8>::builder()
+        .queue(proque.queue().clone())
+        .flags(MemFlags::READ_ONLY)
+        .len(32 * KEY_COUNT)
+        .copy_host_slice(&private_keys.concat())
+        .build()
+        .unwrap();
+    let hash_buffer = Buffer::<u8>::builder()
+        .queue(proque.queue().clone())
+        .flags(MemFlags::WRITE_ONLY)
+        .len(20 * KEY_COUNT)
+        .build()
+        .unwrap();
+    let kernel = proque
+        .kernel_builder("test_hash160")
+        .arg(&key_buffer)
+        .arg(&hash_buffer)
+        .build()
+        .unwrap();
+    unsafe {
+        kernel.enq().unwrap();
+    }
+    let mut cl_hashes = vec![0u8; 20 * KEY_COUNT];
+    hash_buffer.read(&mut cl_hashes).enq().unwrap();
+
+    for (n, private_key) in private_keys.iter().enumerate() {
+        let expected = hash160_from_private_key(private_key).unwrap();
+        assert_eq!(
+            hex::encode(&cl_hashes[n * 20..(n + 1) * 20]),
+            hex::encode(expected),
+            "private key #{}",
+            n
+        );
+    }
+    let first: [u8; 20] = cl_hashes[..20].try_into().unwrap();
+    assert_eq!(
+        p2pkh_address(BITCOIN_P2PKH_VERSION, &first),
+        "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+    );
+}
+
+/// 测试内核的 Taproot 输出公钥 (BIP-341 调整) 前 20 字节与主机实现一致
+#[test]
+fn test_opencl_taproot_output_key_matches_rust() {
+    use rust_profanity::bitcoin::{public_key_from_private_key, taproot_output_key};
+    use rust_profanity::load_kernel_source;
+
+    const KEY_COUNT: usize = 4;
+
+    let mut source = load_kernel_source().expect("加载内核源代码失败");
+    source.push_str(
+        r#"
+__kernel void test_taproot(
+    __constant uchar* private_keys,
+    __global uchar* key_out
+) {
+    for (uint n = 0; n < 4; n++) {
+        uchar private_key[32];
+        for (int i = 0; i < 32; i++) {
+            private_key[i] = private_keys[n * 32 + i];
+        }
+        uchar public_key[65];
+        private_to_public(private_key, public_key);
+        point public_point;
+        mp_from_bytes(public_key + 1, &public_point.x);
+        mp_from_bytes(public_key + 33, &public_point.y);
+        uchar output_key[20];
+        derive_point_key(&public_point, 3, output_key);
+        for (int i = 0; i < 20; i++) {
+            key_out[n * 20 + i] = output_key[i];
+        }
+    }
+}
+"#,
+    );
+
+    let proque = match ProQue::builder().src(&source).dims(1).build() {
+        Ok(p) => p,
+        Err(e) => {
+            println!("OpenCL 不可用，跳过测试: {}", e);
+            return;
+        }
+    };
+
+    // 覆盖 y 为奇数和偶数的内部公钥
+    let mut private_keys = [[0u8; 32]; KEY_COUNT];
+    private_keys[0][31] = 1;
+    private_keys[1][31] = 3;
+    private_keys[2] = [0xa5; 32];
+    private_keys[3] = [0x5a; 32];
+
+    let key_buffer = Buffer::<u8>::builder()
+        .queue(proque.queue().clone())
+        .flags(MemFlags::READ_ONLY)
+        .len(32 * KEY_COUNT)
+        .copy_host_slice(&private_keys.concat())
+        .build()
+        .unwrap();
+    let output_buffer = Buffer::<u8>::builder()
+        .queue(proque.queue().clone())
+        .flags(MemFlags::WRITE_ONLY)
+        .len(20 * KEY_COUNT)
+        .build()
+        .unwrap();
+    let kernel = proque
+        .kernel_builder("test_taproot")
+        .arg(&key_buffer)
+        .arg(&output_buffer)
+        .build()
+        .unwrap();
+    unsafe {
+        kernel.enq().unwrap();
+    }
+    let mut cl_keys = vec![0u8; 20 * KEY_COUNT];
+    output_buffer.read(&mut cl_keys).enq().unwrap();
+
+    for (n, private_key) in private_keys.iter().enumerate() {
+        let expected =
+            taproot_output_key(&public_key_from_private_key(private_key).unwrap()).unwrap();
+        assert_eq!(
+            hex::encode(&cl_keys[n * 20..(n + 1) * 20]),
+            hex::encode(&expected[..20]),
+            "private key #{}",
+            n
+        );
+    }
+}
+
+/// 测试内核为 Nostr 截取的 x-only 公钥前 20 字节与主机实现一致
+#[test]
+fn test_opencl_nostr_x_only_matches_rust() {
+    use rust_profanity::bitcoin::public_key_from_private_key;
+    use rust_profanity::load_kernel_source;
+    use rust_profanity::nostr::x_only_public_key;
+
+    const KEY_COUNT: usize = 4;
+
+    let mut source = load_kernel_source().expect("加载内核源代码失败");
+    source.push_str(
+        r#"
+__kernel void test_nostr(
+    __constant uchar* private_keys,
+    __global uchar* key_out
+) {
+    for (uint n = 0; n < 4; n++) {
+        uchar private_key[32];
+        for (int i = 0; i < 32; i++) {
+            private_key[i] = private_keys[n * 32 + i];
+        }
+        uchar public_key[65];
+        private_to_public(private_key, public_key);
+        point public_point;
+        mp_from_bytes(public_key + 1, &public_point.x);
+        mp_from_bytes(public_key + 33, &public_point.y);
+        uchar x_only[20];
+        derive_point_key(&public_point, 5, x_only);
+        for (int i = 0; i < 20; i++) {
+            key_out[n * 20 + i] = x_only[i];
+        }
+    }
+}
+"#,
+    );
+
+    let proque = match ProQue::builder().src(&source).dims(1).build() {
+        Ok(p) => p,
+        Err(e) => {
+            println!("OpenCL 不可用，跳过测试: {}", e);
+            return;
+        }
+    };
+
+    // 覆盖 y 为奇数和偶数的公钥 (x-only 公钥与 y 的奇偶无关)
+    let mut private_keys = [[0u8; 32]; KEY_COUNT];
+    private_keys[0][31] = 1;
+    private_keys[1][31] = 3;
+    private_keys[2] = [0xa5; 32];
+    private_keys[3] = [0x5a; 32];
+
+    let key_buffer = Buffer::<u8>::builder()
+        .queue(proque.queue().clone())
+        .flags(MemFlags::READ_ONLY)
+        .len(32 * KEY_COUNT)
+        .copy_host_slice(&private_keys.concat())
+        .build()
+        .unwrap();
+    let output_buffer = Buffer::<u8>::builder()
+        .queue(proque.queue().clone())
+        .flags(MemFlags::WRITE_ONLY)
+        .len(20 * KEY_COUNT)
+        .build()
+        .unwrap();
+    let kernel = proque
+        .kernel_builder("test_nostr")
+        .arg(&key_buffer)
+        .arg(&output_buffer)
+        .build()
+        .unwrap();
+    unsafe {
+        kernel.enq().unwrap();
+    }
+    let mut cl_keys = vec![0u8; 20 * KEY_COUNT];
+    output_buffer.read(&mut cl_keys).enq().unwrap();
+
+    for (n, private_key) in private_keys.iter().enumerate() {
+        let expected = x_only_public_key(&public_key_from_private_key(private_key).unwrap());
+        assert_eq!(
+            hex::encode(&cl_keys[n * 20..(n + 1) * 20]),
+            hex::encode(&expected[..20]),
+            "private key #{}",
+            n
+        );
+    }
+}
+
 /// 测试 Jacobian 标量乘法与原始实现的一致性
 #[test]
 fn test_jacobian_scalar_mult() {
@@ -857,3 +1581,151 @@ __kernel void smoke_kernel(__global uchar* out) {
     assert_eq!(match_flag, 1, "Affine 和 Jacobian 标量乘法结果不匹配!");
     println!("✓ Affine 和 Jacobian 标量乘法结果一致!");
 }
+
+/// 测试批量 Jacobian 转仿射 (Montgomery 批量求逆) 与 Rust secp256k1 一致
+/// 覆盖 Z=1 的点、无穷远点以及 1/8/16 的批大小
+#[test]
+fn test_jacobian_to_affine_batch_matches_rust() {
+    use rust_profanity::bitcoin::public_key_from_private_key;
+    use rust_profanity::load_kernel_source;
+
+    // 点的构造方式: 0=标量乘法得到的 Jacobian 点, 1=Z=1 的仿射点, 2=无穷远点
+    const JACOBIAN: u8 = 0;
+    const AFFINE: u8 = 1;
+    const INFINITY: u8 = 2;
+
+    let mut source = load_kernel_source().expect("加载内核源代码失败");
+    source.push_str(
+        r#"
+__kernel void test_batch_affine(
+    __constant uchar* private_keys,
+    __constant uchar* modes,
+    uint count,
+    __global uchar* out
+) {
+    jacobian_point points[BATCH_INVERSE_MAX];
+    for (uint n = 0; n < count; n++) {
+        uchar private_key[32];
+        for (int i = 0; i < 32; i++) {
+            private_key[i] = private_keys[n * 32 + i];
+        }
+        if (modes[n] == 2) {
+            jacobian_set_infinity(&points[n]);
+        } else if (modes[n] == 1) {
+            uchar public_key[65];
+            private_to_public(private_key, public_key);
+            point affine;
+            mp_from_bytes(public_key + 1, &affine.x);
+            mp_from_bytes(public_key + 33, &affine.y);
+            affine_to_jacobian(&points[n], &affine);
+        } else {
+            scalar_mult_base_jacobian_point(private_key, &points[n]);
+        }
+    }
+
+    point affine[BATCH_INVERSE_MAX];
+    jacobian_to_affine_batch(affine, points, count);
+
+    for (uint n = 0; n < count; n++) {
+        uchar x[32], y[32];
+        mp_to_bytes(&affine[n].x, x);
+        mp_to_bytes(&affine[n].y, y);
+        for (int i = 0; i < 32; i++) {
+            out[n * 64 + i] = x[i];
+            out[n * 64 + 32 + i] = y[i];
+        }
+    }
+}
+"#,
+    );
+
+    let proque = match ProQue::builder().src(&source).dims(1).build() {
+        Ok(p) => p,
+        Err(e) => {
+            println!("OpenCL 不可用，跳过测试: {}", e);
+            return;
+        }
+    };
+
+    let cases: [&[u8]; 6] = [
+        &[AFFINE],
+        &[INFINITY],
+        &[JACOBIAN],
+        // 全部 Z=1: 累乘积和逆都是 1，最容易暴露未规约的中间值
+        &[
+            AFFINE, AFFINE, INFINITY, AFFINE, AFFINE, INFINITY, AFFINE, AFFINE,
+        ],
+        &[
+            JACOBIAN, AFFINE, INFINITY, JACOBIAN, JACOBIAN, AFFINE, JACOBIAN, INFINITY,
+        ],
+        &[
+            INFINITY, JACOBIAN, JACOBIAN, AFFINE, JACOBIAN, JACOBIAN, JACOBIAN, JACOBIAN, AFFINE,
+            JACOBIAN, INFINITY, JACOBIAN, JACOBIAN, JACOBIAN, AFFINE, JACOBIAN,
+        ],
+    ];
+
+    for modes in cases {
+        let count = modes.len();
+        let private_keys: Vec<[u8; 32]> = (0..count)
+            .map(|n| {
+                let mut key = [0x11u8; 32];
+                key[0] = n as u8 + 1;
+                key[31] = (n as u8).wrapping_mul(37);
+                key
+            })
+            .collect();
+
+        let key_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(32 * count)
+            .copy_host_slice(&private_keys.concat())
+            .build()
+            .unwrap();
+        let mode_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(count)
+            .copy_host_slice(modes)
+            .build()
+            .unwrap();
+        let output_buffer = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(64 * count)
+            .build()
+            .unwrap();
+        let kernel = proque
+            .kernel_builder("test_batch_affine")
+            .arg(&key_buffer)
+            .arg(&mode_buffer)
+            .arg(count as u32)
+            .arg(&output_buffer)
+            .build()
+            .unwrap();
+        unsafe {
+            kernel.enq().unwrap();
+        }
+        let mut cl_points = vec![0u8; 64 * count];
+        output_buffer.read(&mut cl_points).enq().unwrap();
+
+        for (n, private_key) in private_keys.iter().enumerate() {
+            let expected = if modes[n] == INFINITY {
+                [0u8; 64]
+            } else {
+                let uncompressed = public_key_from_private_key(private_key)
+                    .unwrap()
+                    .serialize_uncompressed();
+                uncompressed[1..].try_into().unwrap()
+            };
+            assert_eq!(
+                hex::encode(&cl_points[n * 64..(n + 1) * 64]),
+                hex::encode(expected),
+                "batch size {}, point #{} (mode {})",
+                count,
+                n,
+                modes[n]
+            );
+        }
+    }
+}
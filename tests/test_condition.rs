@@ -3,6 +3,7 @@
 const COND_PREFIX: u16 = 0x01;
 const COND_SUFFIX: u16 = 0x02;
 const COND_LEADING: u16 = 0x04;
+const COND_ZERO_BYTES: u16 = 0x06;
 
 /// 旧版编码（不带字节数）- 用于前导零条件
 fn encode_condition(cond_type: u16, param: u64) -> u64 {
@@ -34,6 +35,10 @@ fn rust_count_leading_zeros(address: &[u8; 20]) -> u32 {
     address.iter().take_while(|&&b| b == 0).count() as u32
 }
 
+fn rust_count_zero_bytes(address: &[u8; 20]) -> u32 {
+    address.iter().filter(|&&b| b == 0).count() as u32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +92,24 @@ mod tests {
         assert_eq!(rust_count_leading_zeros(&address4), 1);
     }
 
+    #[test]
+    fn test_zero_bytes() {
+        let address1 = [0x11u8; 20];
+        assert_eq!(rust_count_zero_bytes(&address1), 0);
+
+        // 零字节不要求连续或位于开头
+        let mut address2 = [0x11u8; 20];
+        address2[0] = 0x00;
+        address2[7] = 0x00;
+        address2[19] = 0x00;
+        assert_eq!(rust_count_zero_bytes(&address2), 3);
+        assert_eq!(rust_count_leading_zeros(&address2), 1);
+
+        let zero_cond = encode_condition(COND_ZERO_BYTES, 3);
+        assert_eq!((zero_cond >> 48) as u16, COND_ZERO_BYTES);
+        assert!(rust_count_zero_bytes(&address2) >= (zero_cond & 0xFFFFFFFFFFFF) as u32);
+    }
+
     #[test]
     fn test_condition_encoding() {
         let prefix_cond = encode_condition(COND_PREFIX, 0x8888);
@@ -162,7 +185,184 @@ mod tests {
 #[cfg(test)]
 mod opencl_tests {
     use super::*;
-    use ocl::{Buffer, MemFlags, ProQue};
+    use ocl::{Buffer, MemFlags, OclPrm, ProQue};
+    use rand::RngCore;
+    use rust_profanity::api::build_targets;
+    use rust_profanity::{
+        ConditionEncoding, ConditionType, ScoreMode, SearchCondition, SearchTarget,
+        create_contract_address, create2_address, parse_eth_address, parse_hash32,
+        parse_pattern_condition,
+    };
+    use sha3::{Digest, Keccak256};
+
+    /// 测试内核: 每个工作项处理一个地址，直接调用 search.cl 中的函数
+    const SEARCH_TEST_KERNELS: &str = r#"
+__kernel void test_score_address(
+    __constant uchar* addresses,
+    __constant search_target_t* targets,
+    uint score_mode,
+    __global uint* out
+) {
+    uint n = get_global_id(0);
+    uchar address[20];
+    for (int i = 0; i < 20; i++) {
+        address[i] = addresses[n * 20 + i];
+    }
+    out[n] = score_address(address, score_mode, targets[0].pattern_mask, targets[0].pattern_value);
+}
+
+__kernel void test_evaluate_targets(
+    __constant uchar* addresses,
+    __constant search_target_t* targets,
+    uint target_count,
+    __global int* out
+) {
+    uint n = get_global_id(0);
+    uchar address[20];
+    for (int i = 0; i < 20; i++) {
+        address[i] = addresses[n * 20 + i];
+    }
+    out[n] = evaluate_targets(address, targets, target_count);
+}
+
+__kernel void test_create2_address(
+    __constant uchar* deployers,
+    __constant uchar* salts,
+    __constant uchar* init_code_hashes,
+    __global uchar* out
+) {
+    uint n = get_global_id(0);
+    uchar salt[32];
+    uchar address[20];
+    for (int i = 0; i < 32; i++) {
+        salt[i] = salts[n * 32 + i];
+    }
+    derive_create2_address(deployers + n * 20, salt, init_code_hashes + n * 32, address);
+    for (int i = 0; i < 20; i++) {
+        out[n * 20 + i] = address[i];
+    }
+}
+
+__kernel void test_create_address(
+    __constant uchar* deployer,
+    __constant ulong* nonces,
+    __global uchar* out
+) {
+    uint n = get_global_id(0);
+    uchar sender[20];
+    uchar address[20];
+    for (int i = 0; i < 20; i++) {
+        sender[i] = deployer[i];
+    }
+    derive_create_address(sender, nonces[n], address);
+    for (int i = 0; i < 20; i++) {
+        out[n * 20 + i] = address[i];
+    }
+}
+"#;
+
+    /// 编译完整搜索内核加测试内核
+    fn build_search_proque() -> ocl::Result<ProQue> {
+        let mut source = rust_profanity::load_kernel_source().expect("加载内核源代码失败");
+        source.push_str(SEARCH_TEST_KERNELS);
+        ProQue::builder().src(source).dims(1).build()
+    }
+
+    /// 目标表按字节上传，与 SearchKernel::set_targets 相同
+    fn target_buffer(proque: &ProQue, targets: &[SearchTarget]) -> ocl::Result<Buffer<u8>> {
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                targets.as_ptr() as *const u8,
+                std::mem::size_of_val(targets),
+            )
+        };
+        Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(bytes.len())
+            .copy_host_slice(bytes)
+            .build()
+    }
+
+    fn input_buffer<T: OclPrm>(proque: &ProQue, data: &[T]) -> ocl::Result<Buffer<T>> {
+        Buffer::<T>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::READ_ONLY)
+            .len(data.len())
+            .copy_host_slice(data)
+            .build()
+    }
+
+    fn address_buffer(proque: &ProQue, addresses: &[[u8; 20]]) -> ocl::Result<Buffer<u8>> {
+        input_buffer(proque, &addresses.concat())
+    }
+
+    /// 读回每个工作项写出的 20 字节地址
+    fn read_addresses(out: &Buffer<u8>, count: usize) -> ocl::Result<Vec<[u8; 20]>> {
+        let mut bytes = vec![0u8; 20 * count];
+        out.read(&mut bytes).enq()?;
+        Ok(bytes
+            .chunks_exact(20)
+            .map(|chunk| chunk.try_into().unwrap())
+            .collect())
+    }
+
+    /// 在 GPU 上计算 CREATE2 地址，每个工作项一组 (deployer, salt, init_code_hash)
+    fn opencl_create2_addresses(
+        vectors: &[([u8; 20], [u8; 32], [u8; 32])],
+    ) -> ocl::Result<Vec<[u8; 20]>> {
+        let proque = build_search_proque()?;
+        let deployers: Vec<u8> = vectors.iter().flat_map(|v| v.0).collect();
+        let salts: Vec<u8> = vectors.iter().flat_map(|v| v.1).collect();
+        let init_code_hashes: Vec<u8> = vectors.iter().flat_map(|v| v.2).collect();
+        let out = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(20 * vectors.len())
+            .build()?;
+        let kernel = proque
+            .kernel_builder("test_create2_address")
+            .global_work_size(vectors.len())
+            .arg(&input_buffer(&proque, &deployers)?)
+            .arg(&input_buffer(&proque, &salts)?)
+            .arg(&input_buffer(&proque, &init_code_hashes)?)
+            .arg(&out)
+            .build()?;
+        unsafe {
+            kernel.enq()?;
+        }
+        read_addresses(&out, vectors.len())
+    }
+
+    /// 在 GPU 上计算每个地址的分数 (ScoreMatching 使用 target 的 mask/value)
+    fn opencl_score_address(
+        addresses: &[[u8; 20]],
+        score_mode: ScoreMode,
+        target: SearchTarget,
+    ) -> ocl::Result<Vec<u32>> {
+        let proque = build_search_proque()?;
+        let targets = target_buffer(&proque, &[target])?;
+        let addresses_buffer = address_buffer(&proque, addresses)?;
+        let out = Buffer::<u32>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(addresses.len())
+            .build()?;
+        let kernel = proque
+            .kernel_builder("test_score_address")
+            .global_work_size(addresses.len())
+            .arg(&addresses_buffer)
+            .arg(&targets)
+            .arg(score_mode.as_u32())
+            .arg(&out)
+            .build()?;
+        unsafe {
+            kernel.enq()?;
+        }
+        let mut scores = vec![0u32; addresses.len()];
+        out.read(&mut scores).enq()?;
+        Ok(scores)
+    }
 
     fn load_kernel_source() -> String {
         include_str!("../kernels/utils/condition.cl").to_string()
@@ -203,6 +403,65 @@ mod opencl_tests {
         Ok(result[0] != 0)
     }
 
+    /// 以 fill 填充地址，再覆盖指定位置的字节
+    fn address_with(fill: u8, bytes: &[(usize, u8)]) -> [u8; 20] {
+        let mut address = [fill; 20];
+        for &(i, b) in bytes {
+            address[i] = b;
+        }
+        address
+    }
+
+    /// 在 GPU 上对每个地址求值目标表，返回命中的顶层条件序号 (未命中为 -1)
+    fn opencl_evaluate_targets(
+        addresses: &[[u8; 20]],
+        targets: &[SearchTarget],
+    ) -> ocl::Result<Vec<i32>> {
+        let proque = build_search_proque()?;
+        let targets_buffer = target_buffer(&proque, targets)?;
+        let addresses_buffer = address_buffer(&proque, addresses)?;
+        let out = Buffer::<i32>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(addresses.len())
+            .build()?;
+        let kernel = proque
+            .kernel_builder("test_evaluate_targets")
+            .global_work_size(addresses.len())
+            .arg(&addresses_buffer)
+            .arg(&targets_buffer)
+            .arg(targets.len() as u32)
+            .arg(&out)
+            .build()?;
+        unsafe {
+            kernel.enq()?;
+        }
+        let mut matched = vec![0i32; addresses.len()];
+        out.read(&mut matched).enq()?;
+        Ok(matched)
+    }
+
+    /// 在 GPU 上计算同一部署者在各个 nonce 下的 CREATE 合约地址
+    fn opencl_create_addresses(deployer: &[u8; 20], nonces: &[u64]) -> ocl::Result<Vec<[u8; 20]>> {
+        let proque = build_search_proque()?;
+        let out = Buffer::<u8>::builder()
+            .queue(proque.queue().clone())
+            .flags(MemFlags::WRITE_ONLY)
+            .len(20 * nonces.len())
+            .build()?;
+        let kernel = proque
+            .kernel_builder("test_create_address")
+            .global_work_size(nonces.len())
+            .arg(&input_buffer(&proque, deployer)?)
+            .arg(&input_buffer(&proque, nonces)?)
+            .arg(&out)
+            .build()?;
+        unsafe {
+            kernel.enq()?;
+        }
+        read_addresses(&out, nonces.len())
+    }
+
     #[test]
     fn test_opencl_condition_check() {
         let address = [0x88u8; 20];
@@ -227,4 +486,471 @@ mod opencl_tests {
             Err(e) => println!("OpenCL 测试跳过: {}", e),
         }
     }
+
+    #[test]
+    fn test_opencl_score_address() {
+        let mut address = [0xFFu8; 20];
+        address[0] = 0x00;
+        address[1] = 0xAA;
+        address[10] = 0xF0;
+        let mut zeros = [0u8; 20];
+        zeros[19] = 0x01;
+        let addresses = [address, zeros, [0xFF; 20]];
+
+        // 目标 0x00ab...: 地址 0x00aa... 前三个字符相同，第四个 a != b
+        let (condition, pattern) =
+            parse_pattern_condition("0x00abXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX").unwrap();
+        let target = SearchTarget::new_with_pattern(condition, pattern);
+
+        let cases = [
+            (ScoreMode::LeadingZeros, [2, 39, 0]),
+            (ScoreMode::ZeroNibbles, [3, 39, 0]),
+            (ScoreMode::Matching, [3, 2, 0]),
+            (ScoreMode::None, [0, 0, 0]),
+        ];
+        for (mode, expected) in cases {
+            match opencl_score_address(&addresses, mode, target) {
+                Ok(scores) => assert_eq!(scores, expected, "{:?}", mode),
+                Err(e) => {
+                    println!("OpenCL 测试跳过: {}", e);
+                    return;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_opencl_zero_bytes() {
+        // 零字节不要求连续或位于开头
+        let mut scattered = [0x11u8; 20];
+        scattered[0] = 0x00;
+        scattered[7] = 0x00;
+        scattered[19] = 0x00;
+        let mut two = scattered;
+        two[7] = 0x10;
+        // 零字符不等于零字节: 0x01 / 0x10 都不计数
+        let nibbles = [
+            0x01u8, 0x10, 0x01, 0x10, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+            0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+        ];
+        let addresses = [scattered, two, [0u8; 20], nibbles];
+
+        let targets = [SearchTarget::new(encode_condition(COND_ZERO_BYTES, 3))];
+        match opencl_evaluate_targets(&addresses, &targets) {
+            Ok(matched) => assert_eq!(matched, [0, -1, 0, -1]),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_opencl_specialized_encoding_matches_pattern_path() {
+        let conditions = [
+            SearchCondition::Prefix(String::from("12")),
+            SearchCondition::Prefix(String::from("123456")),
+            SearchCondition::Prefix(String::from("1234567890")),
+            SearchCondition::Suffix(String::from("ad")),
+            SearchCondition::Suffix(String::from("00dead")),
+            SearchCondition::Suffix(String::from("ABCDEF0123")),
+            SearchCondition::LeadingZeros(3),
+            SearchCondition::LeadingZeros(4),
+        ];
+
+        let mut rng = rand::thread_rng();
+        for condition in conditions {
+            let pattern = build_targets(
+                std::slice::from_ref(&condition),
+                false,
+                ConditionEncoding::Pattern,
+            )
+            .unwrap();
+            let specialized = build_targets(
+                std::slice::from_ref(&condition),
+                false,
+                ConditionEncoding::Specialized,
+            )
+            .unwrap();
+            assert_ne!(
+                (specialized[0].condition >> 48) & 0xFFFF,
+                ConditionType::Pattern as u64,
+                "{:?} should use a specialized encoding",
+                condition
+            );
+
+            // 偶数下标的地址写入模式的 value，保证命中；奇数下标为随机地址
+            let mask = pattern[0].pattern_config.mask;
+            let value = pattern[0].pattern_config.value;
+            let addresses: Vec<[u8; 20]> = (0..64)
+                .map(|i| {
+                    let mut address = [0u8; 20];
+                    rng.fill_bytes(&mut address);
+                    if i % 2 == 0 {
+                        for (a, (m, v)) in address.iter_mut().zip(mask.iter().zip(value.iter())) {
+                            *a = (*a & !m) | v;
+                        }
+                    }
+                    address
+                })
+                .collect();
+
+            let (pattern_hits, specialized_hits) = match (
+                opencl_evaluate_targets(&addresses, &pattern),
+                opencl_evaluate_targets(&addresses, &specialized),
+            ) {
+                (Ok(p), Ok(s)) => (p, s),
+                (Err(e), _) | (_, Err(e)) => {
+                    println!("OpenCL 测试跳过: {}", e);
+                    return;
+                }
+            };
+            assert_eq!(pattern_hits, specialized_hits, "{:?}", condition);
+            for (i, hit) in specialized_hits.iter().enumerate().step_by(2) {
+                assert_eq!(
+                    *hit,
+                    0,
+                    "{:?} misses {}",
+                    condition,
+                    hex::encode(addresses[i])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_opencl_char_classes() {
+        // 每个顶层条件各占一个 root，命中哪个条件由 evaluate_targets 的返回值区分
+        let conditions = [
+            SearchCondition::AllLetters,
+            SearchCondition::AllDigits,
+            SearchCondition::LeadingDoubles(2),
+            SearchCondition::Mirror(3),
+            // 字母、数字、任意、与第 2 个字符相同，随后为 ab
+            SearchCondition::Pattern(format!("0x{{L}}{{D}}X{{2}}ab{}", "X".repeat(34))),
+        ];
+        let targets = build_targets(&conditions, false, ConditionEncoding::Pattern).unwrap();
+
+        let cases = [
+            (address_with(0xAB, &[]), 0),
+            (address_with(0x09, &[]), 1),
+            (address_with(0x12, &[(0, 0x11), (1, 0xAA)]), 2),
+            (address_with(0x12, &[(0, 0x11), (1, 0xAB)]), -1),
+            // 0x123...321
+            (
+                address_with(0x5A, &[(0, 0x12), (1, 0x30), (18, 0x03), (19, 0x21)]),
+                3,
+            ),
+            (
+                address_with(0x5A, &[(0, 0x12), (1, 0x30), (18, 0x03), (19, 0x22)]),
+                -1,
+            ),
+            (address_with(0x12, &[(0, 0xC5), (1, 0x77), (2, 0xAB)]), 4),
+            // 反向引用不成立: 第 3 个字符 7 != 第 4 个字符 8
+            (address_with(0x12, &[(0, 0xC5), (1, 0x78), (2, 0xAB)]), -1),
+            // 第 1 个字符不是字母
+            (address_with(0x12, &[(0, 0x95), (1, 0x77), (2, 0xAB)]), -1),
+            // 第 2 个字符不是数字
+            (address_with(0x12, &[(0, 0xCA), (1, 0x77), (2, 0xAB)]), -1),
+            // 字符类满足但固定字符 ab 不匹配
+            (address_with(0x12, &[(0, 0xC5), (1, 0x77), (2, 0xAC)]), -1),
+        ];
+        let addresses: Vec<[u8; 20]> = cases.iter().map(|(address, _)| *address).collect();
+        let expected: Vec<i32> = cases.iter().map(|(_, index)| *index).collect();
+
+        match opencl_evaluate_targets(&addresses, &targets) {
+            Ok(matched) => assert_eq!(matched, expected),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_opencl_contains() {
+        let word = "0123456789abcdef0123456789abcdef01234567";
+        let conditions = [
+            SearchCondition::Contains(String::from("dead")),
+            SearchCondition::Contains(String::from("coffee")),
+            // 40 个字符的单词只有偏移 0 一种位置
+            SearchCondition::Contains(String::from(word)),
+        ];
+        let targets = build_targets(&conditions, false, ConditionEncoding::Pattern).unwrap();
+
+        let mut full = [0u8; 20];
+        hex::decode_to_slice(word, &mut full).unwrap();
+        let mut last_differs = full;
+        last_differs[19] = 0x68;
+
+        let cases = [
+            // dead 从第 3 个字符开始，跨越 3 个字节: 0x11 1d ea d1 ...
+            (address_with(0x11, &[(1, 0x1D), (2, 0xEA), (3, 0xD1)]), 0),
+            // 位于地址末尾的偶数偏移
+            (address_with(0x11, &[(18, 0xDE), (19, 0xAD)]), 0),
+            (address_with(0x11, &[(1, 0x1D), (2, 0xEA), (3, 0xC1)]), -1),
+            // coffee 按 leetspeak 转为 c0ffee，从第 33 个字符开始一直到倒数第 2 个字符
+            (
+                address_with(0x11, &[(16, 0x1C), (17, 0x0F), (18, 0xFE), (19, 0xE1)]),
+                1,
+            ),
+            (address_with(0x11, &[(0, 0xC0), (1, 0xFF), (2, 0xEE)]), 1),
+            (
+                address_with(0x11, &[(16, 0x1C), (17, 0x0F), (18, 0xFE), (19, 0xF1)]),
+                -1,
+            ),
+            (full, 2),
+            (last_differs, -1),
+        ];
+        let addresses: Vec<[u8; 20]> = cases.iter().map(|(address, _)| *address).collect();
+        let expected: Vec<i32> = cases.iter().map(|(_, index)| *index).collect();
+
+        match opencl_evaluate_targets(&addresses, &targets) {
+            Ok(matched) => assert_eq!(matched, expected),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
+
+    /// 只约束第 i 个字符的模式
+    fn nibble_is(i: usize, c: char) -> SearchCondition {
+        SearchCondition::Pattern(format!("0x{}{}{}", "X".repeat(i), c, "X".repeat(39 - i)))
+    }
+
+    #[test]
+    fn test_opencl_evaluate_targets() {
+        // 顶层条件 0: a0 AND (a1 AND (... (a30 AND NOT a31)))，求值栈深度正好 32
+        let mut chain = SearchCondition::Not(Box::new(nibble_is(31, 'a')));
+        for i in (0..31).rev() {
+            chain = SearchCondition::And(vec![nibble_is(i, 'a'), chain]);
+        }
+        // 顶层条件 1: 31 个操作数的 Or，运算符掩码为 (1 << 31) - 1
+        let any_b = SearchCondition::Or((0..31).map(|i| nibble_is(i, 'b')).collect());
+        // 顶层条件 2: (suffix ff OR suffix ee) AND NOT (prefix 00 OR prefix 11)
+        let nested = SearchCondition::And(vec![
+            SearchCondition::Or(vec![
+                SearchCondition::Suffix(String::from("ff")),
+                SearchCondition::Suffix(String::from("ee")),
+            ]),
+            SearchCondition::Not(Box::new(SearchCondition::Or(vec![
+                SearchCondition::Prefix(String::from("00")),
+                SearchCondition::Prefix(String::from("11")),
+            ]))),
+        ]);
+        let targets =
+            build_targets(&[chain, any_b, nested], false, ConditionEncoding::Pattern).unwrap();
+        assert_eq!(targets.len(), 104);
+        assert_eq!(targets.iter().filter(|t| t.root != 0).count(), 3);
+
+        let cases = [
+            (format!("{}{}", "a".repeat(31), "1".repeat(9)), 0),
+            // 最深处的 NOT 不成立
+            (format!("{}{}", "a".repeat(32), "1".repeat(8)), -1),
+            // 最外层的操作数不成立
+            (format!("1{}{}", "a".repeat(30), "1".repeat(9)), -1),
+            (format!("{}{}", "a".repeat(30), "1".repeat(10)), -1),
+            (format!("{}b{}", "1".repeat(30), "1".repeat(9)), 1),
+            (format!("b{}", "1".repeat(39)), 1),
+            ("1".repeat(40), -1),
+            // 同时满足条件 1 和 2 时返回第一个
+            (format!("b3{}ff", "3".repeat(36)), 1),
+            (format!("{}ff", "3".repeat(38)), 2),
+            (format!("{}ee", "3".repeat(38)), 2),
+            (format!("00{}ee", "3".repeat(36)), -1),
+            (format!("11{}ff", "3".repeat(36)), -1),
+            (format!("{}dd", "3".repeat(38)), -1),
+            (format!("{}{}ff", "a".repeat(31), "1".repeat(7)), 0),
+        ];
+        let addresses: Vec<[u8; 20]> = cases
+            .iter()
+            .map(|(nibbles, _)| {
+                let mut address = [0u8; 20];
+                hex::decode_to_slice(nibbles, &mut address).unwrap();
+                address
+            })
+            .collect();
+        let expected: Vec<i32> = cases.iter().map(|(_, index)| *index).collect();
+
+        match opencl_evaluate_targets(&addresses, &targets) {
+            Ok(matched) => assert_eq!(matched, expected),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_opencl_range() {
+        let bound = |first: u8, last: u8| {
+            let mut address = [0u8; 20];
+            address[0] = first;
+            address[19] = last;
+            address
+        };
+        let below = |first: u8| {
+            let mut address = [0xFFu8; 20];
+            address[0] = first;
+            address
+        };
+        let conditions = [
+            // [0x10...05, 0x20...00): 下界最后一个字节才决定大小
+            SearchCondition::Range {
+                low: bound(0x10, 0x05),
+                high: Some(bound(0x20, 0x00)),
+            },
+            // 没有上界 (条件参数为 1)
+            SearchCondition::Range {
+                low: bound(0x80, 0x00),
+                high: None,
+            },
+        ];
+        let targets = build_targets(&conditions, false, ConditionEncoding::Pattern).unwrap();
+        assert_eq!(targets[0].condition & 1, 0);
+        assert_eq!(targets[1].condition & 1, 1);
+
+        let cases = [
+            (bound(0x10, 0x05), 0),
+            (bound(0x10, 0x04), -1),
+            (bound(0x10, 0x06), 0),
+            (below(0x0F), -1),
+            (below(0x1F), 0),
+            // 上界不包含在范围内
+            (bound(0x20, 0x00), -1),
+            (bound(0x20, 0x01), -1),
+            (bound(0x80, 0x00), 1),
+            (below(0x7F), -1),
+            ([0xFF; 20], 1),
+        ];
+        let addresses: Vec<[u8; 20]> = cases.iter().map(|(address, _)| *address).collect();
+        let expected: Vec<i32> = cases.iter().map(|(_, index)| *index).collect();
+
+        match opencl_evaluate_targets(&addresses, &targets) {
+            Ok(matched) => assert_eq!(matched, expected),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_opencl_create2_address() {
+        // EIP-1014 示例: (deployer, salt, init_code, 地址)
+        let vectors = [
+            (
+                "0x0000000000000000000000000000000000000000",
+                "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "00",
+                "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "00",
+                "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                "0x000000000000000000000000feed000000000000000000000000000000000000",
+                "00",
+                "0xD04116cDd17beBE565EB2422F2497E06cC1C9833",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "deadbeef",
+                "0x70f2b2914A2a4b783FaEFb75f459A580616Fcb5e",
+            ),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                "0x00000000000000000000000000000000000000000000000000000000cafebabe",
+                "deadbeef",
+                "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7",
+            ),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                "0x00000000000000000000000000000000000000000000000000000000cafebabe",
+                "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                "0x1d8bfDC5D46DC4f61D6b6115972536eBE6A8854C",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "",
+                "0xE33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0",
+            ),
+        ];
+        let inputs: Vec<([u8; 20], [u8; 32], [u8; 32])> = vectors
+            .iter()
+            .map(|(deployer, salt, init_code, _)| {
+                (
+                    parse_eth_address(deployer).unwrap(),
+                    parse_hash32(salt).unwrap(),
+                    Keccak256::digest(hex::decode(init_code).unwrap()).into(),
+                )
+            })
+            .collect();
+        let expected: Vec<[u8; 20]> = vectors
+            .iter()
+            .map(|(_, _, _, address)| parse_eth_address(address).unwrap())
+            .collect();
+        for ((deployer, salt, init_code_hash), address) in inputs.iter().zip(&expected) {
+            assert_eq!(create2_address(deployer, salt, init_code_hash), *address);
+        }
+
+        match opencl_create2_addresses(&inputs) {
+            Ok(addresses) => assert_eq!(addresses, expected),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_opencl_checksum_case() {
+        // EIP-55 示例地址，每个条件的大小写与对应地址的校验和形式一致，最后一个故意写错
+        let addresses: Vec<[u8; 20]> = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ]
+        .iter()
+        .map(|address| parse_eth_address(address).unwrap())
+        .collect();
+        let conditions = [
+            SearchCondition::Prefix(String::from("5aAeb6")),
+            SearchCondition::Suffix(String::from("C8C6FB")),
+            SearchCondition::Pattern(String::from("0xfB69XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXd359")),
+            // 校验和形式为 D1220A，小写 a 要求该字母为小写
+            SearchCondition::Prefix(String::from("D1220a")),
+        ];
+
+        let targets = build_targets(&conditions, true, ConditionEncoding::Pattern).unwrap();
+        assert!(targets.iter().all(|target| target.case_sensitive == 1));
+        // 不区分大小写时最后一个条件命中，区分大小写时被校验和拒绝
+        let insensitive = build_targets(&conditions, false, ConditionEncoding::Pattern).unwrap();
+
+        match opencl_evaluate_targets(&addresses, &targets) {
+            Ok(matched) => assert_eq!(matched, vec![0, 1, 2, -1]),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+        match opencl_evaluate_targets(&addresses, &insensitive) {
+            Ok(matched) => assert_eq!(matched, vec![0, 1, 2, 3]),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_opencl_create_address() {
+        // RLP nonce 编码的每个分支: 0 为空串 0x80，小于 0x80 为单字节，其余为 0x80 + 字节数 + 大端序字节
+        let deployer = parse_eth_address("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0").unwrap();
+        let nonces = [
+            0,
+            1,
+            0x7F,
+            0x80,
+            0xFF,
+            0x100,
+            0xFFFF,
+            0x0102_0304,
+            0x0100_0000_0000,
+            u64::MAX,
+        ];
+        let expected: Vec<[u8; 20]> = nonces
+            .iter()
+            .map(|&nonce| create_contract_address(&deployer, nonce))
+            .collect();
+
+        match opencl_create_addresses(&deployer, &nonces) {
+            Ok(addresses) => assert_eq!(addresses, expected),
+            Err(e) => println!("OpenCL 测试跳过: {}", e),
+        }
+    }
 }
@@ -53,12 +53,12 @@ fn opencl_keccak256(data: &[u8]) -> ocl::Result<[u8; 32]> {
     let proque = ProQue::builder().src(kernel_source).dims(1).build()?;
 
     // 输入数据缓冲区 - 空输入时至少分配 1 字节
-    let input_len = if data.len() == 0 { 1 } else { data.len() };
+    let input_len = if data.is_empty() { 1 } else { data.len() };
     let input_buffer = Buffer::<u8>::builder()
         .queue(proque.queue().clone())
         .flags(MemFlags::READ_ONLY)
         .len(input_len)
-        .copy_host_slice(if data.len() == 0 { &[0u8] } else { data })
+        .copy_host_slice(if data.is_empty() { &[0u8] } else { data })
         .build()?;
 
     // 输出哈希缓冲区